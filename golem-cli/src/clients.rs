@@ -14,6 +14,7 @@
 
 pub mod api_definition;
 pub mod api_deployment;
+pub mod cluster;
 pub mod component;
 pub mod health_check;
 pub mod worker;
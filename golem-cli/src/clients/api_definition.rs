@@ -14,7 +14,7 @@
 
 use crate::model::{ApiDefinitionId, ApiDefinitionVersion, GolemError, PathBufOrStdin};
 use async_trait::async_trait;
-use golem_client::model::HttpApiDefinitionWithTypeInfo;
+use golem_client::model::{HttpApiDefinitionWithTypeInfo, RouteExampleResult};
 
 #[async_trait]
 pub trait ApiDefinitionClient {
@@ -46,6 +46,11 @@ pub trait ApiDefinitionClient {
         path: PathBufOrStdin,
         project: &Self::ProjectContext,
     ) -> Result<HttpApiDefinitionWithTypeInfo, GolemError>;
+    async fn test(
+        &self,
+        path: PathBufOrStdin,
+        project: &Self::ProjectContext,
+    ) -> Result<Vec<RouteExampleResult>, GolemError>;
     async fn delete(
         &self,
         id: ApiDefinitionId,
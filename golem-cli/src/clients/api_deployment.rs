@@ -24,6 +24,7 @@ pub trait ApiDeploymentClient {
         api_definitions: Vec<ApiDefinitionIdWithVersion>,
         host: &str,
         subdomain: Option<String>,
+        rib_logging: Option<bool>,
         project: &Self::ProjectContext,
     ) -> Result<ApiDeployment, GolemError>;
     async fn list(
@@ -0,0 +1,27 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::GolemError;
+use async_trait::async_trait;
+use url::Url;
+
+#[async_trait]
+pub trait ClusterClient {
+    /// Base URL of the service's HTTP endpoint
+    fn url(&self) -> &Url;
+    /// Calls the service's healthcheck endpoint
+    async fn healthcheck(&self) -> Result<(), GolemError>;
+    /// Gets the service's metrics in the Prometheus text exposition format
+    async fn metrics(&self) -> Result<String, GolemError>;
+}
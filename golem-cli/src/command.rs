@@ -18,6 +18,7 @@ use golem_common::uri::oss::uri::ComponentUri;
 
 pub mod api_definition;
 pub mod api_deployment;
+pub mod cluster;
 pub mod component;
 pub mod profile;
 pub mod worker;
@@ -36,7 +36,8 @@ pub enum ApiDefinitionSubcommand<ProjectRef: clap::Args> {
 
     /// Creates an api definition
     ///
-    /// Golem API definition file format expected
+    /// Golem API definition file format expected. Files ending in `.yaml` or `.yml` are read as
+    /// declarative API definitions, supporting `includes`, `variables` and route `templates`
     #[command(alias = "create")]
     Add {
         /// The newly created component's owner project
@@ -50,7 +51,8 @@ pub enum ApiDefinitionSubcommand<ProjectRef: clap::Args> {
 
     /// Updates an api definition
     ///
-    /// Golem API definition file format expected
+    /// Golem API definition file format expected. Files ending in `.yaml` or `.yml` are read as
+    /// declarative API definitions, supporting `includes`, `variables` and route `templates`
     #[command()]
     Update {
         /// The newly created component's owner project
@@ -76,6 +78,20 @@ pub enum ApiDefinitionSubcommand<ProjectRef: clap::Args> {
         definition: PathBufOrStdin, // TODO: validate exists
     },
 
+    /// Evaluates the route examples of an api definition
+    ///
+    /// The api definition is compiled, but not stored. Fails if any of the examples does not hold.
+    #[command()]
+    Test {
+        /// The newly created component's owner project
+        #[command(flatten)]
+        project_ref: ProjectRef,
+
+        /// The Golem API definition file
+        #[arg(value_hint = clap::ValueHint::FilePath)]
+        definition: PathBufOrStdin, // TODO: validate exists
+    },
+
     /// Retrieves metadata about an existing api definition
     #[command()]
     Get {
@@ -145,6 +161,13 @@ impl<ProjectRef: clap::Args + Send + Sync + 'static> ApiDefinitionSubcommand<Pro
                 let project_id = projects.resolve_id_or_default(project_ref).await?;
                 service.import(definition, &project_id).await
             }
+            ApiDefinitionSubcommand::Test {
+                project_ref,
+                definition,
+            } => {
+                let project_id = projects.resolve_id_or_default(project_ref).await?;
+                service.test(definition, &project_id).await
+            }
             ApiDefinitionSubcommand::List { project_ref, id } => {
                 let project_id = projects.resolve_id_or_default(project_ref).await?;
                 service.list(id, &project_id).await
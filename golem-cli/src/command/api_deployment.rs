@@ -36,6 +36,10 @@ pub enum ApiDeploymentSubcommand<ProjectRef: clap::Args> {
 
         #[arg(short, long)]
         subdomain: Option<String>,
+
+        /// Emit the output of Rib `log` statements of the deployed definitions, kept as it is when not given
+        #[arg(long)]
+        rib_logging: Option<bool>,
     },
 
     /// Get api deployment
@@ -79,10 +83,11 @@ impl<ProjectRef: clap::Args + Send + Sync + 'static> ApiDeploymentSubcommand<Pro
                 definitions,
                 host,
                 subdomain,
+                rib_logging,
             } => {
                 let project_id = projects.resolve_id_or_default(project_ref).await?;
                 service
-                    .deploy(definitions, host, subdomain, &project_id)
+                    .deploy(definitions, host, subdomain, rib_logging, &project_id)
                     .await
             }
             ApiDeploymentSubcommand::Get { site } => service.get(site).await,
@@ -0,0 +1,76 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::{Format, GolemError, GolemResult};
+use crate::service::cluster::ClusterService;
+use clap::Subcommand;
+use url::Url;
+
+#[derive(Subcommand, Debug)]
+#[command()]
+pub enum ClusterSubcommand {
+    /// Show the aggregated health of the cluster
+    ///
+    /// Exits with a non-zero code if any service is unhealthy, no worker executors are online,
+    /// some shards are unassigned, or an error rate exceeds the given maximum.
+    #[command()]
+    Status {
+        /// HTTP URL of the worker service, defaults to the profile's worker URL if it differs
+        /// from the component URL
+        #[arg(long)]
+        worker_service_url: Option<Url>,
+
+        /// HTTP URL of the shard manager, defaults to the one configured in the profile
+        #[arg(long)]
+        shard_manager_url: Option<Url>,
+
+        /// Maximum accepted percentage of failed API requests
+        #[arg(long)]
+        max_error_rate: Option<f64>,
+    },
+}
+
+impl ClusterSubcommand {
+    /// The worker service and shard manager URLs given on the command line
+    pub fn service_urls(&self) -> (Option<Url>, Option<Url>) {
+        match self {
+            ClusterSubcommand::Status {
+                worker_service_url,
+                shard_manager_url,
+                ..
+            } => (worker_service_url.clone(), shard_manager_url.clone()),
+        }
+    }
+
+    pub async fn handle(
+        self,
+        format: Format,
+        service: &(dyn ClusterService + Send + Sync),
+    ) -> Result<GolemResult, GolemError> {
+        match self {
+            ClusterSubcommand::Status { max_error_rate, .. } => {
+                let status = service.status(max_error_rate).await?;
+
+                if status.healthy {
+                    Ok(GolemResult::Ok(Box::new(status)))
+                } else {
+                    // The status is still printed, so scripts see what is wrong
+                    let problems = status.problems.join("\n");
+                    GolemResult::Ok(Box::new(status)).print(format);
+                    Err(GolemError(format!("Cluster is unhealthy:\n{problems}")))
+                }
+            }
+        }
+    }
+}
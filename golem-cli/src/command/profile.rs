@@ -93,6 +93,10 @@ pub struct OssProfileAdd {
     #[arg(short, long)]
     worker_url: Option<Url>,
 
+    /// HTTP URL of Golem Shard Manager, used by the cluster commands
+    #[arg(long)]
+    shard_manager_url: Option<Url>,
+
     /// Accept invalid certificates.
     ///
     /// Disables certificate validation.
@@ -263,6 +267,7 @@ impl UniversalProfileAddSubCommand {
                         name,
                         component_url,
                         worker_url,
+                        shard_manager_url,
                         allow_insecure,
                         default_format,
                     },
@@ -270,6 +275,7 @@ impl UniversalProfileAddSubCommand {
                 let profile = Profile::Golem(OssProfile {
                     url: component_url,
                     worker_url,
+                    shard_manager_url,
                     allow_insecure,
                     config: ProfileConfig { default_format },
                 });
@@ -412,6 +418,8 @@ pub struct ProfileView {
     pub cloud_url: Option<Url>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub worker_url: Option<Url>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub shard_manager_url: Option<Url>,
     #[serde(skip_serializing_if = "std::ops::Not::not", default)]
     pub allow_insecure: bool,
     #[serde(skip_serializing_if = "Option::is_none", default)]
@@ -433,6 +441,7 @@ impl ProfileView {
             Profile::Golem(OssProfile {
                 url,
                 worker_url,
+                shard_manager_url,
                 allow_insecure,
                 config,
             }) => ProfileView {
@@ -442,6 +451,7 @@ impl ProfileView {
                 url: Some(url),
                 cloud_url: None,
                 worker_url,
+                shard_manager_url,
                 allow_insecure,
                 authenticated: None,
                 config,
@@ -460,6 +470,7 @@ impl ProfileView {
                 url: custom_url,
                 cloud_url: custom_cloud_url,
                 worker_url: custom_worker_url,
+                shard_manager_url: None,
                 allow_insecure,
                 authenticated: Some(auth.is_some()),
                 config,
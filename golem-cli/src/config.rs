@@ -125,6 +125,8 @@ pub struct OssProfile {
     pub url: Url,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub worker_url: Option<Url>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub shard_manager_url: Option<Url>,
     #[serde(skip_serializing_if = "std::ops::Not::not", default)]
     pub allow_insecure: bool,
     #[serde(default)]
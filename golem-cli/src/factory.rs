@@ -17,8 +17,10 @@ use crate::clients::api_deployment::ApiDeploymentClient;
 use crate::clients::component::ComponentClient;
 use crate::clients::health_check::HealthCheckClient;
 use crate::clients::worker::WorkerClient;
+use crate::model::GolemError;
 use crate::service::api_definition::{ApiDefinitionService, ApiDefinitionServiceLive};
 use crate::service::api_deployment::{ApiDeploymentService, ApiDeploymentServiceLive};
+use crate::service::cluster::ClusterService;
 use crate::service::component::{ComponentService, ComponentServiceLive};
 use crate::service::deploy::{DeployService, DeployServiceLive};
 use crate::service::project::ProjectResolver;
@@ -26,6 +28,7 @@ use crate::service::version::{VersionService, VersionServiceLive};
 use crate::service::worker::{WorkerService, WorkerServiceLive};
 use std::fmt::Display;
 use std::sync::Arc;
+use url::Url;
 
 pub trait ServiceFactory {
     type ProjectRef: Send + Sync + 'static;
@@ -93,6 +96,12 @@ pub trait ServiceFactory {
         })
     }
 
+    fn cluster_service(
+        &self,
+        worker_service_url: Option<Url>,
+        shard_manager_url: Option<Url>,
+    ) -> Result<Arc<dyn ClusterService + Send + Sync>, GolemError>;
+
     fn deploy_service(
         &self,
     ) -> Arc<dyn DeployService<ProjectContext = Self::ProjectContext> + Send + Sync>
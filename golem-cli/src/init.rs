@@ -478,6 +478,7 @@ fn make_oss_custom_profile() -> Result<Profile, GolemError> {
     Ok(Profile::Golem(OssProfile {
         url,
         worker_url,
+        shard_manager_url: None,
         allow_insecure,
         config,
     }))
@@ -493,6 +494,7 @@ fn make_oss_default_profile() -> Result<Profile, GolemError> {
     Ok(Profile::Golem(OssProfile {
         url,
         worker_url: None,
+        shard_manager_url: None,
         allow_insecure: false,
         config,
     }))
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod api_definition;
 pub mod component;
 pub mod deploy;
 pub mod invoke_result_view;
@@ -662,6 +663,9 @@ pub struct ApiDeployment {
     #[serde(default)]
     pub project_id: Option<Uuid>,
     pub site: ApiSite,
+    #[serde(rename = "ribLogging")]
+    #[serde(default)]
+    pub rib_logging: bool,
     #[serde(rename = "createdAt")]
     pub created_at: Option<DateTime<Utc>>,
 }
@@ -672,6 +676,7 @@ impl From<golem_client::model::ApiDeployment> for ApiDeployment {
             api_definitions: value.api_definitions,
             project_id: None,
             site: value.site,
+            rib_logging: value.rib_logging,
             created_at: value.created_at,
         }
     }
@@ -0,0 +1,620 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Declarative API definition file format.
+//!
+//! A declarative API definition is a YAML document describing the same `HttpApiDefinitionRequest`
+//! as the JSON format, with a few additional top level keys. These are resolved by the CLI before
+//! the definition gets registered, the worker service only ever receives the resulting plain
+//! definition and never reads included files itself:
+//!
+//! ```yaml
+//! id: shopping-cart-api
+//! version: 0.0.1
+//! includes:
+//!   - common/routes.yaml
+//! variables:
+//!   component-id: 15d70aa5-2e23-4ee3-b65c-4e1d702836a3
+//! templates:
+//!   cart-route:
+//!     binding:
+//!       componentId:
+//!         componentId: '{{component-id}}'
+//!         version: 0
+//!       workerName: 'let id: u64 = request.path.user-id; "cart-${id}"'
+//!       response: '${ {status: 200u64, body: golem:it/api.{ {{function}} }(id)} }'
+//! routes:
+//!   - template: cart-route
+//!     method: Get
+//!     path: /{user-id}/contents
+//!     variables:
+//!       function: get-cart-contents
+//! ```
+//!
+//! - `includes` lists other declarative files (relative to the including file) whose
+//!   `variables`, `templates` and `routes` are merged into the including document. Definitions
+//!   of the including document take precedence, included routes come first. Included files can
+//!   not contain any other top level keys.
+//! - `variables` are substituted in every string of the document using the `{{name}}` syntax.
+//!   Templates and routes can define their own `variables`, which override the global ones for
+//!   that route; route variables take precedence over template variables.
+//! - `templates` are reusable route fragments. A route referring to a template with `template`
+//!   is deep merged on top of it.
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Component, Path, PathBuf};
+
+use serde_yaml::{Mapping, Value};
+
+use crate::model::GolemError;
+
+const INCLUDES_KEY: &str = "includes";
+const VARIABLES_KEY: &str = "variables";
+const TEMPLATES_KEY: &str = "templates";
+const ROUTES_KEY: &str = "routes";
+const TEMPLATE_KEY: &str = "template";
+
+/// Returns true if the given file should be treated as a declarative API definition
+pub fn is_declarative_api_definition(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|ext| ext.to_str()),
+        Some("yaml") | Some("yml")
+    )
+}
+
+/// Resolves a declarative API definition read from `path` into the JSON representation of
+/// `HttpApiDefinitionRequest`. Included files are read with `load`.
+pub fn resolve_api_definition<F>(
+    path: &Path,
+    source: &str,
+    load: F,
+) -> Result<serde_json::Value, GolemError>
+where
+    F: Fn(&Path) -> Result<String, GolemError>,
+{
+    let mut resolver = Resolver {
+        load,
+        in_progress: HashSet::new(),
+    };
+    let document = resolver.merged_document(path, source)?;
+    let resolved = document.resolve()?;
+
+    serde_json::to_value(resolved)
+        .map_err(|e| GolemError(format!("Failed to convert API definition to JSON: {e}")))
+}
+
+#[derive(Default)]
+struct Document {
+    /// All top level keys which are not part of the declarative format
+    definition: Mapping,
+    variables: BTreeMap<String, String>,
+    templates: BTreeMap<String, Value>,
+    routes: Vec<Value>,
+}
+
+impl Document {
+    fn parse(path: &Path, source: &str) -> Result<(Self, Vec<PathBuf>), GolemError> {
+        let value: Value = serde_yaml::from_str(source).map_err(|e| {
+            GolemError(format!(
+                "Failed to parse API definition {}: {e}",
+                path.display()
+            ))
+        })?;
+        let mut definition = match value {
+            Value::Mapping(mapping) => mapping,
+            _ => {
+                return Err(GolemError(format!(
+                    "API definition {} must be a mapping",
+                    path.display()
+                )))
+            }
+        };
+
+        let includes = match definition.remove(INCLUDES_KEY) {
+            None => vec![],
+            Some(Value::Sequence(includes)) => includes
+                .into_iter()
+                .map(|include| match include {
+                    Value::String(include) => Ok(relative_to(path, &include)),
+                    _ => Err(GolemError(format!(
+                        "Invalid include in {}, expected a file path",
+                        path.display()
+                    ))),
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            Some(_) => {
+                return Err(GolemError(format!(
+                    "Invalid {INCLUDES_KEY} in {}, expected a list of file paths",
+                    path.display()
+                )))
+            }
+        };
+
+        let variables = match definition.remove(VARIABLES_KEY) {
+            None => BTreeMap::new(),
+            Some(variables) => parse_variables(&variables).map_err(|err| {
+                GolemError(format!(
+                    "Invalid variables in {}: {}",
+                    path.display(),
+                    err.0
+                ))
+            })?,
+        };
+
+        let templates = match definition.remove(TEMPLATES_KEY) {
+            None => BTreeMap::new(),
+            Some(Value::Mapping(templates)) => templates
+                .into_iter()
+                .map(|(name, template)| match name {
+                    Value::String(name) => Ok((name, template)),
+                    _ => Err(GolemError(format!(
+                        "Invalid template name in {}",
+                        path.display()
+                    ))),
+                })
+                .collect::<Result<BTreeMap<_, _>, _>>()?,
+            Some(_) => {
+                return Err(GolemError(format!(
+                    "Invalid {TEMPLATES_KEY} in {}, expected a mapping",
+                    path.display()
+                )))
+            }
+        };
+
+        let routes = match definition.remove(ROUTES_KEY) {
+            None => vec![],
+            Some(Value::Sequence(routes)) => routes,
+            Some(_) => {
+                return Err(GolemError(format!(
+                    "Invalid {ROUTES_KEY} in {}, expected a list of routes",
+                    path.display()
+                )))
+            }
+        };
+
+        Ok((
+            Document {
+                definition,
+                variables,
+                templates,
+                routes,
+            },
+            includes,
+        ))
+    }
+
+    /// Merges `self` on top of an included document
+    fn merge_onto(self, mut included: Document) -> Document {
+        included.variables.extend(self.variables);
+        included.templates.extend(self.templates);
+        included.routes.extend(self.routes);
+        Document {
+            definition: self.definition,
+            variables: included.variables,
+            templates: included.templates,
+            routes: included.routes,
+        }
+    }
+
+    fn resolve(self) -> Result<Value, GolemError> {
+        let mut routes = Vec::with_capacity(self.routes.len());
+        for route in self.routes {
+            routes.push(resolve_route(route, &self.templates, &self.variables)?);
+        }
+
+        let mut definition = substitute(Value::Mapping(self.definition), &self.variables)?;
+        if let Value::Mapping(definition) = &mut definition {
+            definition.insert(Value::from(ROUTES_KEY), Value::Sequence(routes));
+        }
+        Ok(definition)
+    }
+}
+
+struct Resolver<F> {
+    load: F,
+    in_progress: HashSet<PathBuf>,
+}
+
+impl<F> Resolver<F>
+where
+    F: Fn(&Path) -> Result<String, GolemError>,
+{
+    fn merged_document(&mut self, path: &Path, source: &str) -> Result<Document, GolemError> {
+        let key = canonical_path(path);
+        if !self.in_progress.insert(key.clone()) {
+            return Err(GolemError(format!(
+                "API definition {} is included recursively",
+                path.display()
+            )));
+        }
+
+        let (document, includes) = Document::parse(path, source)?;
+
+        let mut included = Document::default();
+        for include in includes {
+            let include_source = (self.load)(&include)?;
+            let include_document = self.merged_document(&include, &include_source)?;
+            if !include_document.definition.is_empty() {
+                let keys = include_document
+                    .definition
+                    .keys()
+                    .map(|key| key.as_str().unwrap_or("<non-string key>"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                return Err(GolemError(format!(
+                    "Included API definition {} can only contain {INCLUDES_KEY}, {VARIABLES_KEY}, {TEMPLATES_KEY} and {ROUTES_KEY}, found: {keys}",
+                    include.display()
+                )));
+            }
+            included = include_document.merge_onto(included);
+        }
+
+        self.in_progress.remove(&key);
+        Ok(document.merge_onto(included))
+    }
+}
+
+fn relative_to(path: &Path, include: &str) -> PathBuf {
+    match path.parent() {
+        Some(parent) => normalize_path(&parent.join(include)),
+        None => normalize_path(Path::new(include)),
+    }
+}
+
+/// The identity of a file for cycle detection; falls back to the lexically normalized path
+/// if the file can not be canonicalized (for example when it is not read from the file system)
+fn canonical_path(path: &Path) -> PathBuf {
+    std::fs::canonicalize(path).unwrap_or_else(|_| normalize_path(path))
+}
+
+/// Removes `.` and resolves `..` components without accessing the file system
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut result = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match result.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    result.pop();
+                }
+                Some(Component::RootDir) | Some(Component::Prefix(_)) => {}
+                _ => result.push(component),
+            },
+            _ => result.push(component),
+        }
+    }
+    result
+}
+
+fn parse_variables(value: &Value) -> Result<BTreeMap<String, String>, GolemError> {
+    match value {
+        Value::Mapping(variables) => variables
+            .iter()
+            .map(|(name, value)| {
+                let name = name
+                    .as_str()
+                    .ok_or(GolemError("variable names must be strings".to_string()))?;
+                let value = match value {
+                    Value::String(s) => s.clone(),
+                    Value::Number(n) => n.to_string(),
+                    Value::Bool(b) => b.to_string(),
+                    _ => {
+                        return Err(GolemError(format!(
+                            "variable {name} must be a string, number or boolean"
+                        )))
+                    }
+                };
+                Ok((name.to_string(), value))
+            })
+            .collect(),
+        _ => Err(GolemError("expected a mapping".to_string())),
+    }
+}
+
+fn resolve_route(
+    route: Value,
+    templates: &BTreeMap<String, Value>,
+    variables: &BTreeMap<String, String>,
+) -> Result<Value, GolemError> {
+    let mut route = match route {
+        Value::Mapping(route) => route,
+        _ => return Err(GolemError("Every route must be a mapping".to_string())),
+    };
+
+    let local_variables = match route.remove(VARIABLES_KEY) {
+        None => BTreeMap::new(),
+        Some(local_variables) => parse_variables(&local_variables)
+            .map_err(|err| GolemError(format!("Invalid route variables: {}", err.0)))?,
+    };
+    let mut route_variables = variables.clone();
+
+    let route = match route.remove(TEMPLATE_KEY) {
+        None => Value::Mapping(route),
+        Some(Value::String(name)) => {
+            let mut template = match templates.get(&name) {
+                Some(Value::Mapping(template)) => template.clone(),
+                Some(_) => {
+                    return Err(GolemError(format!(
+                        "Route template {name} must be a mapping"
+                    )))
+                }
+                None => return Err(GolemError(format!("Route template {name} is not defined"))),
+            };
+            if let Some(template_variables) = template.remove(VARIABLES_KEY) {
+                let template_variables = parse_variables(&template_variables).map_err(|err| {
+                    GolemError(format!("Invalid variables in template {name}: {}", err.0))
+                })?;
+                route_variables.extend(template_variables);
+            }
+            deep_merge(Value::Mapping(template), Value::Mapping(route))
+        }
+        Some(_) => {
+            return Err(GolemError(
+                "Route template reference must be a template name".to_string(),
+            ))
+        }
+    };
+
+    route_variables.extend(local_variables);
+    substitute(route, &route_variables)
+}
+
+/// Merges `overlay` on top of `base`; mappings are merged key by key, everything else is replaced
+fn deep_merge(base: Value, overlay: Value) -> Value {
+    match (base, overlay) {
+        (Value::Mapping(mut base), Value::Mapping(overlay)) => {
+            for (key, value) in overlay {
+                let merged = match base.remove(&key) {
+                    Some(base_value) => deep_merge(base_value, value),
+                    None => value,
+                };
+                base.insert(key, merged);
+            }
+            Value::Mapping(base)
+        }
+        (_, overlay) => overlay,
+    }
+}
+
+fn substitute(value: Value, variables: &BTreeMap<String, String>) -> Result<Value, GolemError> {
+    match value {
+        Value::String(s) => Ok(Value::String(substitute_str(&s, variables)?)),
+        Value::Sequence(values) => Ok(Value::Sequence(
+            values
+                .into_iter()
+                .map(|value| substitute(value, variables))
+                .collect::<Result<Vec<_>, _>>()?,
+        )),
+        Value::Mapping(mapping) => {
+            let mut result = Mapping::new();
+            for (key, value) in mapping {
+                result.insert(key, substitute(value, variables)?);
+            }
+            Ok(Value::Mapping(result))
+        }
+        other => Ok(other),
+    }
+}
+
+fn substitute_str(s: &str, variables: &BTreeMap<String, String>) -> Result<String, GolemError> {
+    let mut result = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        let name = rest[start + 2..start + 2 + end].trim();
+        // Rib record and block syntax can also contain `{{`, only simple names are variables
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+        {
+            result.push_str(&rest[..start + 2]);
+            rest = &rest[start + 2..];
+            continue;
+        }
+        let value = variables
+            .get(name)
+            .ok_or(GolemError(format!("Variable {name} is not defined")))?;
+        result.push_str(&rest[..start]);
+        result.push_str(value);
+        rest = &rest[start + 2 + end + 2..];
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use std::collections::HashMap;
+    use std::path::{Path, PathBuf};
+
+    use serde_json::json;
+
+    use crate::model::api_definition::resolve_api_definition;
+    use crate::model::GolemError;
+
+    fn resolve(main: &str, files: &[(&str, &str)]) -> Result<serde_json::Value, GolemError> {
+        let files: HashMap<PathBuf, String> = files
+            .iter()
+            .map(|(path, source)| (PathBuf::from(path), source.to_string()))
+            .collect();
+        resolve_api_definition(Path::new("api/main.yaml"), main, |path| {
+            files
+                .get(path)
+                .cloned()
+                .ok_or(GolemError(format!("not found: {}", path.display())))
+        })
+    }
+
+    #[test]
+    fn plain_definition_is_unchanged() {
+        let result = resolve(
+            "id: api\nversion: 0.0.1\nroutes:\n- method: Get\n  path: /a\n",
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(
+            result,
+            json!({"id": "api", "version": "0.0.1", "routes": [{"method": "Get", "path": "/a"}]})
+        );
+    }
+
+    #[test]
+    fn templates_and_variables_are_resolved() {
+        let main = r#"
+id: api
+version: '{{version}}'
+variables:
+  version: 0.0.1
+  component: c1
+templates:
+  get:
+    method: Get
+    binding:
+      componentId: '{{component}}'
+      response: '${ {body: {{function}}(1)} }'
+routes:
+- template: get
+  path: /a
+  variables:
+    function: get-a
+- template: get
+  path: /b
+  binding:
+    componentId: c2
+  variables:
+    function: get-b
+"#;
+        let result = resolve(main, &[]).unwrap();
+
+        assert_eq!(
+            result,
+            json!({
+                "id": "api",
+                "version": "0.0.1",
+                "routes": [
+                    {"method": "Get", "path": "/a", "binding": {"componentId": "c1", "response": "${ {body: get-a(1)} }"}},
+                    {"method": "Get", "path": "/b", "binding": {"componentId": "c2", "response": "${ {body: get-b(1)} }"}}
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn includes_are_merged() {
+        let main = r#"
+id: api
+version: 0.0.1
+includes:
+- common.yaml
+variables:
+  prefix: /v2
+routes:
+- method: Post
+  path: '{{prefix}}/b'
+"#;
+        let common = r#"
+variables:
+  prefix: /v1
+routes:
+- method: Get
+  path: '{{prefix}}/a'
+"#;
+        let result = resolve(main, &[("api/common.yaml", common)]).unwrap();
+
+        assert_eq!(
+            result,
+            json!({
+                "id": "api",
+                "version": "0.0.1",
+                "routes": [
+                    {"method": "Get", "path": "/v2/a"},
+                    {"method": "Post", "path": "/v2/b"}
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn recursive_includes_are_rejected() {
+        let main = "id: api\nversion: 0.0.1\nincludes:\n- main.yaml\n";
+        let result = resolve(main, &[("api/main.yaml", main)]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn relative_recursive_includes_are_rejected() {
+        let main = "id: api\nversion: 0.0.1\nincludes:\n- ./main.yaml\n";
+        let common = "includes:\n- ../api/common.yaml\n";
+        let files = [("api/main.yaml", main), ("api/common.yaml", common)];
+
+        assert!(resolve(main, &files).is_err());
+        assert!(resolve(
+            "id: api\nversion: 0.0.1\nincludes:\n- ./common.yaml\n",
+            &files
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn unsupported_keys_in_includes_are_rejected() {
+        let main = "id: api\nversion: 0.0.1\nincludes:\n- common.yaml\n";
+        let common = "id: other\nroutes:\n- method: Get\n  path: /a\n";
+        let result = resolve(main, &[("api/common.yaml", common)]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn template_variables_are_not_sent() {
+        let main = r#"
+id: api
+version: 0.0.1
+templates:
+  get:
+    method: Get
+    variables:
+      prefix: /v1
+      name: default
+routes:
+- template: get
+  path: '{{prefix}}/{{name}}'
+  variables:
+    name: a
+"#;
+        let result = resolve(main, &[]).unwrap();
+
+        assert_eq!(
+            result,
+            json!({
+                "id": "api",
+                "version": "0.0.1",
+                "routes": [{"method": "Get", "path": "/v1/a"}]
+            })
+        );
+    }
+
+    #[test]
+    fn undefined_variables_are_rejected() {
+        let result = resolve("id: api\nversion: '{{version}}'\n", &[]);
+
+        assert!(result.is_err());
+    }
+}
@@ -257,7 +257,9 @@ pub mod fmt {
 pub mod api_definition {
     use crate::model::text::fmt::*;
     use cli_table::{format::Justify, Table};
-    use golem_client::model::{HttpApiDefinitionWithTypeInfo, RouteWithTypeInfo};
+    use golem_client::model::{
+        HttpApiDefinitionWithTypeInfo, RouteExampleResult, RouteWithTypeInfo,
+    };
     use golem_common::model::ComponentId;
     use golem_common::uri::oss::urn::ComponentUrn;
     use serde::{Deserialize, Serialize};
@@ -399,6 +401,45 @@ pub mod api_definition {
             print_table::<_, HttpApiDefinitionTableView>(self);
         }
     }
+
+    #[derive(Table)]
+    struct RouteExampleResultTableView {
+        #[table(title = "Method")]
+        pub method: String,
+        #[table(title = "Path")]
+        pub path: String,
+        #[table(title = "Example")]
+        pub example: String,
+        #[table(title = "Result")]
+        pub result: String,
+    }
+
+    impl From<&RouteExampleResult> for RouteExampleResultTableView {
+        fn from(value: &RouteExampleResult) -> Self {
+            Self {
+                method: value.method.to_string(),
+                path: value.path.to_string(),
+                example: value.example.to_string(),
+                result: match &value.failure {
+                    None => "passed".to_string(),
+                    Some(failure) => failure.to_string(),
+                },
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ApiDefinitionTestView(pub Vec<RouteExampleResult>);
+
+    impl TextFormat for ApiDefinitionTestView {
+        fn print(&self) {
+            if self.0.is_empty() {
+                println!("No route examples found");
+            } else {
+                print_table::<_, RouteExampleResultTableView>(&self.0);
+            }
+        }
+    }
 }
 
 pub mod api_deployment {
@@ -461,6 +502,92 @@ pub mod api_deployment {
     }
 }
 
+pub mod cluster {
+    use crate::model::text::fmt::*;
+    use cli_table::Table;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct ServiceStatus {
+        pub service: String,
+        pub url: String,
+        pub healthy: bool,
+        pub error: Option<String>,
+        pub executors_online: Option<u64>,
+        pub shards_assigned: Option<u64>,
+        pub shards_unassigned: Option<u64>,
+        /// Percentage of failed API requests since the service started
+        pub error_rate: Option<f64>,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct ClusterStatusView {
+        pub healthy: bool,
+        pub services: Vec<ServiceStatus>,
+        pub problems: Vec<String>,
+    }
+
+    #[derive(Table)]
+    struct ServiceStatusTableView {
+        #[table(title = "Service")]
+        pub service: String,
+        #[table(title = "URL")]
+        pub url: String,
+        #[table(title = "Status")]
+        pub status: String,
+        #[table(title = "Executors")]
+        pub executors_online: String,
+        #[table(title = "Shards assigned")]
+        pub shards_assigned: String,
+        #[table(title = "Shards unassigned")]
+        pub shards_unassigned: String,
+        #[table(title = "Error rate")]
+        pub error_rate: String,
+    }
+
+    fn format_optional<T: ToString>(value: &Option<T>) -> String {
+        value
+            .as_ref()
+            .map(|value| value.to_string())
+            .unwrap_or_else(|| "-".to_string())
+    }
+
+    impl From<&ServiceStatus> for ServiceStatusTableView {
+        fn from(value: &ServiceStatus) -> Self {
+            Self {
+                service: value.service.clone(),
+                url: value.url.clone(),
+                status: if value.healthy {
+                    "healthy".to_string()
+                } else {
+                    format_warn("unhealthy")
+                },
+                executors_online: format_optional(&value.executors_online),
+                shards_assigned: format_optional(&value.shards_assigned),
+                shards_unassigned: format_optional(&value.shards_unassigned),
+                error_rate: format_optional(&value.error_rate.map(|rate| format!("{rate:.2}%"))),
+            }
+        }
+    }
+
+    impl TextFormat for ClusterStatusView {
+        fn print(&self) {
+            print_table::<_, ServiceStatusTableView>(&self.services);
+
+            if self.healthy {
+                println!("\nCluster is {}", format_message_highlight("healthy"));
+            } else {
+                println!("\nCluster is {}:", format_warn("unhealthy"));
+                for problem in &self.problems {
+                    println!("  - {problem}");
+                }
+            }
+        }
+    }
+}
+
 pub mod component {
     use crate::model::component::ComponentView;
     use crate::model::text::fmt::*;
@@ -670,6 +797,10 @@ pub mod profile {
                 fields.field("Using default URLs", &true);
             }
 
+            if let Some(shard_manager_url) = &self.shard_manager_url {
+                fields.field("Shard manager URL", shard_manager_url);
+            }
+
             fields.build()
         }
     }
@@ -1202,6 +1333,23 @@ pub mod worker {
                     println!("{}", format_message_highlight("RESTART"));
                     println!("{pad}at:                {}", format_id(&params.timestamp));
                 }
+                PublicOplogEntry::CancellationRequested(params) => {
+                    println!("{}", format_message_highlight("CANCELLATION REQUESTED"));
+                    println!("{pad}at:                {}", format_id(&params.timestamp));
+                    println!(
+                        "{pad}idempotency key:   {}",
+                        format_id(&params.idempotency_key)
+                    );
+                    println!("{pad}reason:            {}", format_id(&params.reason));
+                }
+                PublicOplogEntry::CancellationAcknowledged(params) => {
+                    println!("{}", format_message_highlight("CANCELLATION ACKNOWLEDGED"));
+                    println!("{pad}at:                {}", format_id(&params.timestamp));
+                    println!(
+                        "{pad}idempotency key:   {}",
+                        format_id(&params.idempotency_key)
+                    );
+                }
             }
         }
     }
@@ -15,16 +15,19 @@
 use std::fmt::Display;
 
 use std::io::Read;
+use std::path::PathBuf;
 
 use async_trait::async_trait;
 
 use golem_client::model::HttpApiDefinitionRequest;
 use golem_client::model::HttpApiDefinitionWithTypeInfo;
+use golem_client::model::RouteExampleResult;
 
 use crate::clients::api_definition::ApiDefinitionClient;
 use tokio::fs::read_to_string;
 use tracing::info;
 
+use crate::model::api_definition::{is_declarative_api_definition, resolve_api_definition};
 use crate::model::{ApiDefinitionId, ApiDefinitionVersion, GolemError, PathBufOrStdin};
 use crate::oss::model::OssContext;
 
@@ -60,20 +63,7 @@ async fn create_or_update_api_definition<
 ) -> Result<HttpApiDefinitionWithTypeInfo, GolemError> {
     info!("{action} api definition from {path:?}");
 
-    let definition_str: String = match path {
-        PathBufOrStdin::Path(path) => read_to_string(path)
-            .await
-            .map_err(|e| GolemError(format!("Failed to read from file: {e:?}")))?,
-        PathBufOrStdin::Stdin => {
-            let mut content = String::new();
-
-            let _ = std::io::stdin()
-                .read_to_string(&mut content)
-                .map_err(|e| GolemError(format!("Failed to read stdin: {e:?}")))?;
-
-            content
-        }
-    };
+    let (declarative_path, definition_str) = read_api_definition(path).await?;
 
     match action {
         Action::Import => {
@@ -83,14 +73,12 @@ async fn create_or_update_api_definition<
             Ok(client.import_open_api(&value).await?)
         }
         Action::Create => {
-            let value: HttpApiDefinitionRequest = serde_json::from_str(definition_str.as_str())
-                .map_err(|e| GolemError(format!("Failed to parse HttpApiDefinition: {e:?}")))?;
+            let value = parse_api_definition(declarative_path, &definition_str)?;
 
             Ok(client.create_definition(&value).await?)
         }
         Action::Update => {
-            let value: HttpApiDefinitionRequest = serde_json::from_str(definition_str.as_str())
-                .map_err(|e| GolemError(format!("Failed to parse HttpApiDefinition: {e:?}")))?;
+            let value = parse_api_definition(declarative_path, &definition_str)?;
 
             Ok(client
                 .update_definition(&value.id, &value.version, &value)
@@ -99,6 +87,55 @@ async fn create_or_update_api_definition<
     }
 }
 
+async fn read_api_definition(
+    path: PathBufOrStdin,
+) -> Result<(Option<PathBuf>, String), GolemError> {
+    let declarative_path = match &path {
+        PathBufOrStdin::Path(path) if is_declarative_api_definition(path) => Some(path.clone()),
+        _ => None,
+    };
+
+    let definition_str: String = match path {
+        PathBufOrStdin::Path(path) => read_to_string(path)
+            .await
+            .map_err(|e| GolemError(format!("Failed to read from file: {e:?}")))?,
+        PathBufOrStdin::Stdin => {
+            let mut content = String::new();
+
+            let _ = std::io::stdin()
+                .read_to_string(&mut content)
+                .map_err(|e| GolemError(format!("Failed to read stdin: {e:?}")))?;
+
+            content
+        }
+    };
+
+    Ok((declarative_path, definition_str))
+}
+
+fn parse_api_definition(
+    declarative_path: Option<PathBuf>,
+    definition_str: &str,
+) -> Result<HttpApiDefinitionRequest, GolemError> {
+    match declarative_path {
+        Some(path) => {
+            let value = resolve_api_definition(&path, definition_str, |include| {
+                std::fs::read_to_string(include).map_err(|e| {
+                    GolemError(format!(
+                        "Failed to read included API definition {}: {e:?}",
+                        include.display()
+                    ))
+                })
+            })?;
+
+            serde_json::from_value(value)
+                .map_err(|e| GolemError(format!("Failed to parse HttpApiDefinition: {e:?}")))
+        }
+        None => serde_json::from_str(definition_str)
+            .map_err(|e| GolemError(format!("Failed to parse HttpApiDefinition: {e:?}"))),
+    }
+}
+
 #[async_trait]
 impl<C: golem_client::api::ApiDefinitionClient + Sync + Send> ApiDefinitionClient
     for ApiDefinitionClientLive<C>
@@ -156,6 +193,19 @@ impl<C: golem_client::api::ApiDefinitionClient + Sync + Send> ApiDefinitionClien
         create_or_update_api_definition(Action::Import, &self.client, path).await
     }
 
+    async fn test(
+        &self,
+        path: PathBufOrStdin,
+        _project: &Self::ProjectContext,
+    ) -> Result<Vec<RouteExampleResult>, GolemError> {
+        info!("Testing api definition from {path:?}");
+
+        let (declarative_path, definition_str) = read_api_definition(path).await?;
+        let value = parse_api_definition(declarative_path, &definition_str)?;
+
+        Ok(self.client.test_definition(&value).await?)
+    }
+
     async fn delete(
         &self,
         id: ApiDefinitionId,
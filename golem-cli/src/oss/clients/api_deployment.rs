@@ -38,6 +38,7 @@ impl<C: golem_client::api::ApiDeploymentClient + Sync + Send> ApiDeploymentClien
         definitions: Vec<ApiDefinitionIdWithVersion>,
         host: &str,
         subdomain: Option<String>,
+        rib_logging: Option<bool>,
         _project: &Self::ProjectContext,
     ) -> Result<ApiDeployment, GolemError> {
         info!(
@@ -61,6 +62,7 @@ impl<C: golem_client::api::ApiDeploymentClient + Sync + Send> ApiDeploymentClien
                 host: host.to_string(),
                 subdomain,
             },
+            rib_logging,
         };
 
         Ok(self.client.deploy(&deployment).await?.into())
@@ -0,0 +1,62 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::clients::cluster::ClusterClient;
+use async_trait::async_trait;
+use tracing::debug;
+use url::Url;
+
+use crate::model::GolemError;
+
+#[derive(Clone)]
+pub struct ClusterClientLive {
+    pub client: reqwest::Client,
+    pub base_url: Url,
+}
+
+impl ClusterClientLive {
+    async fn get(&self, path: &str) -> Result<String, GolemError> {
+        let url = self
+            .base_url
+            .join(path)
+            .map_err(|err| GolemError(format!("Invalid service URL {}: {err}", self.base_url)))?;
+
+        let response = self.client.get(url.clone()).send().await?;
+        let status = response.status();
+        if status.is_success() {
+            Ok(response.text().await?)
+        } else {
+            Err(GolemError(format!("Unexpected status {status} from {url}")))
+        }
+    }
+}
+
+#[async_trait]
+impl ClusterClient for ClusterClientLive {
+    fn url(&self) -> &Url {
+        &self.base_url
+    }
+
+    async fn healthcheck(&self) -> Result<(), GolemError> {
+        debug!("Checking health of {}", self.base_url);
+
+        self.get("healthcheck").await.map(|_| ())
+    }
+
+    async fn metrics(&self) -> Result<String, GolemError> {
+        debug!("Getting metrics of {}", self.base_url);
+
+        self.get("metrics").await
+    }
+}
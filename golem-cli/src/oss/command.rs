@@ -14,6 +14,7 @@
 
 use crate::command::api_definition::ApiDefinitionSubcommand;
 use crate::command::api_deployment::ApiDeploymentSubcommand;
+use crate::command::cluster::ClusterSubcommand;
 use crate::command::component::ComponentSubCommand;
 use crate::command::profile::ProfileSubCommand;
 use crate::command::worker::{OssWorkerUriArg, WorkerSubcommand};
@@ -78,6 +79,13 @@ pub enum OssCommand<ProfileAdd: clap::Args> {
         subcommand: ApiDeploymentSubcommand<OssContext>,
     },
 
+    /// Inspect the Golem cluster
+    #[command()]
+    Cluster {
+        #[command(subcommand)]
+        subcommand: ClusterSubcommand,
+    },
+
     /// Manage profiles
     #[command()]
     Profile {
@@ -22,10 +22,12 @@ use crate::factory::ServiceFactory;
 use crate::model::GolemError;
 use crate::oss::clients::api_definition::ApiDefinitionClientLive;
 use crate::oss::clients::api_deployment::ApiDeploymentClientLive;
+use crate::oss::clients::cluster::ClusterClientLive;
 use crate::oss::clients::component::ComponentClientLive;
 use crate::oss::clients::health_check::HealthCheckClientLive;
 use crate::oss::clients::worker::WorkerClientLive;
 use crate::oss::model::OssContext;
+use crate::service::cluster::{ClusterService, ClusterServiceLive};
 use crate::service::project::{ProjectResolver, ProjectResolverOss};
 use golem_client::Context;
 use itertools::Itertools;
@@ -37,6 +39,7 @@ use url::Url;
 pub struct OssServiceFactoryConfig {
     pub component_url: Url,
     pub worker_url: Url,
+    pub shard_manager_url: Option<Url>,
     pub service_http_client_config: HttpClientConfig,
     pub health_check_http_client_config: HttpClientConfig,
     pub allow_insecure: bool,
@@ -72,6 +75,7 @@ impl OssServiceFactory {
         OssServiceFactory::new(OssServiceFactoryConfig {
             component_url,
             worker_url,
+            shard_manager_url: profile.shard_manager_url.clone(),
             service_http_client_config: HttpClientConfig::new_for_service_calls(allow_insecure),
             health_check_http_client_config: HttpClientConfig::new_for_health_check(allow_insecure),
             allow_insecure,
@@ -187,6 +191,46 @@ impl ServiceFactory for OssServiceFactory {
             })
             .collect()
     }
+
+    fn cluster_service(
+        &self,
+        worker_service_url: Option<Url>,
+        shard_manager_url: Option<Url>,
+    ) -> Result<Arc<dyn ClusterService + Send + Sync>, GolemError> {
+        // Behind golem-router the worker URL is the router itself, which forwards
+        // the healthcheck and metrics endpoints to the component service
+        let worker_service_url = match worker_service_url {
+            Some(url) => url,
+            None if self.config.worker_url != self.config.component_url => {
+                self.config.worker_url.clone()
+            }
+            None => {
+                return Err(GolemError(
+                    "The worker service URL is the same as the component service URL, use --worker-service-url to check the worker service directly".to_string(),
+                ))
+            }
+        };
+        let shard_manager_url = shard_manager_url
+            .or_else(|| self.config.shard_manager_url.clone())
+            .ok_or_else(|| {
+                GolemError(
+                    "No shard manager URL configured, use --shard-manager-url or add it to the profile".to_string(),
+                )
+            })?;
+
+        let client = |base_url: Url| -> Arc<ClusterClientLive> {
+            Arc::new(ClusterClientLive {
+                client: self.http_client_health_check.clone(),
+                base_url,
+            })
+        };
+
+        Ok(Arc::new(ClusterServiceLive {
+            component_service: client(self.config.component_url.clone()),
+            worker_service: client(worker_service_url),
+            shard_manager: client(shard_manager_url),
+        }))
+    }
 }
 
 pub fn make_reqwest_client(config: &HttpClientConfig) -> Result<reqwest::Client, GolemError> {
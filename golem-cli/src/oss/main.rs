@@ -102,6 +102,13 @@ pub async fn async_main<ProfileAdd: Into<UniversalProfileAdd> + clap::Args>(
                 )
                 .await
         }
+        OssCommand::Cluster { subcommand } => {
+            let factory = OssServiceFactory::from_profile(&profile)?;
+            let (worker_service_url, shard_manager_url) = subcommand.service_urls();
+            let service = factory.cluster_service(worker_service_url, shard_manager_url)?;
+
+            subcommand.handle(format, service.as_ref()).await
+        }
         OssCommand::Profile { subcommand } => {
             subcommand.handle(cli_kind, &config_dir, profile_auth).await
         }
@@ -14,7 +14,8 @@
 
 use crate::clients::api_definition::ApiDefinitionClient;
 use crate::model::text::api_definition::{
-    ApiDefinitionAddView, ApiDefinitionGetView, ApiDefinitionImportView, ApiDefinitionUpdateView,
+    ApiDefinitionAddView, ApiDefinitionGetView, ApiDefinitionImportView, ApiDefinitionTestView,
+    ApiDefinitionUpdateView,
 };
 use crate::model::{
     ApiDefinitionId, ApiDefinitionVersion, GolemError, GolemResult, PathBufOrStdin,
@@ -50,6 +51,11 @@ pub trait ApiDefinitionService {
         id: Option<ApiDefinitionId>,
         project: &Self::ProjectContext,
     ) -> Result<GolemResult, GolemError>;
+    async fn test(
+        &self,
+        definition: PathBufOrStdin,
+        project: &Self::ProjectContext,
+    ) -> Result<GolemResult, GolemError>;
     async fn delete(
         &self,
         id: ApiDefinitionId,
@@ -118,6 +124,37 @@ impl<ProjectContext: Send + Sync> ApiDefinitionService
         Ok(GolemResult::Ok(Box::new(definitions)))
     }
 
+    async fn test(
+        &self,
+        definition: PathBufOrStdin,
+        project: &Self::ProjectContext,
+    ) -> Result<GolemResult, GolemError> {
+        let results = self.client.test(definition, project).await?;
+
+        let failures = results
+            .iter()
+            .filter(|result| !result.passed)
+            .map(|result| {
+                format!(
+                    "{} {} ({}): {}",
+                    result.method,
+                    result.path,
+                    result.example,
+                    result.failure.clone().unwrap_or_default()
+                )
+            })
+            .collect::<Vec<_>>();
+
+        if failures.is_empty() {
+            Ok(GolemResult::Ok(Box::new(ApiDefinitionTestView(results))))
+        } else {
+            Err(GolemError(format!(
+                "Route examples failed:\n{}",
+                failures.join("\n")
+            )))
+        }
+    }
+
     async fn delete(
         &self,
         id: ApiDefinitionId,
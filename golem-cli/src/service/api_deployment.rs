@@ -25,6 +25,7 @@ pub trait ApiDeploymentService {
         definitions: Vec<ApiDefinitionIdWithVersion>,
         host: String,
         subdomain: Option<String>,
+        rib_logging: Option<bool>,
         project: &Self::ProjectContext,
     ) -> Result<GolemResult, GolemError>;
     async fn get(&self, site: String) -> Result<GolemResult, GolemError>;
@@ -51,11 +52,12 @@ impl<ProjectContext: Send + Sync> ApiDeploymentService
         definitions: Vec<ApiDefinitionIdWithVersion>,
         host: String,
         subdomain: Option<String>,
+        rib_logging: Option<bool>,
         project: &Self::ProjectContext,
     ) -> Result<GolemResult, GolemError> {
         let deployment = self
             .client
-            .deploy(definitions, &host, subdomain, project)
+            .deploy(definitions, &host, subdomain, rib_logging, project)
             .await?;
 
         Ok(GolemResult::Ok(Box::new(deployment)))
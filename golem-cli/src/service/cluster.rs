@@ -0,0 +1,384 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::clients::cluster::ClusterClient;
+use crate::model::text::cluster::{ClusterStatusView, ServiceStatus};
+use crate::model::GolemError;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait ClusterService {
+    async fn status(&self, max_error_rate: Option<f64>) -> Result<ClusterStatusView, GolemError>;
+}
+
+pub struct ClusterServiceLive {
+    pub component_service: Arc<dyn ClusterClient + Send + Sync>,
+    pub worker_service: Arc<dyn ClusterClient + Send + Sync>,
+    pub shard_manager: Arc<dyn ClusterClient + Send + Sync>,
+}
+
+#[async_trait]
+impl ClusterService for ClusterServiceLive {
+    async fn status(&self, max_error_rate: Option<f64>) -> Result<ClusterStatusView, GolemError> {
+        let (component_service, worker_service, shard_manager) = tokio::join!(
+            api_service_status(
+                "component-service",
+                self.component_service.as_ref(),
+                max_error_rate
+            ),
+            api_service_status(
+                "worker-service",
+                self.worker_service.as_ref(),
+                max_error_rate
+            ),
+            shard_manager_status(self.shard_manager.as_ref()),
+        );
+
+        let mut services = Vec::new();
+        let mut problems = Vec::new();
+        for (status, service_problems) in [component_service, worker_service, shard_manager] {
+            services.push(status);
+            problems.extend(service_problems);
+        }
+
+        Ok(ClusterStatusView {
+            healthy: problems.is_empty(),
+            services,
+            problems,
+        })
+    }
+}
+
+fn unchecked_status(service: &str, client: &(dyn ClusterClient + Send + Sync)) -> ServiceStatus {
+    ServiceStatus {
+        service: service.to_string(),
+        url: client.url().to_string(),
+        healthy: false,
+        error: None,
+        executors_online: None,
+        shards_assigned: None,
+        shards_unassigned: None,
+        error_rate: None,
+    }
+}
+
+/// Checks a service and gets its metrics if the healthcheck succeeded
+async fn check(
+    status: &mut ServiceStatus,
+    client: &(dyn ClusterClient + Send + Sync),
+    problems: &mut Vec<String>,
+) -> Option<Result<String, GolemError>> {
+    match client.healthcheck().await {
+        Ok(()) => Some(client.metrics().await),
+        Err(error) => {
+            problems.push(format!(
+                "{} at {} is unhealthy: {}",
+                status.service, status.url, error.0
+            ));
+            status.error = Some(error.0);
+            None
+        }
+    }
+}
+
+async fn api_service_status(
+    service: &str,
+    client: &(dyn ClusterClient + Send + Sync),
+    max_error_rate: Option<f64>,
+) -> (ServiceStatus, Vec<String>) {
+    let mut status = unchecked_status(service, client);
+    let mut problems = Vec::new();
+
+    match check(&mut status, client, &mut problems).await {
+        Some(Ok(metrics)) => {
+            status.error_rate = api_error_rate(&metrics);
+            if let (Some(error_rate), Some(max_error_rate)) = (status.error_rate, max_error_rate) {
+                if error_rate > max_error_rate {
+                    problems.push(format!(
+                        "{service} error rate {error_rate:.2}% exceeds the maximum of {max_error_rate:.2}%"
+                    ));
+                }
+            }
+        }
+        Some(Err(error)) => {
+            if max_error_rate.is_some() {
+                problems.push(format!(
+                    "Failed to get the metrics of {service}: {}",
+                    error.0
+                ));
+                status.error = Some(error.0);
+            }
+        }
+        None => {}
+    }
+
+    status.healthy = status.error.is_none() && problems.is_empty();
+    (status, problems)
+}
+
+async fn shard_manager_status(
+    client: &(dyn ClusterClient + Send + Sync),
+) -> (ServiceStatus, Vec<String>) {
+    let mut status = unchecked_status("shard-manager", client);
+    let mut problems = Vec::new();
+
+    match check(&mut status, client, &mut problems).await {
+        Some(Ok(metrics)) => {
+            let executors_online = sum_metric(&metrics, "shard_manager_registered_pods", &[]);
+            let shards_assigned =
+                sum_metric(&metrics, "shard_manager_shards", &[("state", "assigned")]);
+            let shards_unassigned =
+                sum_metric(&metrics, "shard_manager_shards", &[("state", "unassigned")]);
+
+            match (executors_online, shards_assigned, shards_unassigned) {
+                (Some(executors_online), Some(shards_assigned), Some(shards_unassigned)) => {
+                    let executors_online = executors_online as u64;
+                    let shards_unassigned = shards_unassigned as u64;
+                    if executors_online == 0 {
+                        problems.push("No worker executors are online".to_string());
+                    }
+                    if shards_unassigned > 0 {
+                        problems.push(format!(
+                            "{shards_unassigned} shards are not assigned to any worker executor"
+                        ));
+                    }
+                    status.executors_online = Some(executors_online);
+                    status.shards_assigned = Some(shards_assigned as u64);
+                    status.shards_unassigned = Some(shards_unassigned);
+                }
+                _ => {
+                    let error = "Shard assignment metrics are missing".to_string();
+                    problems.push(format!(
+                        "Failed to get the metrics of shard-manager: {error}"
+                    ));
+                    status.error = Some(error);
+                }
+            }
+        }
+        Some(Err(error)) => {
+            problems.push(format!(
+                "Failed to get the metrics of shard-manager: {}",
+                error.0
+            ));
+            status.error = Some(error.0);
+        }
+        None => {}
+    }
+
+    status.healthy = status.error.is_none() && problems.is_empty();
+    (status, problems)
+}
+
+/// Percentage of failed API requests, or None if no requests have been served yet
+fn api_error_rate(metrics: &str) -> Option<f64> {
+    let succeeded = sum_metric(metrics, "api_success_seconds_count", &[]).unwrap_or(0.0);
+    let failed = sum_metric(metrics, "api_failure_seconds_count", &[]).unwrap_or(0.0);
+    let total = succeeded + failed;
+
+    if total > 0.0 {
+        Some(failed / total * 100.0)
+    } else {
+        None
+    }
+}
+
+/// Sums all samples of a metric in the Prometheus text exposition format which have
+/// the given labels. Returns None if there are no such samples.
+fn sum_metric(metrics: &str, name: &str, labels: &[(&str, &str)]) -> Option<f64> {
+    let mut result = None;
+
+    for line in metrics.lines().map(|line| line.trim()) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (sample_name, sample_labels, rest) = match line.find('{') {
+            Some(start) => match line.rfind('}') {
+                Some(end) if end > start => (
+                    &line[..start],
+                    parse_labels(&line[start + 1..end]),
+                    &line[end + 1..],
+                ),
+                _ => continue,
+            },
+            None => match line.split_once(char::is_whitespace) {
+                Some((sample_name, rest)) => (sample_name, Vec::new(), rest),
+                None => continue,
+            },
+        };
+
+        if sample_name != name
+            || !labels
+                .iter()
+                .all(|label| sample_labels.contains(&(label.0, label.1)))
+        {
+            continue;
+        }
+
+        if let Some(value) = rest
+            .split_whitespace()
+            .next()
+            .and_then(|value| value.parse::<f64>().ok())
+        {
+            result = Some(result.unwrap_or(0.0) + value);
+        }
+    }
+
+    result
+}
+
+fn parse_labels(labels: &str) -> Vec<(&str, &str)> {
+    labels
+        .split(',')
+        .filter_map(|label| label.split_once('='))
+        .map(|(name, value)| (name.trim(), value.trim().trim_matches('"')))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::clients::cluster::ClusterClient;
+    use crate::model::GolemError;
+    use crate::service::cluster::{sum_metric, ClusterService, ClusterServiceLive};
+    use async_trait::async_trait;
+    use std::sync::Arc;
+    use url::Url;
+
+    struct ClusterClientStub {
+        url: Url,
+        healthy: bool,
+        metrics: &'static str,
+    }
+
+    impl ClusterClientStub {
+        fn new(port: u16, healthy: bool, metrics: &'static str) -> Arc<Self> {
+            Arc::new(Self {
+                url: Url::parse(&format!("http://localhost:{port}")).unwrap(),
+                healthy,
+                metrics,
+            })
+        }
+    }
+
+    #[async_trait]
+    impl ClusterClient for ClusterClientStub {
+        fn url(&self) -> &Url {
+            &self.url
+        }
+
+        async fn healthcheck(&self) -> Result<(), GolemError> {
+            if self.healthy {
+                Ok(())
+            } else {
+                Err(GolemError("connection refused".to_string()))
+            }
+        }
+
+        async fn metrics(&self) -> Result<String, GolemError> {
+            Ok(self.metrics.to_string())
+        }
+    }
+
+    const API_METRICS: &str = r#"
+# HELP api_success_seconds Time taken for successfully serving API requests
+# TYPE api_success_seconds histogram
+api_success_seconds_count{api="get_component",api_type="http"} 90
+api_success_seconds_count{api="create_component",api_type="http"} 5
+api_failure_seconds_count{api="get_component",api_type="http",error="NotFound"} 5
+"#;
+
+    const SHARD_MANAGER_METRICS: &str = r#"
+# HELP shard_manager_registered_pods Number of worker executors registered in the routing table
+# TYPE shard_manager_registered_pods gauge
+shard_manager_registered_pods 2
+shard_manager_shards{state="assigned"} 1024
+shard_manager_shards{state="unassigned"} 0
+"#;
+
+    #[test]
+    fn sum_metric_filters_by_name_and_labels() {
+        assert_eq!(
+            sum_metric(API_METRICS, "api_success_seconds_count", &[]),
+            Some(95.0)
+        );
+        assert_eq!(
+            sum_metric(
+                API_METRICS,
+                "api_success_seconds_count",
+                &[("api", "get_component")]
+            ),
+            Some(90.0)
+        );
+        assert_eq!(
+            sum_metric(SHARD_MANAGER_METRICS, "shard_manager_registered_pods", &[]),
+            Some(2.0)
+        );
+        assert_eq!(sum_metric(API_METRICS, "shard_manager_shards", &[]), None);
+    }
+
+    #[test]
+    async fn healthy_cluster() {
+        let service = ClusterServiceLive {
+            component_service: ClusterClientStub::new(8083, true, API_METRICS),
+            worker_service: ClusterClientStub::new(9005, true, API_METRICS),
+            shard_manager: ClusterClientStub::new(8081, true, SHARD_MANAGER_METRICS),
+        };
+
+        let status = service.status(Some(10.0)).await.unwrap();
+
+        assert!(status.healthy);
+        assert!(status.problems.is_empty());
+        assert_eq!(status.services[0].error_rate, Some(5.0));
+        assert_eq!(status.services[2].executors_online, Some(2));
+        assert_eq!(status.services[2].shards_assigned, Some(1024));
+        assert_eq!(status.services[2].shards_unassigned, Some(0));
+    }
+
+    #[test]
+    async fn unhealthy_cluster() {
+        let service = ClusterServiceLive {
+            component_service: ClusterClientStub::new(8083, true, API_METRICS),
+            worker_service: ClusterClientStub::new(9005, false, API_METRICS),
+            shard_manager: ClusterClientStub::new(
+                8081,
+                true,
+                r#"
+shard_manager_registered_pods 0
+shard_manager_shards{state="assigned"} 0
+shard_manager_shards{state="unassigned"} 1024
+"#,
+            ),
+        };
+
+        let status = service.status(Some(1.0)).await.unwrap();
+
+        assert!(!status.healthy);
+        assert!(!status.services[0].healthy);
+        assert!(!status.services[1].healthy);
+        assert!(!status.services[2].healthy);
+        assert_eq!(
+            status.problems,
+            vec![
+                "component-service error rate 5.00% exceeds the maximum of 1.00%".to_string(),
+                "worker-service at http://localhost:9005/ is unhealthy: connection refused"
+                    .to_string(),
+                "No worker executors are online".to_string(),
+                "1024 shards are not assigned to any worker executor".to_string(),
+            ]
+        );
+    }
+}
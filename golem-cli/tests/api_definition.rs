@@ -21,9 +21,9 @@ use assert2::assert;
 use chrono::{DateTime, Utc};
 use golem_cli::model::component::ComponentView;
 use golem_client::model::{
-    GolemWorkerBinding, GolemWorkerBindingWithTypeInfo, HttpApiDefinitionRequest,
-    HttpApiDefinitionWithTypeInfo, MethodPattern, RibInputTypeInfo, Route, RouteWithTypeInfo,
-    VersionedComponentId,
+    ExampleRequest, ExampleResponse, GolemWorkerBinding, GolemWorkerBindingWithTypeInfo,
+    HttpApiDefinitionRequest, HttpApiDefinitionWithTypeInfo, MethodPattern, RibInputTypeInfo,
+    RibVersion, Route, RouteExample, RouteExampleResult, RouteWithTypeInfo, VersionedComponentId,
 };
 use golem_test_framework::config::{EnvBasedTestDependencies, TestDependencies};
 use serde_json::json;
@@ -105,6 +105,14 @@ fn make(r: &mut DynamicTestRegistration, suffix: &'static str, name: &'static st
             api_definition_get((deps, name.to_string(), cli.with_args(short)))
         }
     );
+    add_test!(
+        r,
+        format!("api_definition_test{suffix}"),
+        TestType::IntegrationTest,
+        move |deps: &EnvBasedTestDependencies, cli: &CliLive, _tracing: &Tracing| {
+            api_definition_test((deps, name.to_string(), cli.with_args(short)))
+        }
+    );
     add_test!(
         r,
         format!("api_definition_delete{suffix}"),
@@ -142,6 +150,7 @@ fn golem_def_with_response(
         id: id.to_string(),
         version: "0.1.0".to_string(),
         draft: true,
+        rib_version: None,
         routes: vec![Route {
             method: MethodPattern::Get,
             path: "/{user-id}/get-cart-contents".to_string(),
@@ -154,6 +163,7 @@ fn golem_def_with_response(
                 idempotency_key: None,
                 response,
             },
+            examples: None,
         }],
     }
 }
@@ -259,6 +269,8 @@ pub fn to_definition(
         id: request.id,
         version: request.version,
         draft: request.draft,
+        // Definitions that don't declare a Rib version get the latest one
+        rib_version: Some(request.rib_version.unwrap_or(RibVersion::V2)),
         routes: request
             .routes
             .iter()
@@ -281,6 +293,7 @@ pub fn to_definition(
                         }),
                         idempotency_key_input: None,
                     },
+                    examples: v.examples.unwrap_or_default(),
                 }
             })
             .collect(),
@@ -539,3 +552,51 @@ fn api_definition_delete(
 
     Ok(())
 }
+
+fn api_definition_test(
+    (deps, name, cli): (
+        &(impl TestDependencies + Send + Sync + 'static),
+        String,
+        CliLive,
+    ),
+) -> anyhow::Result<()> {
+    let component_name = format!("api_definition_test{name}");
+    let component = make_shopping_cart_component(deps, &component_name, &cli)?;
+    let component_id = component.component_urn.id.0.to_string();
+
+    let example = |body: &str| RouteExample {
+        name: "get".to_string(),
+        request: ExampleRequest {
+            path: "/jon/get-cart-contents".to_string(),
+            headers: None,
+            body: None,
+        },
+        worker_responses: None,
+        response: ExampleResponse {
+            status: 200,
+            headers: Some(HashMap::from([("userid".to_string(), "foo".to_string())])),
+            body: Some(json!(body)),
+        },
+    };
+
+    let mut def = golem_def(&component_name, &component_id);
+    def.routes[0].examples = Some(vec![example("foo")]);
+    let path = make_golem_file(&def)?;
+
+    let res: Vec<RouteExampleResult> =
+        cli.run(&["api-definition", "test", path.to_str().unwrap()])?;
+
+    assert!(res.len() == 1);
+    assert!(res[0].passed);
+
+    def.routes[0].examples = Some(vec![example("bar")]);
+    let path = make_golem_file(&def)?;
+
+    let res = cli.run_unit(&["api-definition", "test", path.to_str().unwrap()]);
+    assert!(res.is_err());
+
+    let res = cli.run_unit(&["api-definition", "add", path.to_str().unwrap()]);
+    assert!(res.is_err());
+
+    Ok(())
+}
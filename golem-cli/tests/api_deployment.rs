@@ -61,6 +61,14 @@ fn make(r: &mut DynamicTestRegistration, suffix: &'static str, name: &'static st
             api_deployment_list((deps, name.to_string(), cli.with_args(short)))
         }
     );
+    add_test!(
+        r,
+        format!("api_deployment_rib_logging{suffix}"),
+        TestType::IntegrationTest,
+        move |deps: &EnvBasedTestDependencies, cli: &CliLive, _tracing: &Tracing| {
+            api_deployment_rib_logging((deps, name.to_string(), cli.with_args(short)))
+        }
+    );
     add_test!(
         r,
         format!("api_deployment_delete{suffix}"),
@@ -108,6 +116,7 @@ fn api_deployment_deploy(
     assert_eq!(deployment.site.host, host);
     assert_eq!(api_definition_info.id, definition.id);
     assert_eq!(api_definition_info.version, definition.version);
+    assert!(!deployment.rib_logging);
 
     let updated_def: HttpApiDefinitionWithTypeInfo = cli.run(&[
         "api-definition",
@@ -149,6 +158,55 @@ fn api_deployment_get(
     Ok(())
 }
 
+fn api_deployment_rib_logging(
+    (deps, name, cli): (&EnvBasedTestDependencies, String, CliLive),
+) -> Result<(), anyhow::Error> {
+    let definition = make_definition(deps, &cli, &format!("api_deployment_rib_logging{name}"))?;
+    let host = format!("rib-logging-host{name}");
+    let cfg = &cli.config;
+    let definition_key = format!("{}/{}", definition.id, definition.version);
+
+    let enabled: ApiDeployment = cli.run(&[
+        "api-deployment",
+        "deploy",
+        &cfg.arg('d', "definition"),
+        &definition_key,
+        &cfg.arg('H', "host"),
+        &host,
+        "--rib-logging",
+        "true",
+    ])?;
+
+    assert!(enabled.rib_logging);
+
+    let unchanged: ApiDeployment = cli.run(&[
+        "api-deployment",
+        "deploy",
+        &cfg.arg('d', "definition"),
+        &definition_key,
+        &cfg.arg('H', "host"),
+        &host,
+    ])?;
+
+    assert!(unchanged.rib_logging);
+
+    let disabled: ApiDeployment = cli.run(&[
+        "api-deployment",
+        "deploy",
+        &cfg.arg('d', "definition"),
+        &definition_key,
+        &cfg.arg('H', "host"),
+        &host,
+        "--rib-logging",
+        "false",
+    ])?;
+
+    assert!(!disabled.rib_logging);
+    assert_eq!(enabled.api_definitions, disabled.api_definitions);
+
+    Ok(())
+}
+
 fn api_deployment_list(
     (deps, name, cli): (&EnvBasedTestDependencies, String, CliLive),
 ) -> Result<(), anyhow::Error> {
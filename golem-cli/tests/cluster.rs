@@ -0,0 +1,83 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use test_r::{inherit_test_dep, test, test_dep};
+
+use crate::cli::{Cli, CliLive};
+use crate::Tracing;
+use golem_cli::model::text::cluster::ClusterStatusView;
+use golem_test_framework::config::{EnvBasedTestDependencies, TestDependencies};
+use std::sync::Arc;
+
+inherit_test_dep!(EnvBasedTestDependencies);
+inherit_test_dep!(Tracing);
+
+#[test_dep]
+fn cli(deps: &EnvBasedTestDependencies) -> CliLive {
+    CliLive::make("cluster", Arc::new(deps.clone()))
+        .unwrap()
+        .with_long_args()
+}
+
+#[test]
+fn cluster_status(
+    deps: &EnvBasedTestDependencies,
+    cli: &CliLive,
+    _tracing: &Tracing,
+) -> Result<(), anyhow::Error> {
+    let shard_manager = deps.shard_manager();
+    let shard_manager_url = format!(
+        "http://{}:{}",
+        shard_manager.public_host(),
+        shard_manager.public_http_port()
+    );
+
+    let status: ClusterStatusView = cli.run(&[
+        "cluster",
+        "status",
+        "--shard-manager-url",
+        &shard_manager_url,
+    ])?;
+
+    assert!(status.healthy, "{:?}", status.problems);
+    assert_eq!(status.services.len(), 3);
+    assert_eq!(
+        status.services[2].executors_online,
+        Some(deps.worker_executor_cluster().size() as u64)
+    );
+    assert_eq!(status.services[2].shards_unassigned, Some(0));
+
+    Ok(())
+}
+
+#[test]
+fn cluster_status_fails_if_unhealthy(
+    cli: &CliLive,
+    _tracing: &Tracing,
+) -> Result<(), anyhow::Error> {
+    // Nothing listens on this port
+    let result = cli.run_string(&[
+        "cluster",
+        "status",
+        "--shard-manager-url",
+        "http://localhost:1",
+    ]);
+
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "golem cli failed with exit code: Some(1)"
+    );
+
+    Ok(())
+}
@@ -90,6 +90,8 @@ fn profile_add_get_list_switch_delete(
         "http://localhost:9876",
         &cfg.arg('w', "worker-url"),
         "http://localhost:9875",
+        "--shard-manager-url",
+        "http://localhost:9874",
         &cfg.arg('a', "allow-insecure"),
         &cfg.arg('f', "default-format"),
         "yaml",
@@ -105,6 +107,7 @@ fn profile_add_get_list_switch_delete(
         url: Some(Url::parse("http://localhost:9876")?),
         cloud_url: None,
         worker_url: Some(Url::parse("http://localhost:9875")?),
+        shard_manager_url: Some(Url::parse("http://localhost:9874")?),
         allow_insecure: true,
         authenticated: None,
         config: ProfileConfig {
@@ -131,6 +134,7 @@ fn profile_add_get_list_switch_delete(
         url: Some(Url::parse("http://localhost:9874")?),
         cloud_url: None,
         worker_url: None,
+        shard_manager_url: None,
         allow_insecure: false,
         authenticated: None,
         config: ProfileConfig::default(),
@@ -155,6 +159,7 @@ fn profile_add_get_list_switch_delete(
         url: Some(Url::parse("http://localhost:9873")?),
         cloud_url: None,
         worker_url: None,
+        shard_manager_url: None,
         allow_insecure: false,
         authenticated: None,
         config: ProfileConfig::default(),
@@ -267,6 +267,25 @@ pub enum LogLevel {
     Critical,
 }
 
+/// The reason a cooperative cancellation was requested for an invocation
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Encode, Decode, Serialize, Deserialize, Enum)]
+#[repr(u8)]
+pub enum CancellationReason {
+    /// The worker was interrupted through the Golem API
+    Interrupt,
+    /// The invocation exceeded the configured invocation timeout
+    InvocationTimeout,
+}
+
+impl Display for CancellationReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CancellationReason::Interrupt => write!(f, "interrupt"),
+            CancellationReason::InvocationTimeout => write!(f, "invocation timeout"),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Encode, Decode)]
 pub enum OplogEntry {
     Create {
@@ -404,6 +423,17 @@ pub enum OplogEntry {
         response: OplogPayload,
         wrapped_function_type: WrappedFunctionType,
     },
+    /// Cooperative cancellation of the given invocation was requested
+    CancellationRequested {
+        timestamp: Timestamp,
+        idempotency_key: IdempotencyKey,
+        reason: CancellationReason,
+    },
+    /// The worker observed the cancellation request of the given invocation
+    CancellationAcknowledged {
+        timestamp: Timestamp,
+        idempotency_key: IdempotencyKey,
+    },
 }
 
 impl OplogEntry {
@@ -581,6 +611,24 @@ impl OplogEntry {
         }
     }
 
+    pub fn cancellation_requested(
+        idempotency_key: IdempotencyKey,
+        reason: CancellationReason,
+    ) -> OplogEntry {
+        OplogEntry::CancellationRequested {
+            timestamp: Timestamp::now_utc(),
+            idempotency_key,
+            reason,
+        }
+    }
+
+    pub fn cancellation_acknowledged(idempotency_key: IdempotencyKey) -> OplogEntry {
+        OplogEntry::CancellationAcknowledged {
+            timestamp: Timestamp::now_utc(),
+            idempotency_key,
+        }
+    }
+
     pub fn is_end_atomic_region(&self, idx: OplogIndex) -> bool {
         matches!(self, OplogEntry::EndAtomicRegion { begin_index, .. } if *begin_index == idx)
     }
@@ -630,6 +678,8 @@ impl OplogEntry {
                 | OplogEntry::DescribeResource { .. }
                 | OplogEntry::Log { .. }
                 | OplogEntry::Restart { .. }
+                | OplogEntry::CancellationRequested { .. }
+                | OplogEntry::CancellationAcknowledged { .. }
         )
     }
 
@@ -660,7 +710,9 @@ impl OplogEntry {
             | OplogEntry::DescribeResource { timestamp, .. }
             | OplogEntry::Log { timestamp, .. }
             | OplogEntry::Restart { timestamp }
-            | OplogEntry::ImportedFunctionInvoked { timestamp, .. } => *timestamp,
+            | OplogEntry::ImportedFunctionInvoked { timestamp, .. }
+            | OplogEntry::CancellationRequested { timestamp, .. }
+            | OplogEntry::CancellationAcknowledged { timestamp, .. } => *timestamp,
         }
     }
 }
@@ -13,7 +13,9 @@
 // limitations under the License.
 
 use crate::config::RetryConfig;
-use crate::model::oplog::{LogLevel, OplogIndex, WorkerResourceId, WrappedFunctionType};
+use crate::model::oplog::{
+    CancellationReason, LogLevel, OplogIndex, WorkerResourceId, WrappedFunctionType,
+};
 use crate::model::regions::OplogRegion;
 use crate::model::{AccountId, ComponentVersion, IdempotencyKey, Timestamp, WorkerId};
 use golem_api_grpc::proto::golem::worker::{oplog_entry, worker_invocation, wrapped_function_type};
@@ -255,6 +257,19 @@ pub struct LogParameters {
     pub message: String,
 }
 
+#[derive(Clone, Debug, Serialize, PartialEq, Deserialize, Object)]
+pub struct CancellationRequestedParameters {
+    pub timestamp: Timestamp,
+    pub idempotency_key: IdempotencyKey,
+    pub reason: CancellationReason,
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Deserialize, Object)]
+pub struct CancellationAcknowledgedParameters {
+    pub timestamp: Timestamp,
+    pub idempotency_key: IdempotencyKey,
+}
+
 /// A mirror of the core `OplogEntry` type, without the undefined arbitrary payloads.
 ///
 /// Instead, it encodes all payloads with wasm-rpc `Value` types. This makes this the base type
@@ -326,6 +341,10 @@ pub enum PublicOplogEntry {
     Log(LogParameters),
     /// Marks the point where the worker was restarted from clean initial state
     Restart(TimestampParameter),
+    /// Cooperative cancellation of the given invocation was requested
+    CancellationRequested(CancellationRequestedParameters),
+    /// The worker observed the cancellation request of the given invocation
+    CancellationAcknowledged(CancellationAcknowledgedParameters),
 }
 
 impl TryFrom<golem_api_grpc::proto::golem::worker::OplogEntry> for PublicOplogEntry {
@@ -578,6 +597,31 @@ impl TryFrom<golem_api_grpc::proto::golem::worker::OplogEntry> for PublicOplogEn
                     timestamp: restart.timestamp.ok_or("Missing timestamp field")?.into(),
                 }))
             }
+            oplog_entry::Entry::CancellationRequested(cancellation_requested) => Ok(
+                PublicOplogEntry::CancellationRequested(CancellationRequestedParameters {
+                    reason: cancellation_requested.reason().into(),
+                    timestamp: cancellation_requested
+                        .timestamp
+                        .ok_or("Missing timestamp field")?
+                        .into(),
+                    idempotency_key: cancellation_requested
+                        .idempotency_key
+                        .ok_or("Missing idempotency_key field")?
+                        .into(),
+                }),
+            ),
+            oplog_entry::Entry::CancellationAcknowledged(cancellation_acknowledged) => Ok(
+                PublicOplogEntry::CancellationAcknowledged(CancellationAcknowledgedParameters {
+                    timestamp: cancellation_acknowledged
+                        .timestamp
+                        .ok_or("Missing timestamp field")?
+                        .into(),
+                    idempotency_key: cancellation_acknowledged
+                        .idempotency_key
+                        .ok_or("Missing idempotency_key field")?
+                        .into(),
+                }),
+            ),
         }
     }
 }
@@ -873,6 +917,35 @@ impl TryFrom<PublicOplogEntry> for golem_api_grpc::proto::golem::worker::OplogEn
                     )),
                 }
             }
+            PublicOplogEntry::CancellationRequested(cancellation_requested) => {
+                golem_api_grpc::proto::golem::worker::OplogEntry {
+                    entry: Some(
+                        oplog_entry::Entry::CancellationRequested(
+                            golem_api_grpc::proto::golem::worker::CancellationRequestedParameters {
+                                timestamp: Some(cancellation_requested.timestamp.into()),
+                                idempotency_key: Some(
+                                    cancellation_requested.idempotency_key.into(),
+                                ),
+                                reason: Into::<
+                                    golem_api_grpc::proto::golem::worker::CancellationReason,
+                                >::into(
+                                    cancellation_requested.reason
+                                ) as i32,
+                            },
+                        ),
+                    ),
+                }
+            }
+            PublicOplogEntry::CancellationAcknowledged(cancellation_acknowledged) => {
+                golem_api_grpc::proto::golem::worker::OplogEntry {
+                    entry: Some(oplog_entry::Entry::CancellationAcknowledged(
+                        golem_api_grpc::proto::golem::worker::CancellationAcknowledgedParameters {
+                            timestamp: Some(cancellation_acknowledged.timestamp.into()),
+                            idempotency_key: Some(cancellation_acknowledged.idempotency_key.into()),
+                        },
+                    )),
+                }
+            }
         })
     }
 }
@@ -1006,6 +1079,32 @@ impl From<LogLevel> for golem_api_grpc::proto::golem::worker::OplogLogLevel {
     }
 }
 
+impl From<golem_api_grpc::proto::golem::worker::CancellationReason> for CancellationReason {
+    fn from(value: golem_api_grpc::proto::golem::worker::CancellationReason) -> Self {
+        match value {
+            golem_api_grpc::proto::golem::worker::CancellationReason::CancellationInterrupt => {
+                CancellationReason::Interrupt
+            }
+            golem_api_grpc::proto::golem::worker::CancellationReason::CancellationInvocationTimeout => {
+                CancellationReason::InvocationTimeout
+            }
+        }
+    }
+}
+
+impl From<CancellationReason> for golem_api_grpc::proto::golem::worker::CancellationReason {
+    fn from(value: CancellationReason) -> Self {
+        match value {
+            CancellationReason::Interrupt => {
+                golem_api_grpc::proto::golem::worker::CancellationReason::CancellationInterrupt
+            }
+            CancellationReason::InvocationTimeout => {
+                golem_api_grpc::proto::golem::worker::CancellationReason::CancellationInvocationTimeout
+            }
+        }
+    }
+}
+
 impl TryFrom<golem_api_grpc::proto::golem::worker::WorkerInvocation> for PublicWorkerInvocation {
     type Error = String;
 
@@ -1179,6 +1278,7 @@ impl From<OplogCursor> for golem_api_grpc::proto::golem::worker::OplogCursor {
 mod tests {
 
     use super::{
+        CancellationAcknowledgedParameters, CancellationRequestedParameters,
         ChangeRetryPolicyParameters, CreateParameters, DescribeResourceParameters, Empty,
         EndRegionParameters, ErrorParameters, ExportedFunctionCompletedParameters,
         ExportedFunctionInvokedParameters, ExportedFunctionParameters, FailedUpdateParameters,
@@ -1188,7 +1288,7 @@ mod tests {
         PublicWrappedFunctionType, ResourceParameters, SnapshotBasedUpdateParameters,
         SuccessfulUpdateParameters, TimestampParameter,
     };
-    use crate::model::oplog::{LogLevel, OplogIndex, WorkerResourceId};
+    use crate::model::oplog::{CancellationReason, LogLevel, OplogIndex, WorkerResourceId};
     use crate::model::regions::OplogRegion;
     use crate::model::{AccountId, ComponentId, IdempotencyKey, Timestamp, WorkerId};
     use golem_wasm_ast::analysis::analysed_type::{field, list, r#enum, record, s16, str, u64};
@@ -1581,4 +1681,28 @@ mod tests {
         let deserialized: PublicOplogEntry = serde_json::from_str(&serialized).unwrap();
         assert_eq!(entry, deserialized);
     }
+
+    #[test]
+    fn cancellation_requested_serialization_poem_serde_equivalence() {
+        let entry = PublicOplogEntry::CancellationRequested(CancellationRequestedParameters {
+            timestamp: rounded_ts(Timestamp::now_utc()),
+            idempotency_key: IdempotencyKey::new("idempotency_key".to_string()),
+            reason: CancellationReason::InvocationTimeout,
+        });
+        let serialized = entry.to_json_string();
+        let deserialized: PublicOplogEntry = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(entry, deserialized);
+    }
+
+    #[test]
+    fn cancellation_acknowledged_serialization_poem_serde_equivalence() {
+        let entry =
+            PublicOplogEntry::CancellationAcknowledged(CancellationAcknowledgedParameters {
+                timestamp: rounded_ts(Timestamp::now_utc()),
+                idempotency_key: IdempotencyKey::new("idempotency_key".to_string()),
+            });
+        let serialized = entry.to_json_string();
+        let deserialized: PublicOplogEntry = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(entry, deserialized);
+    }
 }
@@ -124,6 +124,15 @@ pub trait ComponentRepo {
     async fn get_namespace(&self, component_id: &Uuid) -> Result<Option<String>, RepoError>;
 
     async fn delete(&self, namespace: &str, component_id: &Uuid) -> Result<(), RepoError>;
+
+    async fn get_worker_defaults(&self, component_id: &Uuid) -> Result<Option<Vec<u8>>, RepoError>;
+
+    async fn update_worker_defaults(
+        &self,
+        namespace: &str,
+        component_id: &Uuid,
+        worker_defaults: &[u8],
+    ) -> Result<(), RepoError>;
 }
 
 pub struct DbComponentRepo<DB: Database> {
@@ -227,6 +236,24 @@ impl<Repo: ComponentRepo + Send + Sync> ComponentRepo for LoggedComponentRepo<Re
         let result = self.repo.delete(namespace, component_id).await;
         Self::logged_with_id("delete", component_id, result)
     }
+
+    async fn get_worker_defaults(&self, component_id: &Uuid) -> Result<Option<Vec<u8>>, RepoError> {
+        let result = self.repo.get_worker_defaults(component_id).await;
+        Self::logged_with_id("get_worker_defaults", component_id, result)
+    }
+
+    async fn update_worker_defaults(
+        &self,
+        namespace: &str,
+        component_id: &Uuid,
+        worker_defaults: &[u8],
+    ) -> Result<(), RepoError> {
+        let result = self
+            .repo
+            .update_worker_defaults(namespace, component_id, worker_defaults)
+            .await;
+        Self::logged_with_id("update_worker_defaults", component_id, result)
+    }
 }
 
 #[trait_gen(sqlx::Postgres -> sqlx::Postgres, sqlx::Sqlite)]
@@ -571,6 +598,33 @@ impl ComponentRepo for DbComponentRepo<sqlx::Postgres> {
         transaction.commit().await?;
         Ok(())
     }
+
+    async fn get_worker_defaults(&self, component_id: &Uuid) -> Result<Option<Vec<u8>>, RepoError> {
+        let result = sqlx::query("SELECT worker_defaults FROM components WHERE component_id = $1")
+            .bind(component_id)
+            .fetch_optional(self.db_pool.deref())
+            .await?;
+
+        Ok(result.and_then(|x| x.get("worker_defaults")))
+    }
+
+    async fn update_worker_defaults(
+        &self,
+        namespace: &str,
+        component_id: &Uuid,
+        worker_defaults: &[u8],
+    ) -> Result<(), RepoError> {
+        sqlx::query(
+            "UPDATE components SET worker_defaults = $1 WHERE namespace = $2 AND component_id = $3",
+        )
+        .bind(worker_defaults)
+        .bind(namespace)
+        .bind(component_id)
+        .execute(self.db_pool.deref())
+        .await?;
+
+        Ok(())
+    }
 }
 
 pub mod record_metadata_serde {
@@ -603,3 +657,33 @@ pub mod record_metadata_serde {
         }
     }
 }
+
+pub mod record_worker_defaults_serde {
+    use bytes::{BufMut, Bytes, BytesMut};
+    use golem_api_grpc::proto::golem::component::WorkerDefaults as WorkerDefaultsProto;
+    use golem_service_base::model::WorkerDefaults;
+    use prost::Message;
+
+    pub const SERIALIZATION_VERSION_V1: u8 = 1u8;
+
+    pub fn serialize(value: &WorkerDefaults) -> Result<Bytes, String> {
+        let proto_value: WorkerDefaultsProto = value.clone().into();
+        let mut bytes = BytesMut::new();
+        bytes.put_u8(SERIALIZATION_VERSION_V1);
+        bytes.extend_from_slice(&proto_value.encode_to_vec());
+        Ok(bytes.freeze())
+    }
+
+    pub fn deserialize(bytes: &[u8]) -> Result<WorkerDefaults, String> {
+        let (version, data) = bytes.split_at(1);
+
+        match version[0] {
+            SERIALIZATION_VERSION_V1 => {
+                let proto_value: WorkerDefaultsProto = Message::decode(data)
+                    .map_err(|e| format!("Failed to deserialize value: {e}"))?;
+                Ok(proto_value.into())
+            }
+            _ => Err("Unsupported serialization version".to_string()),
+        }
+    }
+}
@@ -17,7 +17,7 @@ use std::num::TryFromIntError;
 use std::sync::Arc;
 
 use crate::model::Component;
-use crate::repo::component::ComponentRepo;
+use crate::repo::component::{record_worker_defaults_serde, ComponentRepo};
 use crate::service::component_compilation::ComponentCompilationService;
 use crate::service::component_processor::process_component;
 use async_trait::async_trait;
@@ -25,7 +25,7 @@ use chrono::Utc;
 use golem_common::model::component_metadata::ComponentProcessingError;
 use golem_common::model::{ComponentId, ComponentType};
 use golem_common::SafeDisplay;
-use golem_service_base::model::{ComponentName, VersionedComponentId};
+use golem_service_base::model::{ComponentName, VersionedComponentId, WorkerDefaults};
 use golem_service_base::repo::RepoError;
 use golem_service_base::service::component_object_store::ComponentObjectStore;
 use golem_service_base::stream::ByteStream;
@@ -194,6 +194,19 @@ pub trait ComponentService<Namespace> {
         component_id: &ComponentId,
         namespace: &Namespace,
     ) -> Result<(), ComponentError>;
+
+    async fn get_worker_defaults(
+        &self,
+        component_id: &ComponentId,
+        namespace: &Namespace,
+    ) -> Result<WorkerDefaults, ComponentError>;
+
+    async fn update_worker_defaults(
+        &self,
+        component_id: &ComponentId,
+        worker_defaults: WorkerDefaults,
+        namespace: &Namespace,
+    ) -> Result<WorkerDefaults, ComponentError>;
 }
 
 pub struct ComponentServiceDefault {
@@ -558,6 +571,43 @@ where
             Err(ComponentError::UnknownComponentId(component_id.clone()))
         }
     }
+
+    async fn get_worker_defaults(
+        &self,
+        component_id: &ComponentId,
+        namespace: &Namespace,
+    ) -> Result<WorkerDefaults, ComponentError> {
+        info!(namespace = %namespace, "Get component worker defaults");
+        self.check_namespace(component_id, namespace).await?;
+
+        match self
+            .component_repo
+            .get_worker_defaults(&component_id.0)
+            .await?
+        {
+            Some(data) => record_worker_defaults_serde::deserialize(&data)
+                .map_err(|e| ComponentError::conversion_error("worker defaults", e)),
+            None => Ok(WorkerDefaults::default()),
+        }
+    }
+
+    async fn update_worker_defaults(
+        &self,
+        component_id: &ComponentId,
+        worker_defaults: WorkerDefaults,
+        namespace: &Namespace,
+    ) -> Result<WorkerDefaults, ComponentError> {
+        info!(namespace = %namespace, "Update component worker defaults");
+        self.check_namespace(component_id, namespace).await?;
+
+        let data = record_worker_defaults_serde::serialize(&worker_defaults)
+            .map_err(|e| ComponentError::conversion_error("worker defaults", e))?;
+        self.component_repo
+            .update_worker_defaults(namespace.to_string().as_str(), &component_id.0, &data)
+            .await?;
+
+        Ok(worker_defaults)
+    }
 }
 
 impl ComponentServiceDefault {
@@ -598,6 +648,17 @@ impl ComponentServiceDefault {
             })
     }
 
+    async fn check_namespace<Namespace: Display>(
+        &self,
+        component_id: &ComponentId,
+        namespace: &Namespace,
+    ) -> Result<(), ComponentError> {
+        match self.component_repo.get_namespace(&component_id.0).await? {
+            Some(stored) if stored == namespace.to_string() => Ok(()),
+            _ => Err(ComponentError::UnknownComponentId(component_id.clone())),
+        }
+    }
+
     async fn get_versioned_component_id<Namespace: Display + Clone>(
         &self,
         component_id: &ComponentId,
@@ -14,8 +14,9 @@ use golem_component_service_base::service::component::{
 use golem_component_service_base::service::component_compilation::{
     ComponentCompilationService, ComponentCompilationServiceDisabled,
 };
-use golem_service_base::model::ComponentName;
+use golem_service_base::model::{ComponentName, WorkerDefaults};
 use golem_service_base::service::component_object_store;
+use std::collections::HashMap;
 use std::sync::Arc;
 use testcontainers::runners::AsyncRunner;
 use testcontainers::{ContainerAsync, ImageExt};
@@ -332,6 +333,36 @@ async fn test_services(component_repo: Arc<dyn ComponentRepo + Sync + Send>) {
         .unwrap();
     assert_eq!(component_result.len(), 3);
 
+    let worker_defaults_result = component_service
+        .get_worker_defaults(
+            &component2.versioned_component_id.component_id,
+            &DefaultNamespace::default(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(worker_defaults_result, WorkerDefaults::default());
+
+    let worker_defaults = WorkerDefaults {
+        args: vec!["--name={{worker_name}}".to_string()],
+        env: HashMap::from_iter(vec![("MODE".to_string(), "test".to_string())]),
+    };
+    component_service
+        .update_worker_defaults(
+            &component2.versioned_component_id.component_id,
+            worker_defaults.clone(),
+            &DefaultNamespace::default(),
+        )
+        .await
+        .unwrap();
+    let worker_defaults_result = component_service
+        .get_worker_defaults(
+            &component2.versioned_component_id.component_id,
+            &DefaultNamespace::default(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(worker_defaults_result, worker_defaults);
+
     component_service
         .delete(
             &component1v2.versioned_component_id.component_id,
@@ -0,0 +1,2 @@
+ALTER TABLE components
+    ADD COLUMN IF NOT EXISTS worker_defaults bytea;
@@ -342,6 +342,68 @@ impl ComponentApi {
         record.result(response)
     }
 
+    /// Get the worker defaults of a component
+    ///
+    /// Returns the default arguments and environment variables used when creating a worker of the component without specifying them.
+    #[oai(
+        path = "/:component_id/worker-defaults",
+        method = "get",
+        operation_id = "get_worker_defaults"
+    )]
+    async fn get_worker_defaults(
+        &self,
+        component_id: Path<ComponentId>,
+    ) -> Result<Json<WorkerDefaults>> {
+        let record = recorded_http_api_request!(
+            "get_worker_defaults",
+            component_id = component_id.0.to_string()
+        );
+
+        let response = self
+            .component_service
+            .get_worker_defaults(&component_id.0, &DefaultNamespace::default())
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(Json);
+
+        record.result(response)
+    }
+
+    /// Update the worker defaults of a component
+    ///
+    /// Sets the default arguments and environment variables used when creating a worker of the component without specifying them.
+    /// The values can contain the `{{worker_name}}` and `{{component_id}}` placeholders, which are resolved at worker creation.
+    #[oai(
+        path = "/:component_id/worker-defaults",
+        method = "put",
+        operation_id = "update_worker_defaults"
+    )]
+    async fn update_worker_defaults(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_defaults: Json<WorkerDefaults>,
+    ) -> Result<Json<WorkerDefaults>> {
+        let record = recorded_http_api_request!(
+            "update_worker_defaults",
+            component_id = component_id.0.to_string()
+        );
+
+        let response = self
+            .component_service
+            .update_worker_defaults(
+                &component_id.0,
+                worker_defaults.0,
+                &DefaultNamespace::default(),
+            )
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(Json);
+
+        record.result(response)
+    }
+
     /// Get all components
     ///
     /// Gets all components, optionally filtered by component name.
@@ -23,16 +23,17 @@ use golem_api_grpc::proto::golem::component::v1::component_service_server::Compo
 use golem_api_grpc::proto::golem::component::v1::{
     component_error, create_component_request, create_component_response,
     download_component_response, get_component_metadata_all_versions_response,
-    get_component_metadata_response, get_components_response, update_component_request,
-    update_component_response, ComponentError, CreateComponentRequest,
-    CreateComponentRequestHeader, CreateComponentResponse, DownloadComponentRequest,
-    DownloadComponentResponse, GetComponentMetadataAllVersionsResponse,
+    get_component_metadata_response, get_components_response, get_worker_defaults_response,
+    update_component_request, update_component_response, update_worker_defaults_response,
+    ComponentError, CreateComponentRequest, CreateComponentRequestHeader, CreateComponentResponse,
+    DownloadComponentRequest, DownloadComponentResponse, GetComponentMetadataAllVersionsResponse,
     GetComponentMetadataResponse, GetComponentMetadataSuccessResponse, GetComponentRequest,
     GetComponentSuccessResponse, GetComponentsRequest, GetComponentsResponse,
     GetComponentsSuccessResponse, GetLatestComponentRequest, GetVersionedComponentRequest,
-    UpdateComponentRequest, UpdateComponentRequestHeader, UpdateComponentResponse,
+    GetWorkerDefaultsResponse, UpdateComponentRequest, UpdateComponentRequestHeader,
+    UpdateComponentResponse, UpdateWorkerDefaultsRequest, UpdateWorkerDefaultsResponse,
 };
-use golem_api_grpc::proto::golem::component::Component;
+use golem_api_grpc::proto::golem::component::{Component, WorkerDefaults};
 use golem_common::grpc::proto_component_id_string;
 use golem_common::model::{ComponentId, ComponentType};
 use golem_common::recorded_grpc_api_request;
@@ -191,6 +192,39 @@ impl ComponentGrpcApi {
             .await?;
         Ok(result.into())
     }
+
+    async fn get_worker_defaults(
+        &self,
+        request: GetComponentRequest,
+    ) -> Result<WorkerDefaults, ComponentError> {
+        let id: ComponentId = request
+            .component_id
+            .and_then(|id| id.try_into().ok())
+            .ok_or_else(|| bad_request_error("Missing component id"))?;
+        let result = self
+            .component_service
+            .get_worker_defaults(&id, &DefaultNamespace::default())
+            .await?;
+        Ok(result.into())
+    }
+
+    async fn update_worker_defaults(
+        &self,
+        request: UpdateWorkerDefaultsRequest,
+    ) -> Result<WorkerDefaults, ComponentError> {
+        let id: ComponentId = request
+            .component_id
+            .and_then(|id| id.try_into().ok())
+            .ok_or_else(|| bad_request_error("Missing component id"))?;
+        let worker_defaults = request
+            .worker_defaults
+            .ok_or_else(|| bad_request_error("Missing worker defaults"))?;
+        let result = self
+            .component_service
+            .update_worker_defaults(&id, worker_defaults.into(), &DefaultNamespace::default())
+            .await?;
+        Ok(result.into())
+    }
 }
 
 #[async_trait::async_trait]
@@ -455,4 +489,62 @@ impl ComponentService for ComponentGrpcApi {
             result: Some(response),
         }))
     }
+
+    async fn get_worker_defaults(
+        &self,
+        request: Request<GetComponentRequest>,
+    ) -> Result<Response<GetWorkerDefaultsResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "get_worker_defaults",
+            component_id = proto_component_id_string(&request.component_id)
+        );
+
+        let response = match self
+            .get_worker_defaults(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(worker_defaults) => record.succeed(get_worker_defaults_response::Result::Success(
+                worker_defaults,
+            )),
+            Err(error) => record.fail(
+                get_worker_defaults_response::Result::Error(error.clone()),
+                &ComponentTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(GetWorkerDefaultsResponse {
+            result: Some(response),
+        }))
+    }
+
+    async fn update_worker_defaults(
+        &self,
+        request: Request<UpdateWorkerDefaultsRequest>,
+    ) -> Result<Response<UpdateWorkerDefaultsResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "update_worker_defaults",
+            component_id = proto_component_id_string(&request.component_id)
+        );
+
+        let response = match self
+            .update_worker_defaults(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(worker_defaults) => record.succeed(
+                update_worker_defaults_response::Result::Success(worker_defaults),
+            ),
+            Err(error) => record.fail(
+                update_worker_defaults_response::Result::Error(error.clone()),
+                &ComponentTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(UpdateWorkerDefaultsResponse {
+            result: Some(response),
+        }))
+    }
 }
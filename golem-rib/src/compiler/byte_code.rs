@@ -18,6 +18,12 @@ use crate::{Expr, InstructionId};
 use bincode::{Decode, Encode};
 use golem_api_grpc::proto::golem::rib::RibByteCode as ProtoRibByteCode;
 
+// Upper bound on the number of iterations of all `repeat` expressions in a Rib
+// expression: sibling repeats add up and nested repeats multiply.
+// Repeat is unrolled at compile time, so this keeps both the size of the
+// byte code and the number of worker invocations per request bounded.
+pub const MAX_REPEAT_ITERATIONS: usize = 100;
+
 #[derive(Debug, Clone, PartialEq, Encode, Decode)]
 pub struct RibByteCode {
     pub instructions: Vec<RibIR>,
@@ -26,6 +32,8 @@ pub struct RibByteCode {
 impl RibByteCode {
     // Convert expression to bytecode instructions
     pub fn from_expr(expr: Expr) -> Result<RibByteCode, String> {
+        internal::check_repeat_iterations(&expr)?;
+
         let mut instructions = Vec::new();
         let mut stack: Vec<ExprState> = Vec::new();
         let mut instruction_id = InstructionId::init();
@@ -82,16 +90,18 @@ impl From<RibByteCode> for ProtoRibByteCode {
 }
 
 mod internal {
+    use crate::compiler::byte_code::MAX_REPEAT_ITERATIONS;
     use crate::compiler::desugar::desugar_pattern_match;
     use crate::{
         AnalysedTypeWithUnit, DynamicParsedFunctionReference, Expr, FunctionReferenceType,
-        InferredType, InstructionId, RibIR,
+        InferredType, InstructionId, RibIR, VariableId,
     };
     use golem_wasm_ast::analysis::AnalysedType;
     use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 
     use crate::call_type::CallType;
     use golem_wasm_rpc::protobuf::TypedFlags;
+    use std::collections::VecDeque;
     use std::ops::Deref;
 
     pub(crate) fn process_expr(
@@ -439,6 +449,16 @@ mod internal {
                 stack.push(ExprState::from_ir(RibIR::GetTag));
             }
 
+            Expr::Repeat(count, body, inferred_type) => {
+                let unrolled_expr = unroll_repeat(expr, *count, body.deref(), inferred_type)?;
+                stack.push(ExprState::from_expr(&unrolled_expr));
+            }
+
+            Expr::Log(level, expr, _) => {
+                stack.push(ExprState::from_expr(expr.deref()));
+                instructions.push(RibIR::Log(*level));
+            }
+
             Expr::Concat(exprs, _) => {
                 for expr in exprs.iter().rev() {
                     stack.push(ExprState::from_expr(expr));
@@ -495,6 +515,104 @@ mod internal {
         }
     }
 
+    // repeat n times { body } is unrolled into n nested conditions,
+    // each evaluating the body and stopping at the first `some`
+    //
+    //   let __repeat_result = body;
+    //   if get_tag(__repeat_result) == "some" then __repeat_result else {
+    //     let __repeat_result = body;
+    //     ...
+    //     none
+    //   }
+    pub(crate) fn unroll_repeat(
+        expr: &Expr,
+        count: usize,
+        body: &Expr,
+        inferred_type: &InferredType,
+    ) -> Result<Expr, String> {
+        if !matches!(inferred_type, InferredType::Option(_)) {
+            return Err(format!(
+                "Invalid Rib {}. The body of repeat must be an option, found {:?}",
+                expr, inferred_type
+            ));
+        }
+
+        let result_variable = VariableId::local_with_no_id("__repeat_result");
+
+        let mut unrolled = Expr::Option(None, inferred_type.clone());
+
+        for _ in 0..count {
+            let result_identifier =
+                Expr::Identifier(result_variable.clone(), inferred_type.clone());
+
+            unrolled = Expr::Multiple(
+                vec![
+                    Expr::Let(
+                        result_variable.clone(),
+                        None,
+                        Box::new(body.clone()),
+                        InferredType::Unknown,
+                    ),
+                    Expr::Cond(
+                        Box::new(Expr::EqualTo(
+                            Box::new(Expr::GetTag(
+                                Box::new(result_identifier.clone()),
+                                InferredType::Str,
+                            )),
+                            Box::new(Expr::Literal("some".to_string(), InferredType::Str)),
+                            InferredType::Bool,
+                        )),
+                        Box::new(result_identifier),
+                        Box::new(unrolled),
+                        inferred_type.clone(),
+                    ),
+                ],
+                inferred_type.clone(),
+            );
+        }
+
+        Ok(unrolled)
+    }
+
+    // Checked before unrolling anything, as the unrolled expression grows with
+    // the number of iterations
+    pub(crate) fn check_repeat_iterations(expr: &Expr) -> Result<(), String> {
+        let iterations = unrolled_iterations(expr)?;
+        if iterations > MAX_REPEAT_ITERATIONS {
+            return Err(format!(
+                "Invalid Rib {}. Repeats unroll to {} iterations, exceeding the maximum of {} iterations",
+                expr, iterations, MAX_REPEAT_ITERATIONS
+            ));
+        }
+        Ok(())
+    }
+
+    // The number of repeat bodies evaluated by the unrolled expression: the
+    // iterations of sibling expressions add up, and a repeat multiplies the
+    // iterations of its body by its count
+    fn unrolled_iterations(expr: &Expr) -> Result<usize, String> {
+        let mut children = VecDeque::new();
+        expr.visit_children_bottom_up(&mut children);
+
+        let mut nested: usize = 0;
+        for child in children {
+            nested = nested.saturating_add(unrolled_iterations(child)?);
+        }
+
+        match expr {
+            Expr::Repeat(count, _, _) => {
+                if *count > MAX_REPEAT_ITERATIONS {
+                    return Err(format!(
+                        "Invalid Rib {}. Repeat count {} exceeds the maximum of {} iterations",
+                        expr, count, MAX_REPEAT_ITERATIONS
+                    ));
+                }
+                Ok(count.saturating_mul(nested.max(1)))
+            }
+            _ => Ok(nested),
+        }
+    }
+
     fn handle_if_condition(
         instruction_id: &mut InstructionId,
         if_expr: &Expr,
@@ -530,7 +648,7 @@ mod compiler_tests {
     use test_r::test;
 
     use super::*;
-    use crate::{ArmPattern, InferredType, MatchArm, Number, VariableId};
+    use crate::{ArmPattern, InferredType, LogLevel, MatchArm, Number, VariableId};
     use golem_wasm_ast::analysis::{AnalysedType, NameTypePair, TypeRecord, TypeStr};
     use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 
@@ -549,6 +667,25 @@ mod compiler_tests {
         assert_eq!(instructions, expected_instructions);
     }
 
+    #[test]
+    fn test_instructions_for_log() {
+        let literal = Expr::Literal("hello".to_string(), InferredType::Str);
+        let expr = Expr::log(LogLevel::Info, literal);
+
+        let instructions = RibByteCode::from_expr(expr).unwrap();
+
+        let instruction_set = vec![
+            RibIR::PushLit(TypeAnnotatedValue::Str("hello".to_string())),
+            RibIR::Log(LogLevel::Info),
+        ];
+
+        let expected_instructions = RibByteCode {
+            instructions: instruction_set,
+        };
+
+        assert_eq!(instructions, expected_instructions);
+    }
+
     #[test]
     fn test_instructions_for_identifier() {
         let inferred_input_type = InferredType::Str;
@@ -1018,6 +1155,85 @@ mod compiler_tests {
         assert_eq!(instructions, expected_instructions);
     }
 
+    #[cfg(test)]
+    mod invalid_repeat_tests {
+        use test_r::test;
+
+        use crate::{compiler, Expr};
+
+        #[test]
+        fn test_repeat_exceeding_max_iterations() {
+            let expr = r#"
+               repeat 101 times { some("foo") }
+            "#;
+
+            let expr = Expr::from_text(expr).unwrap();
+            let compiler_error = compiler::compile(&expr, &vec![]).unwrap_err();
+
+            assert_eq!(
+                compiler_error,
+                r#"Invalid Rib repeat 101 times { some("foo") }. Repeat count 101 exceeds the maximum of 100 iterations"#
+            );
+        }
+
+        #[test]
+        fn test_nested_repeats_exceeding_max_iterations() {
+            let expr = r#"
+               repeat 20 times { repeat 10 times { some("foo") } }
+            "#;
+
+            let expr = Expr::from_text(expr).unwrap();
+            let compiler_error = compiler::compile(&expr, &vec![]).unwrap_err();
+
+            assert_eq!(
+                compiler_error,
+                r#"Invalid Rib repeat 20 times { repeat 10 times { some("foo") } }. Repeats unroll to 200 iterations, exceeding the maximum of 100 iterations"#
+            );
+        }
+
+        #[test]
+        fn test_sibling_repeats_exceeding_max_iterations() {
+            let expr = r#"
+               let x = repeat 60 times { some("foo") };
+               let y = repeat 60 times { some("bar") };
+               x
+            "#;
+
+            let expr = Expr::from_text(expr).unwrap();
+            let compiler_error = compiler::compile(&expr, &vec![]).unwrap_err();
+
+            assert!(compiler_error.ends_with(
+                "Repeats unroll to 120 iterations, exceeding the maximum of 100 iterations"
+            ));
+        }
+
+        #[test]
+        fn test_repeats_within_max_iterations() {
+            let expr = r#"
+               repeat 5 times { let x = repeat 10 times { some("foo") }; repeat 10 times { some("bar") } }
+            "#;
+
+            let expr = Expr::from_text(expr).unwrap();
+
+            assert!(compiler::compile(&expr, &vec![]).is_ok());
+        }
+
+        #[test]
+        fn test_repeat_with_non_option_body() {
+            let expr = r#"
+               repeat 2 times { "foo" }
+            "#;
+
+            let expr = Expr::from_text(expr).unwrap();
+            let compiler_error = compiler::compile(&expr, &vec![]).unwrap_err();
+
+            assert_eq!(
+                compiler_error,
+                r#"Invalid Rib repeat 2 times { "foo" }. The body of repeat must be an option, found Str"#
+            );
+        }
+    }
+
     #[cfg(test)]
     mod invalid_function_invoke_tests {
         use test_r::test;
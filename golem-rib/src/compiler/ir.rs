@@ -12,13 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{AnalysedTypeWithUnit, ParsedFunctionSite, VariableId};
+use crate::{AnalysedTypeWithUnit, LogLevel, ParsedFunctionSite, VariableId};
 use bincode::{Decode, Encode};
 use golem_api_grpc::proto::golem::rib::rib_ir::Instruction;
 use golem_api_grpc::proto::golem::rib::{
     And, CallInstruction, ConcatInstruction, CreateFunctionNameInstruction, EqualTo, GetTag,
-    GreaterThan, GreaterThanOrEqualTo, JumpInstruction, LessThan, LessThanOrEqualTo, Negate, Or,
-    PushListInstruction, PushNoneInstruction, PushTupleInstruction, RibIr as ProtoRibIR,
+    GreaterThan, GreaterThanOrEqualTo, JumpInstruction, LessThan, LessThanOrEqualTo,
+    LogInstruction, Negate, Or, PushListInstruction, PushNoneInstruction, PushTupleInstruction,
+    RibIr as ProtoRibIR,
 };
 use golem_wasm_ast::analysis::{AnalysedType, TypeStr};
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
@@ -60,6 +61,7 @@ pub enum RibIR {
     GetTag,
     Concat(usize),
     Negate,
+    Log(LogLevel),
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Encode, Decode)]
@@ -396,6 +398,7 @@ impl TryFrom<ProtoRibIR> for RibIR {
                 ))
             }
             Instruction::Negate(_) => Ok(RibIR::Negate),
+            Instruction::Log(log_instruction) => Ok(RibIR::Log(log_instruction.level().into())),
             Instruction::Concat(concat_instruction) => {
                 Ok(RibIR::Concat(concat_instruction.arg_size as usize))
             }
@@ -516,6 +519,9 @@ impl From<RibIR> for ProtoRibIR {
                 arg_size: concat as u64,
             }),
             RibIR::Negate => Instruction::Negate(Negate {}),
+            RibIR::Log(level) => Instruction::Log(LogInstruction {
+                level: golem_api_grpc::proto::golem::rib::LogLevel::from(level) as i32,
+            }),
             RibIR::CreateFunctionName(site, reference_type) => {
                 Instruction::CreateFunctionName(CreateFunctionNameInstruction {
                     site: Some(site.into()),
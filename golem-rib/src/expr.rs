@@ -16,9 +16,10 @@ use crate::call_type::CallType;
 use crate::parser::rib_expr::rib_program;
 use crate::parser::type_name::TypeName;
 use crate::type_registry::FunctionTypeRegistry;
+use crate::version::lower_to_version;
 use crate::{
     from_string, text, type_checker, type_inference, DynamicParsedFunctionName, InferredType,
-    ParsedFunctionName, VariableId,
+    ParsedFunctionName, RibVersion, VariableId,
 };
 use bincode::{Decode, Encode};
 use combine::stream::position;
@@ -63,6 +64,8 @@ pub enum Expr {
     Unwrap(Box<Expr>, InferredType),
     Throw(String, InferredType),
     GetTag(Box<Expr>, InferredType),
+    Repeat(usize, Box<Expr>, InferredType),
+    Log(LogLevel, Box<Expr>, InferredType),
 }
 
 impl Expr {
@@ -100,6 +103,14 @@ impl Expr {
             .map_err(|err| format!("{}", err))
     }
 
+    /// Parse a text as a Rib expression written against the given version of the grammar.
+    /// Syntax introduced after that version is either interpreted the way the older
+    /// grammar did, or rejected.
+    pub fn from_text_with_version(input: &str, version: RibVersion) -> Result<Expr, String> {
+        let expr = Expr::from_text(input)?;
+        lower_to_version(expr, version)
+    }
+
     pub fn is_literal(&self) -> bool {
         matches!(self, Expr::Literal(_, _))
     }
@@ -378,6 +389,16 @@ impl Expr {
         Expr::GetTag(Box::new(expr), InferredType::Unknown)
     }
 
+    pub fn repeat(count: usize, body: Expr) -> Self {
+        let inferred_type = body.inferred_type();
+        Expr::Repeat(count, Box::new(body), inferred_type)
+    }
+
+    pub fn log(level: LogLevel, expr: Expr) -> Self {
+        let inferred_type = expr.inferred_type();
+        Expr::Log(level, Box::new(expr), inferred_type)
+    }
+
     pub fn tuple(expressions: Vec<Expr>) -> Self {
         let inferred_type = InferredType::Tuple(
             expressions
@@ -427,6 +448,8 @@ impl Expr {
             | Expr::Unwrap(_, inferred_type)
             | Expr::Throw(_, inferred_type)
             | Expr::GetTag(_, inferred_type)
+            | Expr::Repeat(_, _, inferred_type)
+            | Expr::Log(_, _, inferred_type)
             | Expr::And(_, _, inferred_type)
             | Expr::Or(_, _, inferred_type)
             | Expr::Call(_, _, inferred_type) => inferred_type.clone(),
@@ -560,6 +583,8 @@ impl Expr {
             | Expr::Unwrap(_, inferred_type)
             | Expr::Throw(_, inferred_type)
             | Expr::GetTag(_, inferred_type)
+            | Expr::Repeat(_, _, inferred_type)
+            | Expr::Log(_, _, inferred_type)
             | Expr::And(_, _, inferred_type)
             | Expr::Or(_, _, inferred_type)
             | Expr::Call(_, _, inferred_type) => {
@@ -604,6 +629,8 @@ impl Expr {
             | Expr::And(_, _, inferred_type)
             | Expr::Or(_, _, inferred_type)
             | Expr::GetTag(_, inferred_type)
+            | Expr::Repeat(_, _, inferred_type)
+            | Expr::Log(_, _, inferred_type)
             | Expr::Call(_, _, inferred_type) => {
                 if new_inferred_type != InferredType::Unknown {
                     *inferred_type = new_inferred_type;
@@ -703,6 +730,64 @@ impl Display for Number {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Encode, Decode)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    pub fn from_keyword(keyword: &str) -> Option<LogLevel> {
+        match keyword {
+            "trace" => Some(LogLevel::Trace),
+            "debug" => Some(LogLevel::Debug),
+            "info" => Some(LogLevel::Info),
+            "warn" => Some(LogLevel::Warn),
+            "error" => Some(LogLevel::Error),
+            _ => None,
+        }
+    }
+}
+
+impl Display for LogLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogLevel::Trace => write!(f, "trace"),
+            LogLevel::Debug => write!(f, "debug"),
+            LogLevel::Info => write!(f, "info"),
+            LogLevel::Warn => write!(f, "warn"),
+            LogLevel::Error => write!(f, "error"),
+        }
+    }
+}
+
+impl From<golem_api_grpc::proto::golem::rib::LogLevel> for LogLevel {
+    fn from(value: golem_api_grpc::proto::golem::rib::LogLevel) -> Self {
+        match value {
+            golem_api_grpc::proto::golem::rib::LogLevel::Trace => LogLevel::Trace,
+            golem_api_grpc::proto::golem::rib::LogLevel::Debug => LogLevel::Debug,
+            golem_api_grpc::proto::golem::rib::LogLevel::Info => LogLevel::Info,
+            golem_api_grpc::proto::golem::rib::LogLevel::Warn => LogLevel::Warn,
+            golem_api_grpc::proto::golem::rib::LogLevel::Error => LogLevel::Error,
+        }
+    }
+}
+
+impl From<LogLevel> for golem_api_grpc::proto::golem::rib::LogLevel {
+    fn from(value: LogLevel) -> Self {
+        match value {
+            LogLevel::Trace => golem_api_grpc::proto::golem::rib::LogLevel::Trace,
+            LogLevel::Debug => golem_api_grpc::proto::golem::rib::LogLevel::Debug,
+            LogLevel::Info => golem_api_grpc::proto::golem::rib::LogLevel::Info,
+            LogLevel::Warn => golem_api_grpc::proto::golem::rib::LogLevel::Warn,
+            LogLevel::Error => golem_api_grpc::proto::golem::rib::LogLevel::Error,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
 pub struct MatchArm {
     pub arm_pattern: ArmPattern,
@@ -1025,6 +1110,17 @@ impl TryFrom<golem_api_grpc::proto::golem::rib::Expr> for Expr {
                 Expr::get_tag((*expr).try_into()?)
             }
 
+            golem_api_grpc::proto::golem::rib::expr::Expr::Repeat(expr) => {
+                let body = expr.body.ok_or("Missing body in repeat")?;
+                Expr::repeat(expr.count as usize, (*body).try_into()?)
+            }
+
+            golem_api_grpc::proto::golem::rib::expr::Expr::Log(expr) => {
+                let level = expr.level().into();
+                let expr = expr.expr.ok_or("Missing expr in log")?;
+                Expr::log(level, (*expr).try_into()?)
+            }
+
             golem_api_grpc::proto::golem::rib::expr::Expr::Unwrap(expr) => {
                 let expr = expr.expr.ok_or("Missing expr")?;
                 let expr: Expr = (*expr).try_into()?;
@@ -1326,6 +1422,20 @@ impl From<Expr> for golem_api_grpc::proto::golem::rib::Expr {
                     expr: Some(Box::new((*expr).into())),
                 }),
             )),
+            Expr::Repeat(count, body, _) => {
+                Some(golem_api_grpc::proto::golem::rib::expr::Expr::Repeat(
+                    Box::new(golem_api_grpc::proto::golem::rib::RepeatExpr {
+                        count: count as u64,
+                        body: Some(Box::new((*body).into())),
+                    }),
+                ))
+            }
+            Expr::Log(level, expr, _) => Some(golem_api_grpc::proto::golem::rib::expr::Expr::Log(
+                Box::new(golem_api_grpc::proto::golem::rib::LogExpr {
+                    level: golem_api_grpc::proto::golem::rib::LogLevel::from(level) as i32,
+                    expr: Some(Box::new((*expr).into())),
+                }),
+            )),
             Expr::And(left, right, _) => Some(golem_api_grpc::proto::golem::rib::expr::Expr::And(
                 Box::new(golem_api_grpc::proto::golem::rib::AndExpr {
                     left: Some(Box::new((*left).into())),
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::interpreter::result::RibInterpreterResult;
-use crate::VariableId;
+use crate::{LogLevel, VariableId};
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 use std::collections::HashMap;
 use std::fmt::Debug;
@@ -24,6 +24,7 @@ use std::sync::Arc;
 pub struct InterpreterEnv {
     pub env: HashMap<EnvironmentKey, RibInterpreterResult>,
     pub call_worker_function_async: RibFunctionInvoke,
+    pub rib_logger: RibLogger,
 }
 
 impl Debug for InterpreterEnv {
@@ -43,11 +44,15 @@ pub type RibFunctionInvoke = Arc<
         + Sync,
 >;
 
+// Receives the value of every `log(level, expr)` evaluated by the interpreter
+pub type RibLogger = Arc<dyn Fn(LogLevel, &RibInterpreterResult) + Send + Sync>;
+
 impl Default for InterpreterEnv {
     fn default() -> Self {
         InterpreterEnv {
             env: HashMap::new(),
             call_worker_function_async: internal::default_worker_invoke_async(),
+            rib_logger: internal::default_rib_logger(),
         }
     }
 }
@@ -60,6 +65,7 @@ impl InterpreterEnv {
         InterpreterEnv {
             env,
             call_worker_function_async,
+            rib_logger: internal::default_rib_logger(),
         }
     }
 
@@ -71,6 +77,10 @@ impl InterpreterEnv {
         (self.call_worker_function_async)(function_name, args)
     }
 
+    pub fn log(&self, level: LogLevel, value: &RibInterpreterResult) {
+        (self.rib_logger)(level, value)
+    }
+
     pub fn from_input(env: HashMap<String, TypeAnnotatedValue>) -> Self {
         let env = env
             .into_iter()
@@ -80,6 +90,7 @@ impl InterpreterEnv {
         InterpreterEnv {
             env,
             call_worker_function_async: internal::default_worker_invoke_async(),
+            rib_logger: internal::default_rib_logger(),
         }
     }
 
@@ -119,7 +130,7 @@ impl EnvironmentKey {
 }
 
 mod internal {
-    use crate::interpreter::env::RibFunctionInvoke;
+    use crate::interpreter::env::{RibFunctionInvoke, RibLogger};
     use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
     use golem_wasm_rpc::protobuf::TypedTuple;
     use std::sync::Arc;
@@ -134,4 +145,8 @@ mod internal {
             })
         })
     }
+
+    pub(crate) fn default_rib_logger() -> RibLogger {
+        Arc::new(|_, _| {})
+    }
 }
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-pub use env::RibFunctionInvoke;
+pub use env::{RibFunctionInvoke, RibLogger};
 pub use literal::*;
 pub use result::*;
 pub use rib_interpreter::*;
@@ -36,6 +36,19 @@ pub async fn interpret(
     interpreter.run(rib.clone()).await
 }
 
+// Same as `interpret`, with the values of `log` statements handed over to `rib_logger`.
+// Without a logger, `log` statements have no effect.
+pub async fn interpret_with_logger(
+    rib: &RibByteCode,
+    rib_input: HashMap<String, TypeAnnotatedValue>,
+    function_invoke: RibFunctionInvoke,
+    rib_logger: RibLogger,
+) -> Result<RibInterpreterResult, String> {
+    let mut interpreter = Interpreter::new(rib_input, function_invoke);
+    interpreter.env.rib_logger = rib_logger;
+    interpreter.run(rib.clone()).await
+}
+
 // This function can be used for those the Rib Scripts
 // where there are no side effecting function calls.
 // It is recommended to use `interpret` over `interpret_pure` if you are unsure.
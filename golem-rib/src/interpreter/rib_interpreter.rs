@@ -197,6 +197,10 @@ impl Interpreter {
                     internal::run_negate_instruction(&mut self.stack)?;
                 }
 
+                RibIR::Log(level) => {
+                    internal::run_log_instruction(level, self)?;
+                }
+
                 RibIR::Label(_) => {}
 
                 RibIR::And => {
@@ -221,8 +225,8 @@ mod internal {
     use crate::interpreter::result::RibInterpreterResult;
     use crate::interpreter::stack::InterpreterStack;
     use crate::{
-        FunctionReferenceType, GetLiteralValue, InstructionId, Interpreter, ParsedFunctionName,
-        ParsedFunctionReference, ParsedFunctionSite, RibIR, VariableId,
+        FunctionReferenceType, GetLiteralValue, InstructionId, Interpreter, LogLevel,
+        ParsedFunctionName, ParsedFunctionReference, ParsedFunctionSite, RibIR, VariableId,
     };
     use golem_wasm_ast::analysis::AnalysedType;
     use golem_wasm_ast::analysis::TypeResult;
@@ -248,6 +252,21 @@ mod internal {
         Ok(())
     }
 
+    // The logged value is left on the stack, so that `log` doesn't change the result
+    pub(crate) fn run_log_instruction(
+        level: LogLevel,
+        interpreter: &mut Interpreter,
+    ) -> Result<(), String> {
+        let value = interpreter
+            .stack
+            .pop()
+            .ok_or("Expected a value on the stack to log".to_string())?;
+
+        interpreter.env.log(level, &value);
+        interpreter.stack.push(value);
+        Ok(())
+    }
+
     pub(crate) fn run_load_var_instruction(
         variable_id: VariableId,
         interpreter: &mut Interpreter,
@@ -1506,6 +1525,117 @@ mod interpreter_tests {
         }
     }
 
+    mod repeat_tests {
+        use test_r::test;
+
+        use crate::{compiler, Expr, FunctionTypeRegistry, Interpreter};
+        use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
+
+        #[test]
+        async fn test_interpreter_for_repeat_with_some() {
+            let mut interpreter = Interpreter::default();
+
+            let expr = r#"
+           let result = repeat 3 times {
+             let x: option<u64> = some(1u64);
+             x
+           };
+
+           match result {
+              some(v) => v,
+              none => 0u64
+           }
+        "#;
+
+            let mut expr = Expr::from_text(expr).unwrap();
+            expr.infer_types(&FunctionTypeRegistry::empty()).unwrap();
+            let compiled = compiler::compile(&expr, &vec![]).unwrap();
+            let result = interpreter.run(compiled.byte_code).await.unwrap();
+
+            assert_eq!(result.get_val().unwrap(), TypeAnnotatedValue::U64(1));
+        }
+
+        #[test]
+        async fn test_interpreter_for_repeat_with_none() {
+            let mut interpreter = Interpreter::default();
+
+            let expr = r#"
+           let result = repeat 3 times {
+             let x: option<u64> = none;
+             x
+           };
+
+           match result {
+              some(v) => v,
+              none => 0u64
+           }
+        "#;
+
+            let mut expr = Expr::from_text(expr).unwrap();
+            expr.infer_types(&FunctionTypeRegistry::empty()).unwrap();
+            let compiled = compiler::compile(&expr, &vec![]).unwrap();
+            let result = interpreter.run(compiled.byte_code).await.unwrap();
+
+            assert_eq!(result.get_val().unwrap(), TypeAnnotatedValue::U64(0));
+        }
+    }
+
+    mod log_tests {
+        use test_r::test;
+
+        use crate::{compiler, Expr, FunctionTypeRegistry, Interpreter, LogLevel};
+        use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
+        use std::sync::{Arc, Mutex};
+
+        #[test]
+        async fn test_interpreter_for_log() {
+            let logged = Arc::new(Mutex::new(vec![]));
+            let logged_clone = logged.clone();
+
+            let mut interpreter = Interpreter::default();
+            interpreter.env.rib_logger = Arc::new(move |level, value| {
+                logged_clone
+                    .lock()
+                    .unwrap()
+                    .push((level, value.get_val().unwrap()));
+            });
+
+            let expr = r#"
+           let x: u64 = 1;
+           let y = log(info, x);
+           y
+        "#;
+
+            let mut expr = Expr::from_text(expr).unwrap();
+            expr.infer_types(&FunctionTypeRegistry::empty()).unwrap();
+            let compiled = compiler::compile(&expr, &vec![]).unwrap();
+            let result = interpreter.run(compiled.byte_code).await.unwrap();
+
+            assert_eq!(result.get_val().unwrap(), TypeAnnotatedValue::U64(1));
+            assert_eq!(
+                logged.lock().unwrap().clone(),
+                vec![(LogLevel::Info, TypeAnnotatedValue::U64(1))]
+            );
+        }
+
+        #[test]
+        async fn test_interpreter_for_log_without_logger() {
+            let mut interpreter = Interpreter::default();
+
+            let expr = r#"
+           let x: u64 = 1;
+           log(debug, x)
+        "#;
+
+            let mut expr = Expr::from_text(expr).unwrap();
+            expr.infer_types(&FunctionTypeRegistry::empty()).unwrap();
+            let compiled = compiler::compile(&expr, &vec![]).unwrap();
+            let result = interpreter.run(compiled.byte_code).await.unwrap();
+
+            assert_eq!(result.get_val().unwrap(), TypeAnnotatedValue::U64(1));
+        }
+    }
+
     mod dynamic_resource_parameter_tests {
         use test_r::test;
 
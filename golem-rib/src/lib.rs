@@ -22,6 +22,7 @@ pub use text::*;
 pub use type_inference::*;
 pub use type_registry::*;
 pub use variable_id::*;
+pub use version::*;
 
 mod call_type;
 mod compiler;
@@ -36,6 +37,7 @@ mod type_inference;
 mod type_refinement;
 mod type_registry;
 mod variable_id;
+mod version;
 
 #[cfg(test)]
 test_r::enable!();
@@ -19,6 +19,8 @@ use combine::{many, ParseError, Parser, Stream};
 use crate::expr::Expr;
 use crate::parser::errors::RibParseError;
 
+// `repeat` is deliberately not reserved, so that identifiers named `repeat`
+// in programs written against Rib V1 keep parsing
 const RESERVED_KEYWORDS: &[&str] = &[
     "if", "then", "else", "match", "ok", "some", "err", "none", "let",
 ];
@@ -0,0 +1,106 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use combine::parser::char::{char, letter, spaces, string};
+use combine::{attempt, many1, ParseError, Parser};
+
+use crate::expr::{Expr, LogLevel};
+use crate::parser::errors::RibParseError;
+use crate::parser::rib_expr::rib_expr;
+
+// log(<level>, <expr>)
+pub fn log<Input>() -> impl Parser<Input, Output = Expr>
+where
+    Input: combine::Stream<Token = char>,
+    RibParseError: Into<
+        <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
+    >,
+{
+    // The whole prefix up to the comma is attempted, so that a call to a function
+    // that happens to be named `log` is still parsed as a call
+    attempt(
+        (
+            string("log").skip(spaces()),
+            char('(').skip(spaces()),
+            many1(letter()).and_then(|level: String| {
+                LogLevel::from_keyword(level.as_str()).ok_or_else(|| {
+                    RibParseError::Message(format!("Invalid log level {}", level)).into()
+                })
+            }),
+            spaces().with(char(',')),
+        )
+            .map(|(_, _, level, _)| level),
+    )
+    .and(rib_expr().skip(char(')')))
+    .map(|(level, expr)| Expr::log(level, expr))
+    .message("Unable to parse log")
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use combine::EasyParser;
+
+    use super::*;
+    use crate::{DynamicParsedFunctionName, DynamicParsedFunctionReference, ParsedFunctionSite};
+
+    #[test]
+    fn test_log() {
+        let input = "log(info, foo)";
+        let result = rib_expr().easy_parse(input);
+        assert_eq!(
+            result,
+            Ok((Expr::log(LogLevel::Info, Expr::identifier("foo")), ""))
+        );
+    }
+
+    #[test]
+    fn test_log_record() {
+        let input = "log(debug, { a: foo, b: \"bar\" })";
+        let result = rib_expr().easy_parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Expr::log(
+                    LogLevel::Debug,
+                    Expr::record(vec![
+                        ("a".to_string(), Expr::identifier("foo")),
+                        ("b".to_string(), Expr::literal("bar"))
+                    ])
+                ),
+                ""
+            ))
+        );
+    }
+
+    #[test]
+    fn test_call_to_function_named_log() {
+        let input = "log(foo, bar)";
+        let result = rib_expr().easy_parse(input);
+        let expected = Ok((
+            Expr::call(
+                DynamicParsedFunctionName {
+                    site: ParsedFunctionSite::Global,
+                    function: DynamicParsedFunctionReference::Function {
+                        function: "log".to_string(),
+                    },
+                },
+                vec![Expr::identifier("foo"), Expr::identifier("bar")],
+            ),
+            "",
+        ));
+        assert_eq!(result, expected);
+    }
+}
@@ -21,12 +21,14 @@ mod flag;
 mod identifier;
 mod let_binding;
 pub(crate) mod literal;
+mod log;
 mod multi_line_code_block;
 mod not;
 mod number;
 mod optional;
 mod pattern_match;
 mod record;
+mod repeat;
 mod result;
 pub(crate) mod rib_expr;
 mod select_field;
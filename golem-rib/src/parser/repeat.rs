@@ -0,0 +1,107 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use combine::parser::char::{alpha_num, char, digit, spaces, string};
+use combine::{attempt, many1, not_followed_by, ParseError, Parser};
+
+use crate::expr::Expr;
+use crate::parser::errors::RibParseError;
+use crate::parser::multi_line_code_block::multi_line_block;
+
+// repeat <n> times { <block> }
+pub fn repeat<Input>() -> impl Parser<Input, Output = Expr>
+where
+    Input: combine::Stream<Token = char>,
+    RibParseError: Into<
+        <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
+    >,
+{
+    // The whole `repeat <n> times` prefix is attempted, so that an identifier
+    // that happens to be named `repeat` is still parsed as an identifier
+    attempt(
+        (
+            string("repeat")
+                .skip(not_followed_by(alpha_num().or(char('-')).or(char('_'))))
+                .skip(spaces()),
+            many1(digit())
+                .and_then(|digits: String| {
+                    digits.parse::<usize>().map_err(|_| {
+                        RibParseError::Message("Unable to parse repeat count".to_string()).into()
+                    })
+                })
+                .skip(spaces()),
+            string("times").skip(spaces()),
+        )
+            .map(|(_, count, _)| count),
+    )
+    .and(multi_line_block())
+    .map(|(count, body)| Expr::repeat(count, body))
+    .message("Unable to parse repeat")
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use combine::EasyParser;
+
+    use super::*;
+    use crate::parser::rib_expr::rib_expr;
+
+    #[test]
+    fn test_repeat() {
+        let input = "repeat 3 times { foo }";
+        let result = rib_expr().easy_parse(input);
+        assert_eq!(result, Ok((Expr::repeat(3, Expr::identifier("foo")), "")));
+    }
+
+    #[test]
+    fn test_repeat_with_multi_line_body() {
+        let input = "repeat 10 times { let x = foo; some(x) }";
+        let result = rib_expr().easy_parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Expr::repeat(
+                    10,
+                    Expr::multiple(vec![
+                        Expr::let_binding("x", Expr::identifier("foo")),
+                        Expr::option(Some(Expr::identifier("x")))
+                    ])
+                ),
+                ""
+            ))
+        );
+    }
+
+    #[test]
+    fn test_repeat_without_count() {
+        let input = "repeat times { foo }";
+        let result = Expr::from_text(input);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_identifier_named_repeat() {
+        let input = "let repeat = foo; repeat";
+        let result = Expr::from_text(input);
+        assert_eq!(
+            result,
+            Ok(Expr::multiple(vec![
+                Expr::let_binding("repeat", Expr::identifier("foo")),
+                Expr::identifier("repeat")
+            ]))
+        );
+    }
+}
@@ -88,12 +88,14 @@ mod internal {
     use crate::parser::identifier::identifier;
     use crate::parser::let_binding::let_binding;
     use crate::parser::literal::literal;
+    use crate::parser::log::log;
     use crate::parser::multi_line_code_block::multi_line_block;
     use crate::parser::not::not;
     use crate::parser::number::number;
     use crate::parser::optional::option;
     use crate::parser::pattern_match::pattern_match;
     use crate::parser::record::record;
+    use crate::parser::repeat::repeat;
     use crate::parser::result::result;
 
     use crate::parser::select_field::select_field;
@@ -117,6 +119,8 @@ mod internal {
                 pattern_match(),
                 let_binding(),
                 conditional(),
+                repeat(),
+                log(),
                 selection_expr(),
                 flag_or_record(),
                 multi_line_block(),
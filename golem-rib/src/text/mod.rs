@@ -13,13 +13,20 @@
 // limitations under the License.
 
 use crate::expr::Expr;
-use crate::ArmPattern;
+use crate::{ArmPattern, RibVersion};
 
 mod writer;
 
 use crate::text::writer::WriterError;
 
 pub fn from_string(input: impl AsRef<str>) -> Result<Expr, String> {
+    from_string_with_version(input, RibVersion::LATEST)
+}
+
+pub fn from_string_with_version(
+    input: impl AsRef<str>,
+    version: RibVersion,
+) -> Result<Expr, String> {
     let trimmed = input.as_ref().trim();
 
     // This check is kept for backward compatibility to support rib programs that were wrapped in `${..}`
@@ -28,9 +35,9 @@ pub fn from_string(input: impl AsRef<str>) -> Result<Expr, String> {
     if trimmed.starts_with("${") && trimmed.ends_with("}") {
         let trimmed_open = trimmed.strip_prefix("${").unwrap();
         let trimmed_closing = trimmed_open.strip_suffix('}').unwrap();
-        Expr::from_text(trimmed_closing)
+        Expr::from_text_with_version(trimmed_closing, version)
     } else {
-        Expr::from_text(input.as_ref())
+        Expr::from_text_with_version(input.as_ref(), version)
     }
 }
 
@@ -1665,7 +1672,7 @@ mod match_tests {
 mod if_cond_tests {
     use test_r::test;
 
-    use crate::expr::Expr;
+    use crate::expr::{Expr, LogLevel};
     use crate::text::{from_string, to_string};
 
     #[test]
@@ -1788,4 +1795,34 @@ mod if_cond_tests {
         let output_expr = from_string(expr_str.as_str()).unwrap();
         assert_eq!((expr_str, input_expr), (expected_str, output_expr));
     }
+
+    #[test]
+    fn test_round_trip_repeat() {
+        let input_expr = Expr::repeat(
+            3,
+            Expr::cond(
+                Expr::equal_to(Expr::identifier("foo"), Expr::number(1f64)),
+                Expr::option(Some(Expr::identifier("foo"))),
+                Expr::option(None),
+            ),
+        );
+
+        let expr_str = to_string(&input_expr).unwrap();
+        let expected_str = r#"repeat 3 times { if foo == 1 then some(foo) else none }"#.to_string();
+        let output_expr = from_string(expr_str.as_str()).unwrap();
+        assert_eq!((expr_str, input_expr), (expected_str, output_expr));
+    }
+
+    #[test]
+    fn test_round_trip_log() {
+        let input_expr = Expr::log(
+            LogLevel::Warn,
+            Expr::select_field(Expr::identifier("request"), "body"),
+        );
+
+        let expr_str = to_string(&input_expr).unwrap();
+        let expected_str = r#"log(warn, request.body)"#.to_string();
+        let output_expr = from_string(expr_str.as_str()).unwrap();
+        assert_eq!((expr_str, input_expr), (expected_str, output_expr));
+    }
 }
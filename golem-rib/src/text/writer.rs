@@ -281,6 +281,20 @@ impl<W: Write> Writer<W> {
                 self.write_expr(expr)?;
                 self.write_str(")")
             }
+            Expr::Repeat(count, body, _) => {
+                self.write_str("repeat ")?;
+                self.write_display(count)?;
+                self.write_str(" times { ")?;
+                self.write_expr(body)?;
+                self.write_str(" }")
+            }
+            Expr::Log(level, expr, _) => {
+                self.write_str("log(")?;
+                self.write_display(level)?;
+                self.write_str(", ")?;
+                self.write_expr(expr)?;
+                self.write_str(")")
+            }
             Expr::And(left, right, _) => {
                 self.write_expr(left)?;
                 self.write_str(" && ")?;
@@ -133,6 +133,18 @@ pub fn check_unresolved_types(expr: &Expr) -> Result<(), UnResolvedTypesError> {
             Expr::Unwrap(_, _) => {}
             Expr::Throw(_, _) => {}
             Expr::GetTag(_, _) => {}
+            Expr::Repeat(_, body, inferred_type) => {
+                queue.push_back(body);
+                if inferred_type.un_resolved() {
+                    return Err(UnResolvedTypesError::new(expr));
+                }
+            }
+            Expr::Log(_, log_expr, inferred_type) => {
+                queue.push_back(log_expr);
+                if inferred_type.un_resolved() {
+                    return Err(UnResolvedTypesError::new(expr));
+                }
+            }
         }
     }
 
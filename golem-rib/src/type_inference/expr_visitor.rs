@@ -73,6 +73,9 @@ pub fn visit_children_bottom_up_mut<'a>(expr: &'a mut Expr, queue: &mut VecDeque
             queue.push_back(&mut *exr);
         }
 
+        Expr::Repeat(_, body, _) => queue.push_back(&mut *body),
+        Expr::Log(_, expr, _) => queue.push_back(&mut *expr),
+
         Expr::Literal(_, _) => {}
         Expr::Number(_, _, _) => {}
         Expr::Flags(_, _) => {}
@@ -150,6 +153,8 @@ pub fn visit_children_bottom_up<'a>(expr: &'a Expr, queue: &mut VecDeque<&'a Exp
         Expr::GetTag(expr, _) => {
             queue.push_back(expr);
         }
+        Expr::Repeat(_, body, _) => queue.push_back(body),
+        Expr::Log(_, expr, _) => queue.push_back(expr),
 
         Expr::Literal(_, _) => {}
         Expr::Number(_, _, _) => {}
@@ -253,6 +258,8 @@ pub fn visit_children_mut_top_down<'a>(expr: &'a mut Expr, queue: &mut VecDeque<
         }
 
         Expr::Unwrap(expr, _) => queue.push_front(&mut *expr),
+        Expr::Repeat(_, body, _) => queue.push_front(&mut *body),
+        Expr::Log(_, expr, _) => queue.push_front(&mut *expr),
         Expr::Literal(_, _) => {}
         Expr::Number(_, _, _) => {}
         Expr::Flags(_, _) => {}
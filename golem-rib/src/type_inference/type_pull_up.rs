@@ -217,6 +217,14 @@ pub fn type_pull_up(expr: &Expr) -> Result<Expr, String> {
             Expr::GetTag(_, inferred_type) => {
                 internal::handle_get_tag(expr, inferred_type, &mut inferred_type_stack);
             }
+
+            Expr::Repeat(count, body, inferred_type) => {
+                internal::handle_repeat(*count, body, inferred_type, &mut inferred_type_stack);
+            }
+
+            Expr::Log(level, expr, inferred_type) => {
+                internal::handle_log(*level, expr, inferred_type, &mut inferred_type_stack);
+            }
         }
     }
 
@@ -230,7 +238,7 @@ mod internal {
 
     use crate::type_refinement::precise_types::{ListType, RecordType};
     use crate::type_refinement::TypeRefinement;
-    use crate::{Expr, InferredType, MatchArm, VariableId};
+    use crate::{Expr, InferredType, LogLevel, MatchArm, VariableId};
     use std::collections::VecDeque;
     use std::ops::Deref;
 
@@ -640,6 +648,40 @@ mod internal {
         inferred_type_stack.push_front(new_get_tag);
     }
 
+    pub(crate) fn handle_repeat(
+        count: usize,
+        original_body: &Expr,
+        current_inferred_type: &InferredType,
+        inferred_type_stack: &mut VecDeque<Expr>,
+    ) {
+        let body = inferred_type_stack
+            .pop_front()
+            .unwrap_or(original_body.clone());
+        let new_repeat = Expr::Repeat(
+            count,
+            Box::new(body.clone()),
+            current_inferred_type.merge(body.inferred_type()),
+        );
+        inferred_type_stack.push_front(new_repeat);
+    }
+
+    pub(crate) fn handle_log(
+        level: LogLevel,
+        original_expr: &Expr,
+        current_inferred_type: &InferredType,
+        inferred_type_stack: &mut VecDeque<Expr>,
+    ) {
+        let expr = inferred_type_stack
+            .pop_front()
+            .unwrap_or(original_expr.clone());
+        let new_log = Expr::Log(
+            level,
+            Box::new(expr.clone()),
+            current_inferred_type.merge(expr.inferred_type()),
+        );
+        inferred_type_stack.push_front(new_log);
+    }
+
     pub(crate) fn handle_let(
         original_variable_id: &VariableId,
         original_expr: &Expr,
@@ -49,6 +49,14 @@ pub fn push_types_down(expr: &mut Expr) -> Result<(), String> {
                 expr.add_infer_type_mut(inferred_type.clone());
                 queue.push_back(expr);
             }
+            Expr::Repeat(_, body, inferred_type) => {
+                body.add_infer_type_mut(inferred_type.clone());
+                queue.push_back(body);
+            }
+            Expr::Log(_, expr, inferred_type) => {
+                expr.add_infer_type_mut(inferred_type.clone());
+                queue.push_back(expr);
+            }
             Expr::Option(Some(expr), inferred_type) => {
                 internal::handle_option(expr, inferred_type)?;
                 queue.push_back(expr);
@@ -284,6 +284,18 @@ pub fn unify_types(expr: &mut Expr) -> Result<(), Vec<String>> {
                     }
                 }
             }
+            Expr::Repeat(_, body, inferred_type) | Expr::Log(_, body, inferred_type) => {
+                queue.push(body);
+                let unified_inferred_type = inferred_type.unify();
+
+                match unified_inferred_type {
+                    Ok(unified_type) => *inferred_type = unified_type,
+                    Err(e) => {
+                        errors.push(format!("Unable to resolve the type of {}", expr_str));
+                        errors.push(e);
+                    }
+                }
+            }
             Expr::Unwrap(expr, inferred_type) => {
                 queue.push(expr);
                 let unified_inferred_type = inferred_type.unify();
@@ -0,0 +1,225 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{DynamicParsedFunctionName, DynamicParsedFunctionReference, Expr, ParsedFunctionSite};
+use poem_openapi::Enum;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Version of the Rib grammar a program is written against.
+///
+/// Programs are always parsed with the latest grammar, and then lowered to the
+/// version they declare, so that syntax introduced later never changes the meaning
+/// of a program written for an older version.
+///
+/// New definitions that don't declare a version use `LATEST`, while data stored or
+/// sent before versioning was introduced is read as `LEGACY`.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Enum,
+)]
+pub enum RibVersion {
+    /// The grammar before explicit versioning was introduced
+    V1,
+    /// Adds `repeat <n> times { .. }` and `log(<level>, <expr>)`
+    V2,
+}
+
+impl RibVersion {
+    pub const LATEST: RibVersion = RibVersion::V2;
+
+    /// The version of programs that were written before the version was recorded
+    pub const LEGACY: RibVersion = RibVersion::V1;
+
+    pub const OLDEST_SUPPORTED: RibVersion = RibVersion::V1;
+
+    pub fn supports(&self, feature: RibFeature) -> bool {
+        *self >= feature.introduced_in()
+    }
+
+    pub fn as_u32(&self) -> u32 {
+        match self {
+            RibVersion::V1 => 1,
+            RibVersion::V2 => 2,
+        }
+    }
+}
+
+impl TryFrom<u32> for RibVersion {
+    type Error = String;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(RibVersion::V1),
+            2 => Ok(RibVersion::V2),
+            _ => Err(format!(
+                "Unsupported Rib version {}. Supported versions are {} to {}",
+                value,
+                RibVersion::OLDEST_SUPPORTED,
+                RibVersion::LATEST
+            )),
+        }
+    }
+}
+
+impl FromStr for RibVersion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let number = trimmed
+            .strip_prefix('v')
+            .or_else(|| trimmed.strip_prefix('V'))
+            .unwrap_or(trimmed);
+
+        number
+            .parse::<u32>()
+            .map_err(|_| format!("Invalid Rib version {}", s))
+            .and_then(RibVersion::try_from)
+    }
+}
+
+impl Display for RibVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "V{}", self.as_u32())
+    }
+}
+
+/// Syntax that is only available starting from a given Rib version
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RibFeature {
+    Repeat,
+    Log,
+}
+
+impl RibFeature {
+    pub fn introduced_in(&self) -> RibVersion {
+        match self {
+            RibFeature::Repeat => RibVersion::V2,
+            RibFeature::Log => RibVersion::V2,
+        }
+    }
+}
+
+impl Display for RibFeature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RibFeature::Repeat => write!(f, "repeat"),
+            RibFeature::Log => write!(f, "log"),
+        }
+    }
+}
+
+// Rewrites an expression parsed with the latest grammar into what the given version
+// of the grammar would have produced, failing on syntax that did not exist yet
+pub(crate) fn lower_to_version(mut expr: Expr, version: RibVersion) -> Result<Expr, String> {
+    if version == RibVersion::LATEST {
+        return Ok(expr);
+    }
+
+    let mut queue = VecDeque::new();
+    queue.push_back(&mut expr);
+
+    while let Some(expr) = queue.pop_back() {
+        match expr {
+            // Before V2, `log(<level>, <expr>)` was a call to a global function named `log`
+            Expr::Log(level, inner, _) if !version.supports(RibFeature::Log) => {
+                let function_name = DynamicParsedFunctionName {
+                    site: ParsedFunctionSite::Global,
+                    function: DynamicParsedFunctionReference::Function {
+                        function: "log".to_string(),
+                    },
+                };
+
+                let level = Expr::identifier(level.to_string());
+                let inner = std::mem::replace(inner.as_mut(), Expr::empty_expr());
+
+                *expr = Expr::call(function_name, vec![level, inner]);
+
+                expr.visit_children_mut_bottom_up(&mut queue);
+            }
+            Expr::Repeat(_, _, _) if !version.supports(RibFeature::Repeat) => {
+                return Err(unsupported_feature(RibFeature::Repeat, version));
+            }
+            _ => expr.visit_children_mut_bottom_up(&mut queue),
+        }
+    }
+
+    Ok(expr)
+}
+
+fn unsupported_feature(feature: RibFeature, version: RibVersion) -> String {
+    format!(
+        "`{}` requires Rib version {} or later, but the program declares version {}",
+        feature,
+        feature.introduced_in(),
+        version
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::{
+        DynamicParsedFunctionName, DynamicParsedFunctionReference, Expr, LogLevel,
+        ParsedFunctionSite, RibVersion,
+    };
+
+    #[test]
+    fn test_parse_rib_version() {
+        assert_eq!("1".parse::<RibVersion>(), Ok(RibVersion::V1));
+        assert_eq!("v2".parse::<RibVersion>(), Ok(RibVersion::V2));
+        assert_eq!("V2".parse::<RibVersion>(), Ok(RibVersion::V2));
+        assert!("3".parse::<RibVersion>().is_err());
+        assert!("latest".parse::<RibVersion>().is_err());
+    }
+
+    #[test]
+    fn test_log_in_latest_version() {
+        let result = Expr::from_text_with_version("log(info, foo)", RibVersion::LATEST);
+        assert_eq!(
+            result,
+            Ok(Expr::log(LogLevel::Info, Expr::identifier("foo")))
+        );
+    }
+
+    #[test]
+    fn test_log_in_v1_is_a_function_call() {
+        let result = Expr::from_text_with_version("log(info, foo)", RibVersion::V1);
+        let expected = Expr::call(
+            DynamicParsedFunctionName {
+                site: ParsedFunctionSite::Global,
+                function: DynamicParsedFunctionReference::Function {
+                    function: "log".to_string(),
+                },
+            },
+            vec![Expr::identifier("info"), Expr::identifier("foo")],
+        );
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn test_repeat_in_v1_is_rejected() {
+        let result = Expr::from_text_with_version("repeat 3 times { foo }", RibVersion::V1);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_repeat_in_v2() {
+        let result = Expr::from_text_with_version("repeat 3 times { foo }", RibVersion::V2);
+        assert_eq!(result, Ok(Expr::repeat(3, Expr::identifier("foo"))));
+    }
+}
@@ -1657,6 +1657,74 @@ impl Component {
     }
 }
 
+/// Default arguments and environment variables of the workers of a component, used when
+/// a worker creation request does not specify them.
+///
+/// The values can contain the `{{worker_name}}` and `{{component_id}}` placeholders, which
+/// are resolved when the worker is created.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize, Object)]
+pub struct WorkerDefaults {
+    #[serde(default)]
+    #[oai(default)]
+    pub args: Vec<String>,
+    #[serde(default)]
+    #[oai(default)]
+    pub env: HashMap<String, String>,
+}
+
+impl WorkerDefaults {
+    /// Returns the arguments and environment variables to create the given worker with,
+    /// replacing the omitted (empty) ones from the request with the resolved defaults
+    pub fn apply(
+        &self,
+        worker_id: &WorkerId,
+        args: Vec<String>,
+        env: HashMap<String, String>,
+    ) -> (Vec<String>, HashMap<String, String>) {
+        let args = if args.is_empty() {
+            self.args
+                .iter()
+                .map(|arg| Self::resolve(arg, worker_id))
+                .collect()
+        } else {
+            args
+        };
+        let env = if env.is_empty() {
+            self.env
+                .iter()
+                .map(|(key, value)| (key.clone(), Self::resolve(value, worker_id)))
+                .collect()
+        } else {
+            env
+        };
+        (args, env)
+    }
+
+    fn resolve(template: &str, worker_id: &WorkerId) -> String {
+        template
+            .replace("{{worker_name}}", &worker_id.worker_name)
+            .replace("{{component_id}}", &worker_id.component_id.to_string())
+    }
+}
+
+impl From<golem_api_grpc::proto::golem::component::WorkerDefaults> for WorkerDefaults {
+    fn from(value: golem_api_grpc::proto::golem::component::WorkerDefaults) -> Self {
+        Self {
+            args: value.args,
+            env: value.env,
+        }
+    }
+}
+
+impl From<WorkerDefaults> for golem_api_grpc::proto::golem::component::WorkerDefaults {
+    fn from(value: WorkerDefaults) -> Self {
+        Self {
+            args: value.args,
+            env: value.env,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
 #[serde(rename_all = "camelCase")]
 #[oai(rename_all = "camelCase")]
@@ -1682,3 +1750,51 @@ impl From<golem_api_grpc::proto::golem::common::ResourceLimits> for ResourceLimi
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use std::collections::HashMap;
+
+    use golem_common::model::{ComponentId, WorkerId};
+
+    use crate::model::WorkerDefaults;
+
+    #[test]
+    fn worker_defaults_are_resolved_for_omitted_values() {
+        let component_id = ComponentId::new_v4();
+        let worker_id = WorkerId {
+            component_id: component_id.clone(),
+            worker_name: "worker-1".to_string(),
+        };
+        let defaults = WorkerDefaults {
+            args: vec!["--name={{worker_name}}".to_string()],
+            env: HashMap::from_iter(vec![(
+                "COMPONENT".to_string(),
+                "{{component_id}}/{{worker_name}}".to_string(),
+            )]),
+        };
+
+        let (args, env) = defaults.apply(&worker_id, vec![], HashMap::new());
+        assert_eq!(args, vec!["--name=worker-1".to_string()]);
+        assert_eq!(
+            env,
+            HashMap::from_iter(vec![(
+                "COMPONENT".to_string(),
+                format!("{component_id}/worker-1")
+            )])
+        );
+
+        let (args, env) = defaults.apply(
+            &worker_id,
+            vec!["explicit".to_string()],
+            HashMap::from_iter(vec![("KEY".to_string(), "value".to_string())]),
+        );
+        assert_eq!(args, vec!["explicit".to_string()]);
+        assert_eq!(
+            env,
+            HashMap::from_iter(vec![("KEY".to_string(), "value".to_string())])
+        );
+    }
+}
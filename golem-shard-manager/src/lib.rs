@@ -15,6 +15,7 @@
 mod error;
 mod healthcheck;
 mod http_server;
+mod metrics;
 mod model;
 mod persistence;
 mod rebalancing;
@@ -72,6 +73,7 @@ impl ShardManagerServiceImpl {
             worker_executor_service,
             health_check.clone(),
             shard_manager_config.rebalance_threshold,
+            shard_manager_config.standby.enabled,
         )
         .await?;
 
@@ -0,0 +1,46 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use lazy_static::lazy_static;
+use prometheus::*;
+
+use crate::model::RoutingTable;
+
+lazy_static! {
+    static ref REGISTERED_POD_COUNT: Gauge = register_gauge!(
+        "shard_manager_registered_pods",
+        "Number of worker executors registered in the routing table"
+    )
+    .unwrap();
+    static ref SHARD_COUNT: GaugeVec = register_gauge_vec!(
+        "shard_manager_shards",
+        "Number of shards in the routing table by assignment state",
+        &["state"]
+    )
+    .unwrap();
+}
+
+/// Records the pod and shard counts of the given routing table
+pub fn record_routing_table(routing_table: &RoutingTable) {
+    let unassigned = routing_table.get_unassigned_shards().len();
+    let assigned = routing_table.number_of_shards - unassigned;
+
+    REGISTERED_POD_COUNT.set(routing_table.get_pod_count() as f64);
+    SHARD_COUNT
+        .with_label_values(&["assigned"])
+        .set(assigned as f64);
+    SHARD_COUNT
+        .with_label_values(&["unassigned"])
+        .set(unassigned as f64);
+}
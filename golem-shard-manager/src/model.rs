@@ -204,6 +204,44 @@ impl RoutingTable {
     pub fn has_pod(&self, pod: &Pod) -> bool {
         self.shard_assignments.contains_key(pod)
     }
+
+    /// Calculates a warm standby pod for each assigned shard. Every pod gets an entry, even if it
+    /// is not a standby for any shard, so that previous standby assignments can be cleared.
+    ///
+    /// The shards of each pod are distributed round-robin among all the _other_ pods, so a
+    /// shard's standby never runs on the pod that owns it, and the standby load stays balanced.
+    pub fn get_standby_assignments(&self) -> Assignments {
+        let pods = self.shard_assignments.keys().cloned().collect::<Vec<_>>();
+        let mut standby = Assignments::new();
+        for pod in &pods {
+            standby.assignments.insert(pod.clone(), BTreeSet::new());
+        }
+
+        if pods.len() > 1 {
+            for (pod, shard_ids) in &self.shard_assignments {
+                let others = pods
+                    .iter()
+                    .filter(|other| *other != pod)
+                    .collect::<Vec<_>>();
+                for (idx, shard_id) in shard_ids.iter().enumerate() {
+                    standby.assign(others[idx % others.len()].clone(), *shard_id);
+                }
+            }
+        }
+
+        standby
+    }
+
+    /// The warm standby pod of each assigned shard, as calculated by `get_standby_assignments`
+    pub fn get_standby_pods(&self) -> BTreeMap<ShardId, Pod> {
+        let mut standby_pods = BTreeMap::new();
+        for (pod, shard_ids) in self.get_standby_assignments().assignments {
+            for shard_id in shard_ids {
+                standby_pods.insert(shard_id, pod.clone());
+            }
+        }
+        standby_pods
+    }
 }
 
 impl From<RoutingTable> for golem::shardmanager::RoutingTable {
@@ -495,3 +533,93 @@ fn shard_ids_to_ranges<'a, T: Iterator<Item = &'a ShardId>>(ids: T) -> Vec<Shard
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Empty {}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use golem_common::model::ShardId;
+    use std::collections::BTreeMap;
+
+    use crate::model::{Pod, RoutingTable};
+
+    fn pod(idx: usize) -> Pod {
+        Pod::new(format!("pod{}", idx), (9000 + idx) as u16)
+    }
+
+    fn routing_table(assignments: Vec<(usize, Vec<i64>)>) -> RoutingTable {
+        let number_of_shards = assignments.iter().map(|(_, ids)| ids.len()).sum();
+        let mut routing_table = RoutingTable::new(number_of_shards);
+        for (idx, shard_ids) in assignments {
+            routing_table
+                .shard_assignments
+                .insert(pod(idx), shard_ids.into_iter().map(ShardId::new).collect());
+        }
+        routing_table
+    }
+
+    fn standby_owners(routing_table: &RoutingTable) -> BTreeMap<ShardId, Vec<Pod>> {
+        let mut result: BTreeMap<ShardId, Vec<Pod>> = BTreeMap::new();
+        for (pod, shard_ids) in routing_table.get_standby_assignments().assignments {
+            for shard_id in shard_ids {
+                result.entry(shard_id).or_default().push(pod.clone());
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn every_shard_has_a_standby_on_another_pod() {
+        let routing_table = routing_table(vec![
+            (0, vec![0, 1, 2, 3]),
+            (1, vec![4, 5]),
+            (2, vec![6, 7, 8]),
+        ]);
+
+        let standby = standby_owners(&routing_table);
+
+        for (owner, shard_ids) in &routing_table.shard_assignments {
+            for shard_id in shard_ids {
+                let standby_pods = standby.get(shard_id).cloned().unwrap_or_default();
+                assert_eq!(standby_pods.len(), 1, "shard {shard_id} has one standby");
+                assert_ne!(&standby_pods[0], owner, "shard {shard_id} fails over");
+            }
+        }
+
+        // Removing any single pod leaves a standby for each of its shards
+        for removed in routing_table.shard_assignments.keys() {
+            for shard_id in &routing_table.shard_assignments[removed] {
+                let standby_pod = &standby[shard_id][0];
+                assert!(routing_table.has_pod(standby_pod));
+                assert_ne!(standby_pod, removed);
+            }
+        }
+    }
+
+    #[test]
+    fn standby_load_is_balanced_per_owner() {
+        let routing_table = routing_table(vec![
+            (0, vec![0, 1, 2, 3, 4, 5]),
+            (1, vec![6, 7, 8]),
+            (2, vec![9, 10, 11]),
+        ]);
+
+        let standby = standby_owners(&routing_table);
+
+        // The shards of pod 0 are split evenly between the two other pods
+        let mut per_pod: BTreeMap<Pod, usize> = BTreeMap::new();
+        for shard_id in &routing_table.shard_assignments[&pod(0)] {
+            *per_pod.entry(standby[shard_id][0].clone()).or_default() += 1;
+        }
+        assert_eq!(per_pod.get(&pod(1)), Some(&3));
+        assert_eq!(per_pod.get(&pod(2)), Some(&3));
+    }
+
+    #[test]
+    fn single_pod_has_no_standby() {
+        let routing_table = routing_table(vec![(0, vec![0, 1, 2])]);
+
+        let standby = routing_table.get_standby_assignments();
+        assert_eq!(standby.assignments.get(&pod(0)).map(|s| s.len()), Some(0));
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::{BTreeSet, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::fmt;
 use std::fmt::{Display, Formatter};
 
@@ -33,6 +33,17 @@ impl Rebalance {
     /// and a threshold of 10%, pods with shard count between 90 and 110 will be considered
     /// balanced.
     pub fn from_routing_table(routing_table: &RoutingTable, threshold: f64) -> Self {
+        Self::from_routing_table_with_standby(routing_table, threshold, &BTreeMap::new())
+    }
+
+    /// Constructs a rebalance plan like `from_routing_table`, but unassigned shards which have a
+    /// warm standby on one of the remaining pods are assigned to that pod first, so the shards of
+    /// a removed pod fail over to their pre-warmed standby.
+    pub fn from_routing_table_with_standby(
+        routing_table: &RoutingTable,
+        threshold: f64,
+        standby_pods: &BTreeMap<ShardId, Pod>,
+    ) -> Self {
         let mut assignments = Assignments::new();
         let mut unassignments = Unassignments::new();
         let pod_count = routing_table.get_pod_count();
@@ -54,8 +65,30 @@ impl Rebalance {
         let upper_threshold = (optimal_count as f64 * (1.0 + threshold)).ceil() as usize;
         let lower_threshold = (optimal_count as f64 * (1.0 - threshold)).floor() as usize;
 
-        // Distributing unassigned shards evenly
-        let unassigned_shards = routing_table.get_unassigned_shards();
+        // Failing over unassigned shards to their standby pods
+        let mut unassigned_shards = routing_table.get_unassigned_shards();
+        unassigned_shards.retain(|shard| {
+            let standby_entry = standby_pods.get(shard).and_then(|standby_pod| {
+                routing_table_entries
+                    .iter_mut()
+                    .find(|entry| &entry.pod == standby_pod)
+            });
+            match standby_entry {
+                Some(routing_table_entry) => {
+                    trace!(
+                        "Assigning shard to its standby pod: {} to {}",
+                        shard,
+                        routing_table_entry.pod
+                    );
+                    assignments.assign(routing_table_entry.pod.clone(), *shard);
+                    routing_table_entry.shard_ids.insert(*shard);
+                    false
+                }
+                None => true,
+            }
+        });
+
+        // Distributing the remaining unassigned shards evenly
         let mut unassigned_shards_iter = unassigned_shards.into_iter();
 
         // First assign to and distribute among empty pods, until all of them reach the optimal count
@@ -77,7 +110,7 @@ impl Rebalance {
                 routing_table_entry.shard_ids.insert(shard);
 
                 // If the last pod is at optimal count, then all pods are at optimal count
-                if idx == last_pod_idx && routing_table_entry.shard_ids.len() == optimal_count {
+                if idx == last_pod_idx && routing_table_entry.shard_ids.len() >= optimal_count {
                     break;
                 }
 
@@ -626,6 +659,49 @@ mod tests {
         );
     }
 
+    #[test]
+    #[traced_test]
+    fn rebalance_removed_pods_fail_over_to_standby() {
+        let mut routing_table = new_routing_table(TestConfig {
+            number_of_shards: 8,
+            number_of_pods: 4,
+            initial_assignments: vec![
+                //
+                (0, vec![0, 1]),
+                (1, vec![2, 3]),
+                (2, vec![4, 5]),
+                (3, vec![6, 7]),
+            ],
+        });
+
+        // Standby pods are 1 for shard 0, 2 for shard 1, 0 for shard 2 and 2 for shard 3
+        let standby_pods = routing_table.get_standby_pods();
+        routing_table.remove_pod(&pod(0));
+        routing_table.remove_pod(&pod(1));
+
+        let rebalance =
+            Rebalance::from_routing_table_with_standby(&routing_table, 0.5, &standby_pods);
+
+        // Shards 1 and 3 fail over to their standby, while the standby of 0 and 2 got removed too
+        assert_assignments(
+            &rebalance,
+            vec![
+                //
+                (2, vec![0, 1, 3]),
+                (3, vec![2]),
+            ],
+        );
+
+        assert_unassignments(
+            &rebalance,
+            vec![
+                //
+                (2, vec![]),
+                (3, vec![]),
+            ],
+        );
+    }
+
     #[test]
     #[traced_test]
     fn two_empty_pods_one_filled() {
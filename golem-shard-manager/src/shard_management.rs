@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::sync::Arc;
 
 use async_rwlock::RwLock;
@@ -23,10 +23,13 @@ use tracing::{debug, info, warn};
 
 use crate::error::ShardManagerError;
 use crate::healthcheck::{get_unhealthy_pods, HealthCheck};
-use crate::model::{Pod, RoutingTable};
+use crate::metrics::record_routing_table;
+use crate::model::{Assignments, Pod, RoutingTable};
 use crate::persistence::PersistenceService;
 use crate::rebalancing::Rebalance;
-use crate::worker_executor::{assign_shards, revoke_shards, WorkerExecutorService};
+use crate::worker_executor::{
+    assign_shards, assign_standby_shards, revoke_shards, WorkerExecutorService,
+};
 
 #[derive(Clone)]
 pub struct ShardManagement {
@@ -45,8 +48,10 @@ impl ShardManagement {
         worker_executors: Arc<dyn WorkerExecutorService + Send + Sync>,
         health_check: Arc<dyn HealthCheck + Send + Sync>,
         threshold: f64,
+        standby_enabled: bool,
     ) -> Result<Self, ShardManagerError> {
         let routing_table = persistence_service.read().await.unwrap();
+        record_routing_table(&routing_table);
 
         info!("Initial healthcheck started");
 
@@ -78,6 +83,7 @@ impl ShardManagement {
                     persistence_service,
                     worker_executors,
                     threshold,
+                    standby_enabled,
                 )
                 .await
             })))
@@ -119,6 +125,7 @@ impl ShardManagement {
         persistence_service: Arc<dyn PersistenceService + Send + Sync>,
         worker_executors: Arc<dyn WorkerExecutorService + Send + Sync>,
         threshold: f64,
+        standby_enabled: bool,
     ) {
         loop {
             debug!("Shard management loop awaiting changes");
@@ -138,6 +145,14 @@ impl ShardManagement {
             let mut rebalance = {
                 let mut current_routing_table = routing_table.write().await;
 
+                // The shards of removed pods are failed over to their standby, calculated before
+                // the pods are removed from the routing table
+                let standby_pods = if standby_enabled {
+                    current_routing_table.get_standby_pods()
+                } else {
+                    BTreeMap::new()
+                };
+
                 for pod in removed_pods {
                     current_routing_table.remove_pod(&pod);
                     info!(pod= %pod, "Pod removed");
@@ -155,8 +170,11 @@ impl ShardManagement {
                         info!(pod= %pod, "Pod added");
                     }
                 }
-                let mut rebalance =
-                    Rebalance::from_routing_table(&current_routing_table, threshold);
+                let mut rebalance = Rebalance::from_routing_table_with_standby(
+                    &current_routing_table,
+                    threshold,
+                    &standby_pods,
+                );
 
                 for pod in send_full_assignment {
                     let assignments = current_routing_table.get_shards(&pod).unwrap_or_default();
@@ -175,10 +193,39 @@ impl ShardManagement {
             Self::execute_rebalance(worker_executors.clone(), &mut rebalance).await;
 
             routing_table.write().await.rebalance(rebalance);
+            let current_routing_table = routing_table.read().await.clone();
+            record_routing_table(&current_routing_table);
             persistence_service
-                .write(&routing_table.read().await.clone())
+                .write(&current_routing_table)
                 .await
                 .expect("Failed to persist routing table after rebalance");
+
+            if standby_enabled {
+                let standby_assignments = routing_table.read().await.get_standby_assignments();
+                Self::execute_standby_assignments(worker_executors.clone(), &standby_assignments)
+                    .await;
+            }
+        }
+    }
+
+    async fn execute_standby_assignments(
+        worker_executors: Arc<dyn WorkerExecutorService + Send + Sync>,
+        standby_assignments: &Assignments,
+    ) {
+        info!(
+            standby_assignments = %standby_assignments,
+            "Executing standby shard assignments",
+        );
+        let failed_standby_assignments =
+            assign_standby_shards(worker_executors, standby_assignments).await;
+        if !failed_standby_assignments.is_empty() {
+            warn!(
+                failed_pods = failed_standby_assignments
+                    .iter()
+                    .map(|(pod, _)| pod)
+                    .join(", "),
+                "Some pods could not be assigned their standby shards"
+            );
         }
     }
 
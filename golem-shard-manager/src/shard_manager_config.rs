@@ -34,6 +34,7 @@ pub struct ShardManagerConfig {
     pub http_port: u16,
     pub number_of_shards: usize,
     pub rebalance_threshold: f64,
+    pub standby: StandbyConfig,
 }
 
 impl Default for ShardManagerConfig {
@@ -46,6 +47,7 @@ impl Default for ShardManagerConfig {
             http_port: 8081,
             number_of_shards: 1024,
             rebalance_threshold: 0.1,
+            standby: StandbyConfig::default(),
         }
     }
 }
@@ -89,6 +91,14 @@ impl Default for WorkerExecutorServiceConfig {
     }
 }
 
+/// When enabled, each assigned shard gets a warm standby worker executor which pre-fetches the
+/// component artifacts (but not the worker state) of the shard's running workers, so a failover
+/// can skip downloading and compiling the components.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct StandbyConfig {
+    pub enabled: bool,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct HealthCheckConfig {
     #[serde(with = "humantime_serde")]
@@ -51,6 +51,12 @@ pub trait WorkerExecutorService {
         pod: &Pod,
         shard_ids: &BTreeSet<ShardId>,
     ) -> Result<(), ShardManagerError>;
+
+    async fn assign_standby_shards(
+        &self,
+        pod: &Pod,
+        shard_ids: &BTreeSet<ShardId>,
+    ) -> Result<(), ShardManagerError>;
 }
 
 /// Sends revoke requests to all worker executors based on an `Unassignments` plan
@@ -103,6 +109,31 @@ pub async fn assign_shards(
         .collect()
 }
 
+/// Sends standby assignments to all worker executors based on an `Assignments` plan
+pub async fn assign_standby_shards(
+    worker_executors: Arc<dyn WorkerExecutorService + Send + Sync>,
+    standby_assignments: &Assignments,
+) -> Vec<(Pod, BTreeSet<ShardId>)> {
+    let futures: Vec<_> = standby_assignments
+        .assignments
+        .iter()
+        .map(|(pod, shard_ids)| {
+            let worker_executors = worker_executors.clone();
+            Box::pin(async move {
+                match worker_executors.assign_standby_shards(pod, shard_ids).await {
+                    Ok(_) => None,
+                    Err(_) => Some((pod.clone(), shard_ids.clone())),
+                }
+            })
+        })
+        .collect();
+    futures::future::join_all(futures)
+        .await
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
 pub struct WorkerExecutorServiceDefault {
     config: WorkerExecutorServiceConfig,
     client: MultiTargetGrpcClient<WorkerExecutorClient<Channel>>,
@@ -152,6 +183,27 @@ impl WorkerExecutorService for WorkerExecutorServiceDefault {
         .await
     }
 
+    async fn assign_standby_shards(
+        &self,
+        pod: &Pod,
+        shard_ids: &BTreeSet<ShardId>,
+    ) -> Result<(), ShardManagerError> {
+        info!(
+            standby_shards = pod_shard_assignments_to_string(pod, shard_ids.iter()),
+            "Assigning standby shards",
+        );
+
+        with_retriable_errors(
+            "worker_executor",
+            "assign_standby_shards",
+            Some(format!("{pod}")),
+            &self.config.retries,
+            &(pod, shard_ids),
+            |(pod, shard_ids)| Box::pin(self.assign_standby_shards_internal(pod, shard_ids)),
+        )
+        .await
+    }
+
     async fn health_check(&self, pod: &Pod) -> Result<(), HealthCheckError> {
         // NOTE: retries are handled in healthcheck.rs
         let endpoint = pod.endpoint();
@@ -236,6 +288,50 @@ impl WorkerExecutorServiceDefault {
         }
     }
 
+    async fn assign_standby_shards_internal(
+        &self,
+        pod: &Pod,
+        shard_ids: &BTreeSet<ShardId>,
+    ) -> Result<(), ShardManagerError> {
+        let assign_standby_shards_request = golem::workerexecutor::v1::AssignStandbyShardsRequest {
+            shard_ids: shard_ids
+                .clone()
+                .into_iter()
+                .map(|shard_id| shard_id.into())
+                .collect(),
+        };
+
+        let assign_standby_shards_response = timeout(
+            self.config.assign_shards_timeout,
+            self.client.call(pod.uri(), move |client| {
+                let assign_standby_shards_request = assign_standby_shards_request.clone();
+                Box::pin(client.assign_standby_shards(assign_standby_shards_request))
+            }),
+        )
+        .await
+        .map_err(|_: Elapsed| ShardManagerError::Timeout)?
+        .map_err(ShardManagerError::GrpcError)?;
+
+        match assign_standby_shards_response.into_inner() {
+            golem::workerexecutor::v1::AssignStandbyShardsResponse {
+                result:
+                    Some(golem::workerexecutor::v1::assign_standby_shards_response::Result::Success(_)),
+            } => Ok(()),
+            golem::workerexecutor::v1::AssignStandbyShardsResponse {
+                result:
+                    Some(golem::workerexecutor::v1::assign_standby_shards_response::Result::Failure(
+                        failure,
+                    )),
+            } => Err(ShardManagerError::WorkerExecutionError(format!(
+                "{:?}",
+                failure
+            ))),
+            golem::workerexecutor::v1::AssignStandbyShardsResponse { result: None } => {
+                Err(ShardManagerError::NoResult)
+            }
+        }
+    }
+
     async fn revoke_shards_internal(
         &self,
         pod: &Pod,
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::components::component_service::{AddComponentError, ComponentService};
+use anyhow::anyhow;
 use async_trait::async_trait;
 use golem_api_grpc::proto::golem::component::v1::component_service_client::ComponentServiceClient;
 use golem_common::model::{ComponentId, ComponentType};
@@ -40,14 +41,14 @@ impl ComponentService for FileSystemComponentService {
         panic!("No real component service running")
     }
 
-    async fn get_or_add_component(
+    async fn try_get_or_add_component(
         &self,
         local_path: &Path,
         component_type: ComponentType,
-    ) -> ComponentId {
+    ) -> crate::Result<ComponentId> {
         self.add_component(local_path, component_type)
             .await
-            .expect("Failed to add component")
+            .map_err(|error| anyhow!("Failed to add component: {error}"))
     }
 
     async fn add_component(
@@ -19,6 +19,7 @@ use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
 
+use anyhow::anyhow;
 use async_trait::async_trait;
 use create_component_request::Data;
 use golem_api_grpc::proto::golem::component::v1::{
@@ -56,6 +57,19 @@ pub trait ComponentService {
         local_path: &Path,
         component_type: ComponentType,
     ) -> ComponentId {
+        self.try_get_or_add_component(local_path, component_type)
+            .await
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Gets the latest version of the component stored with the file name of `local_path`,
+    /// or adds it if there is no such component. Failed gRPC calls are returned as errors
+    /// so they can be retried.
+    async fn try_get_or_add_component(
+        &self,
+        local_path: &Path,
+        component_type: ComponentType,
+    ) -> crate::Result<ComponentId> {
         let mut retries = 5;
         loop {
             let mut file_name: String = local_path
@@ -73,13 +87,14 @@ pub trait ComponentService {
                     project_id: None,
                     component_name: Some(file_name.to_string()),
                 })
-                .await
-                .expect("Failed to call get-components")
+                .await?
                 .into_inner();
 
             match response.result {
                 None => {
-                    panic!("Missing response from golem-component-service for get-components")
+                    break Err(anyhow!(
+                        "Missing response from golem-component-service for get-components"
+                    ))
                 }
                 Some(get_components_response::Result::Success(result)) => {
                     debug!("Response from get_components was {result:?}");
@@ -94,18 +109,19 @@ pub trait ComponentService {
                         {
                             break component
                                 .versioned_component_id
-                                .expect("versioned_component_id field is missing")
-                                .component_id
-                                .expect("component_id field is missing")
+                                .and_then(|id| id.component_id)
+                                .ok_or(anyhow!("component_id field is missing"))?
                                 .try_into()
-                                .expect("component_id has unexpected format")
+                                .map_err(|error| {
+                                    anyhow!("component_id has unexpected format: {error}")
+                                })
                         }
                         _ => {
                             match self
                                 .add_component_with_name(local_path, &file_name, component_type)
                                 .await
                             {
-                                Ok(component_id) => break component_id,
+                                Ok(component_id) => break Ok(component_id),
                                 Err(AddComponentError::AlreadyExists) => {
                                     if retries > 0 {
                                         info!("Component with name {file_name} got created in parallel, retrying get_or_add_component");
@@ -113,20 +129,22 @@ pub trait ComponentService {
                                         sleep(Duration::from_secs(1)).await;
                                         continue;
                                     } else {
-                                        panic!("Component with name {file_name} already exists in golem-component-service");
+                                        break Err(anyhow!("Component with name {file_name} already exists in golem-component-service"));
                                     }
                                 }
                                 Err(AddComponentError::Other(message)) => {
-                                    panic!(
+                                    break Err(anyhow!(
                                         "Failed to add component with name {file_name}: {message}"
-                                    );
+                                    ));
                                 }
                             }
                         }
                     }
                 }
                 Some(get_components_response::Result::Error(error)) => {
-                    panic!("Failed to get components from golem-component-service: {error:?}");
+                    break Err(anyhow!(
+                        "Failed to get components from golem-component-service: {error:?}"
+                    ));
                 }
             }
         }
@@ -22,15 +22,16 @@ use async_trait::async_trait;
 use golem_api_grpc::proto::golem::common::{Empty, ResourceLimits};
 use golem_api_grpc::proto::golem::worker::v1::worker_service_client::WorkerServiceClient;
 use golem_api_grpc::proto::golem::worker::v1::{
-    ConnectWorkerRequest, DeleteWorkerRequest, DeleteWorkerResponse, GetOplogRequest,
-    GetOplogResponse, GetOplogSuccessResponse, GetWorkerMetadataRequest, GetWorkerMetadataResponse,
-    InterruptWorkerRequest, InterruptWorkerResponse, InvokeAndAwaitJsonRequest,
-    InvokeAndAwaitJsonResponse, InvokeAndAwaitRequest, InvokeAndAwaitResponse, InvokeJsonRequest,
-    InvokeRequest, InvokeResponse, LaunchNewWorkerRequest, LaunchNewWorkerResponse,
+    CompletePromiseRequest, CompletePromiseResponse, ConnectWorkerRequest, DeleteWorkerRequest,
+    DeleteWorkerResponse, GetOplogRequest, GetOplogResponse, GetOplogSuccessResponse,
+    GetWorkerMetadataRequest, GetWorkerMetadataResponse, InterruptWorkerRequest,
+    InterruptWorkerResponse, InvokeAndAwaitJsonRequest, InvokeAndAwaitJsonResponse,
+    InvokeAndAwaitRequest, InvokeAndAwaitResponse, InvokeJsonRequest, InvokeRequest,
+    InvokeResponse, LaunchNewWorkerRequest, LaunchNewWorkerResponse,
     LaunchNewWorkerSuccessResponse, ResumeWorkerRequest, ResumeWorkerResponse, UpdateWorkerRequest,
     UpdateWorkerResponse, WorkerError,
 };
-use golem_api_grpc::proto::golem::worker::{InvokeResult, LogEvent, WorkerId};
+use golem_api_grpc::proto::golem::worker::{InvokeResult, LogEvent, PromiseId, WorkerId};
 use golem_api_grpc::proto::golem::workerexecutor::v1::CreateWorkerRequest;
 use golem_api_grpc::proto::golem::{worker, workerexecutor};
 use golem_common::model::AccountId;
@@ -533,6 +534,57 @@ impl WorkerService for ForwardingWorkerService {
         }
     }
 
+    async fn complete_promise(
+        &self,
+        request: CompletePromiseRequest,
+    ) -> crate::Result<CompletePromiseResponse> {
+        let complete_parameters = request
+            .complete_parameters
+            .ok_or(anyhow!("Missing complete parameters"))?;
+
+        let result = self
+            .worker_executor
+            .client()
+            .await?
+            .complete_promise(workerexecutor::v1::CompletePromiseRequest {
+                promise_id: Some(PromiseId {
+                    worker_id: request.worker_id,
+                    oplog_idx: complete_parameters.oplog_idx,
+                }),
+                data: complete_parameters.data,
+                account_id: Some(
+                    AccountId {
+                        value: "test-account".to_string(),
+                    }
+                    .into(),
+                ),
+            })
+            .await?
+            .into_inner();
+
+        match result.result {
+            None => Err(anyhow!(
+                "No response from golem-worker-executor complete-promise call"
+            )),
+            Some(workerexecutor::v1::complete_promise_response::Result::Success(success)) => {
+                Ok(CompletePromiseResponse {
+                    result: Some(worker::v1::complete_promise_response::Result::Success(
+                        success.completed,
+                    )),
+                })
+            }
+            Some(workerexecutor::v1::complete_promise_response::Result::Failure(error)) => {
+                Ok(CompletePromiseResponse {
+                    result: Some(worker::v1::complete_promise_response::Result::Error(
+                        WorkerError {
+                            error: Some(worker::v1::worker_error::Error::InternalError(error)),
+                        },
+                    )),
+                })
+            }
+        }
+    }
+
     fn private_host(&self) -> String {
         panic!("No real golem-worker-service, forwarding requests to worker-executor");
     }
@@ -14,9 +14,10 @@
 
 use async_trait::async_trait;
 use clap::{Parser, Subcommand};
+use golem_common::config::RetryConfig;
 use golem_common::tracing::{init_tracing, TracingConfig};
 use itertools::Itertools;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
@@ -61,8 +62,9 @@ use crate::components::worker_service::k8s::K8sWorkerService;
 use crate::components::worker_service::provided::ProvidedWorkerService;
 use crate::components::worker_service::spawned::SpawnedWorkerService;
 use crate::components::worker_service::WorkerService;
-use crate::config::{TestDependencies, TestService};
+use crate::config::{default_grpc_retry_config, TestDependencies, TestService};
 use crate::dsl::benchmark::{BenchmarkConfig, RunConfig};
+use crate::reporter::ArtifactReporterArgs;
 
 /// Test dependencies created from command line arguments
 ///
@@ -80,6 +82,7 @@ pub struct CliTestDependencies {
     worker_service: Arc<dyn WorkerService + Send + Sync + 'static>,
     worker_executor_cluster: Arc<dyn WorkerExecutorCluster + Send + Sync + 'static>,
     component_directory: PathBuf,
+    grpc_retry_config: RetryConfig,
 }
 
 #[derive(Parser, Debug, Clone)]
@@ -94,6 +97,9 @@ pub struct CliParams {
     #[command(flatten)]
     pub benchmark_config: BenchmarkConfig,
 
+    #[command(flatten)]
+    pub artifact_reporter: ArtifactReporterArgs,
+
     #[arg(long, default_value = "false")]
     pub quiet: bool,
     #[arg(long, default_value = "false")]
@@ -107,6 +113,16 @@ pub struct CliParams {
 
     #[arg(long, default_value = "false")]
     pub keep_containers: bool,
+
+    /// Maximum number of attempts of gRPC calls failing with transient errors
+    #[arg(long, default_value = "1")]
+    pub grpc_max_attempts: u32,
+    /// Minimum delay between gRPC call attempts in milliseconds
+    #[arg(long, default_value = "100")]
+    pub grpc_min_delay_ms: u64,
+    /// Maximum delay between gRPC call attempts in milliseconds
+    #[arg(long, default_value = "2000")]
+    pub grpc_max_delay_ms: u64,
 }
 
 impl CliParams {
@@ -120,6 +136,15 @@ impl CliParams {
         }
     }
 
+    pub fn grpc_retry_config(&self) -> RetryConfig {
+        RetryConfig {
+            max_attempts: self.grpc_max_attempts,
+            min_delay: Duration::from_millis(self.grpc_min_delay_ms),
+            max_delay: Duration::from_millis(self.grpc_max_delay_ms),
+            ..default_grpc_retry_config()
+        }
+    }
+
     pub fn service_verbosity(&self) -> Level {
         if self.verbose {
             Level::DEBUG
@@ -128,6 +153,38 @@ impl CliParams {
         }
     }
 
+    /// Configuration values describing this run in published artifacts
+    pub fn artifact_run_config(&self) -> BTreeMap<String, String> {
+        let mode = match &self.mode {
+            TestMode::Provided { .. } => "provided",
+            TestMode::Docker { .. } => "docker",
+            TestMode::Spawned { .. } => "spawned",
+            TestMode::Minikube { .. } => "minikube",
+            TestMode::Aws { .. } => "aws",
+        };
+
+        BTreeMap::from([
+            ("mode".to_string(), mode.to_string()),
+            (
+                "iterations".to_string(),
+                self.benchmark_config.iterations.to_string(),
+            ),
+            (
+                "cluster-size".to_string(),
+                self.benchmark_config.cluster_size.iter().join(","),
+            ),
+            (
+                "size".to_string(),
+                self.benchmark_config.size.iter().join(","),
+            ),
+            (
+                "length".to_string(),
+                self.benchmark_config.length.iter().join(","),
+            ),
+            ("primary-only".to_string(), self.primary_only.to_string()),
+        ])
+    }
+
     pub fn runs(&self) -> Vec<RunConfig> {
         let cluster_size: Vec<usize> = match self.mode {
             TestMode::Provided { .. } => {
@@ -435,6 +492,7 @@ impl CliTestDependencies {
             worker_service,
             worker_executor_cluster,
             component_directory: Path::new(&params.component_directory).to_path_buf(),
+            grpc_retry_config: params.grpc_retry_config(),
         }
     }
 
@@ -593,6 +651,7 @@ impl CliTestDependencies {
             worker_service,
             worker_executor_cluster,
             component_directory: Path::new(&params.component_directory).to_path_buf(),
+            grpc_retry_config: params.grpc_retry_config(),
         }
     }
 
@@ -725,6 +784,7 @@ impl CliTestDependencies {
             worker_service,
             worker_executor_cluster,
             component_directory: Path::new(&params.component_directory).to_path_buf(),
+            grpc_retry_config: params.grpc_retry_config(),
         }
     }
 
@@ -867,6 +927,7 @@ impl CliTestDependencies {
             worker_service,
             worker_executor_cluster,
             component_directory: Path::new(&params.component_directory).to_path_buf(),
+            grpc_retry_config: params.grpc_retry_config(),
         }
     }
 
@@ -955,6 +1016,7 @@ impl CliTestDependencies {
                     worker_service,
                     worker_executor_cluster,
                     component_directory: Path::new(&params.component_directory).to_path_buf(),
+                    grpc_retry_config: params.grpc_retry_config(),
                 }
             }
             TestMode::Docker {
@@ -1086,6 +1148,10 @@ impl TestDependencies for CliTestDependencies {
     fn worker_executor_cluster(&self) -> Arc<dyn WorkerExecutorCluster + Send + Sync + 'static> {
         self.worker_executor_cluster.clone()
     }
+
+    fn grpc_retry_config(&self) -> RetryConfig {
+        self.grpc_retry_config.clone()
+    }
 }
 
 #[allow(dead_code)]
@@ -37,11 +37,13 @@ use crate::components::worker_executor_cluster::WorkerExecutorCluster;
 use crate::components::worker_service::docker::DockerWorkerService;
 use crate::components::worker_service::spawned::SpawnedWorkerService;
 use crate::components::worker_service::WorkerService;
-use crate::config::{DbType, TestDependencies};
+use crate::config::{default_grpc_retry_config, DbType, TestDependencies};
 use async_trait::async_trait;
+use golem_common::config::RetryConfig;
 use std::fmt::{Debug, Formatter};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 use tracing::Level;
 
 pub struct EnvBasedTestDependenciesConfig {
@@ -56,6 +58,7 @@ pub struct EnvBasedTestDependenciesConfig {
     pub redis_port: u16,
     pub redis_key_prefix: String,
     pub golem_test_components: PathBuf,
+    pub grpc_retries: RetryConfig,
 }
 
 impl EnvBasedTestDependenciesConfig {
@@ -96,6 +99,28 @@ impl EnvBasedTestDependenciesConfig {
             self.golem_test_components = golem_test_components.into();
         }
 
+        if let Some(max_attempts) = opt_env_var("GOLEM_TEST_GRPC_MAX_ATTEMPTS") {
+            self.grpc_retries.max_attempts = max_attempts
+                .parse()
+                .expect("Failed to parse GOLEM_TEST_GRPC_MAX_ATTEMPTS");
+        }
+
+        if let Some(min_delay) = opt_env_var("GOLEM_TEST_GRPC_MIN_DELAY_MS") {
+            self.grpc_retries.min_delay = Duration::from_millis(
+                min_delay
+                    .parse()
+                    .expect("Failed to parse GOLEM_TEST_GRPC_MIN_DELAY_MS"),
+            );
+        }
+
+        if let Some(max_delay) = opt_env_var("GOLEM_TEST_GRPC_MAX_DELAY_MS") {
+            self.grpc_retries.max_delay = Duration::from_millis(
+                max_delay
+                    .parse()
+                    .expect("Failed to parse GOLEM_TEST_GRPC_MAX_DELAY_MS"),
+            );
+        }
+
         self
     }
 
@@ -138,6 +163,7 @@ impl Default for EnvBasedTestDependenciesConfig {
             redis_port: 6379,
             redis_key_prefix: "".to_string(),
             golem_test_components: Path::new("../test-components").to_path_buf(),
+            grpc_retries: default_grpc_retry_config(),
         }
     }
 }
@@ -505,6 +531,10 @@ impl TestDependencies for EnvBasedTestDependencies {
     fn worker_executor_cluster(&self) -> Arc<dyn WorkerExecutorCluster + Send + Sync + 'static> {
         self.worker_executor_cluster.clone()
     }
+
+    fn grpc_retry_config(&self) -> RetryConfig {
+        self.config.grpc_retries.clone()
+    }
 }
 
 fn opt_env_var(name: &str) -> Option<String> {
@@ -17,8 +17,10 @@ use async_trait::async_trait;
 pub use cli::{CliParams, CliTestDependencies, CliTestService};
 pub use env::EnvBasedTestDependencies;
 pub use env::EnvBasedTestDependenciesConfig;
+use golem_common::config::RetryConfig;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::components::component_service::ComponentService;
 use crate::components::rdb::Rdb;
@@ -46,6 +48,20 @@ pub trait TestDependencies {
     fn worker_service(&self) -> Arc<dyn WorkerService + Send + Sync + 'static>;
     fn worker_executor_cluster(&self) -> Arc<dyn WorkerExecutorCluster + Send + Sync + 'static>;
 
+    /// Retry policy of the `TestDsl` gRPC calls failing with transient errors
+    fn grpc_retry_config(&self) -> RetryConfig {
+        default_grpc_retry_config()
+    }
+
+    /// Gets a view of these dependencies which never retries gRPC calls, to be used
+    /// by tests asserting on failing calls
+    fn without_grpc_retries(&self) -> WithoutGrpcRetries<'_, Self>
+    where
+        Self: Sized,
+    {
+        WithoutGrpcRetries(self)
+    }
+
     async fn kill_all(&self) {
         self.worker_executor_cluster().kill_all().await;
         self.worker_service().kill().await;
@@ -58,6 +74,68 @@ pub trait TestDependencies {
     }
 }
 
+/// The default gRPC retry policy, performing a single attempt only
+pub fn default_grpc_retry_config() -> RetryConfig {
+    RetryConfig {
+        max_attempts: 1,
+        min_delay: Duration::from_millis(100),
+        max_delay: Duration::from_secs(2),
+        multiplier: 2.0,
+        max_jitter_factor: Some(0.15),
+    }
+}
+
+/// View of test dependencies performing a single attempt for every `TestDsl` gRPC call
+pub struct WithoutGrpcRetries<'a, Deps>(&'a Deps);
+
+#[async_trait]
+impl<Deps: TestDependencies + Send + Sync> TestDependencies for WithoutGrpcRetries<'_, Deps> {
+    fn rdb(&self) -> Arc<dyn Rdb + Send + Sync + 'static> {
+        self.0.rdb()
+    }
+
+    fn redis(&self) -> Arc<dyn Redis + Send + Sync + 'static> {
+        self.0.redis()
+    }
+
+    fn redis_monitor(&self) -> Arc<dyn RedisMonitor + Send + Sync + 'static> {
+        self.0.redis_monitor()
+    }
+
+    fn shard_manager(&self) -> Arc<dyn ShardManager + Send + Sync + 'static> {
+        self.0.shard_manager()
+    }
+
+    fn component_directory(&self) -> PathBuf {
+        self.0.component_directory()
+    }
+
+    fn component_service(&self) -> Arc<dyn ComponentService + Send + Sync + 'static> {
+        self.0.component_service()
+    }
+
+    fn component_compilation_service(
+        &self,
+    ) -> Arc<dyn ComponentCompilationService + Send + Sync + 'static> {
+        self.0.component_compilation_service()
+    }
+
+    fn worker_service(&self) -> Arc<dyn WorkerService + Send + Sync + 'static> {
+        self.0.worker_service()
+    }
+
+    fn worker_executor_cluster(&self) -> Arc<dyn WorkerExecutorCluster + Send + Sync + 'static> {
+        self.0.worker_executor_cluster()
+    }
+
+    fn grpc_retry_config(&self) -> RetryConfig {
+        RetryConfig {
+            max_attempts: 1,
+            ..self.0.grpc_retry_config()
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum DbType {
     Postgres,
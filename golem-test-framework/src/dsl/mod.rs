@@ -17,38 +17,46 @@ pub mod benchmark;
 use crate::config::TestDependencies;
 use anyhow::anyhow;
 use async_trait::async_trait;
+use golem_api_grpc::proto::golem::shardmanager::v1::{
+    get_routing_table_response, GetRoutingTableRequest,
+};
 use golem_api_grpc::proto::golem::worker::update_record::Update;
 use golem_api_grpc::proto::golem::worker::v1::worker_error::Error;
 use golem_api_grpc::proto::golem::worker::v1::{
-    get_oplog_response, get_worker_metadata_response, get_workers_metadata_response,
-    interrupt_worker_response, invoke_and_await_json_response, invoke_and_await_response,
-    invoke_response, launch_new_worker_response, resume_worker_response, update_worker_response,
-    worker_execution_error, ConnectWorkerRequest, DeleteWorkerRequest, GetOplogRequest,
-    GetWorkerMetadataRequest, GetWorkersMetadataRequest, GetWorkersMetadataSuccessResponse,
-    InterruptWorkerRequest, InterruptWorkerResponse, InvokeAndAwaitJsonRequest,
-    InvokeAndAwaitRequest, InvokeRequest, LaunchNewWorkerRequest, ResumeWorkerRequest,
-    UpdateWorkerRequest, UpdateWorkerResponse, WorkerError, WorkerExecutionError,
+    complete_promise_response, get_oplog_response, get_worker_metadata_response,
+    get_workers_metadata_response, interrupt_worker_response, invoke_and_await_json_response,
+    invoke_and_await_response, invoke_response, launch_new_worker_response, resume_worker_response,
+    update_worker_response, worker_execution_error, CompletePromiseRequest, ConnectWorkerRequest,
+    DeleteWorkerRequest, GetOplogRequest, GetWorkerMetadataRequest, GetWorkersMetadataRequest,
+    GetWorkersMetadataSuccessResponse, InterruptWorkerRequest, InterruptWorkerResponse,
+    InvokeAndAwaitJsonRequest, InvokeAndAwaitRequest, InvokeRequest, LaunchNewWorkerRequest,
+    ResumeWorkerRequest, UpdateWorkerRequest, UpdateWorkerResponse, WorkerError,
+    WorkerExecutionError,
 };
 use golem_api_grpc::proto::golem::worker::{
-    log_event, InvokeParameters, LogEvent, StdErrLog, StdOutLog, UpdateMode,
+    log_event, CompleteParameters, InvokeParameters, LogEvent, StdErrLog, StdOutLog, UpdateMode,
 };
+use golem_common::config::RetryConfig;
 use golem_common::model::oplog::{
     OplogIndex, TimestampedUpdateDescription, UpdateDescription, WorkerResourceId,
 };
 use golem_common::model::public_oplog::PublicOplogEntry;
 use golem_common::model::regions::DeletedRegions;
 use golem_common::model::{
-    ComponentId, ComponentType, ComponentVersion, FailedUpdateRecord, IdempotencyKey, ScanCursor,
-    SuccessfulUpdateRecord, TargetWorkerId, WorkerFilter, WorkerId, WorkerMetadata,
-    WorkerResourceDescription, WorkerStatusRecord,
+    ComponentId, ComponentType, ComponentVersion, FailedUpdateRecord, IdempotencyKey, PromiseId,
+    RoutingTable, ScanCursor, SuccessfulUpdateRecord, TargetWorkerId, WorkerFilter, WorkerId,
+    WorkerMetadata, WorkerResourceDescription, WorkerStatusRecord,
 };
+use golem_common::retries::RetryState;
 use golem_wasm_rpc::Value;
 use std::collections::HashMap;
+use std::future::Future;
 use std::path::Path;
+use std::pin::Pin;
 use tokio::select;
 use tokio::sync::mpsc::UnboundedReceiver;
 use tokio::sync::oneshot::Sender;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 use uuid::Uuid;
 
 #[async_trait]
@@ -170,6 +178,29 @@ pub trait TestDsl {
         worker_id: &WorkerId,
         from: OplogIndex,
     ) -> crate::Result<Vec<PublicOplogEntry>>;
+    /// Gets the current routing table from the shard manager
+    async fn get_routing_table(&self) -> crate::Result<RoutingTable>;
+
+    /// Creates a promise owned by the given worker and returns its id. Promises can only be
+    /// created from within a worker, so its component has to export `golem:it/api.{create-promise}`
+    /// returning the `promise-id` created by `golem:api/host.create-promise`.
+    async fn create_promise(&self, worker_id: &WorkerId) -> crate::Result<PromiseId>;
+    /// Completes the given promise with `payload`, returning whether it was completed by this call
+    async fn complete_promise(
+        &self,
+        promise_id: &PromiseId,
+        payload: Vec<u8>,
+    ) -> crate::Result<bool>;
+    /// Invokes a function that awaits the given promise, completes the promise with `payload`
+    /// while the invocation is in progress and returns the result of the invocation.
+    async fn await_promise_result(
+        &self,
+        worker_id: &WorkerId,
+        function_name: &str,
+        params: Vec<Value>,
+        promise_id: &PromiseId,
+        payload: Vec<u8>,
+    ) -> crate::Result<Result<Vec<Value>, Error>>;
 }
 
 #[async_trait]
@@ -177,10 +208,15 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
     async fn store_component(&self, name: &str) -> ComponentId {
         let source_path = self.component_directory().join(format!("{name}.wasm"));
 
-        let component_id = self
-            .component_service()
-            .get_or_add_component(&source_path, ComponentType::Durable)
-            .await;
+        let component_id = with_grpc_retries(
+            &self.grpc_retry_config(),
+            &(self.component_service(), source_path.clone()),
+            |(component_service, source_path)| {
+                component_service.try_get_or_add_component(source_path, ComponentType::Durable)
+            },
+        )
+        .await
+        .expect("Failed to store component");
 
         let _ = log_and_save_component_metadata(&source_path).await;
 
@@ -190,10 +226,15 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
     async fn store_ephemeral_component(&self, name: &str) -> ComponentId {
         let source_path = self.component_directory().join(format!("{name}.wasm"));
 
-        let component_id = self
-            .component_service()
-            .get_or_add_component(&source_path, ComponentType::Ephemeral)
-            .await;
+        let component_id = with_grpc_retries(
+            &self.grpc_retry_config(),
+            &(self.component_service(), source_path.clone()),
+            |(component_service, source_path)| {
+                component_service.try_get_or_add_component(source_path, ComponentType::Ephemeral)
+            },
+        )
+        .await
+        .expect("Failed to store component");
 
         let _ = log_and_save_component_metadata(&source_path).await;
 
@@ -213,9 +254,15 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
 
     async fn store_component_unverified(&self, name: &str) -> ComponentId {
         let source_path = self.component_directory().join(format!("{name}.wasm"));
-        self.component_service()
-            .get_or_add_component(&source_path, ComponentType::Durable)
-            .await
+        with_grpc_retries(
+            &self.grpc_retry_config(),
+            &(self.component_service(), source_path),
+            |(component_service, source_path)| {
+                component_service.try_get_or_add_component(source_path, ComponentType::Durable)
+            },
+        )
+        .await
+        .expect("Failed to store component")
     }
 
     async fn update_component(&self, component_id: &ComponentId, name: &str) -> ComponentVersion {
@@ -260,6 +307,7 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
         args: Vec<String>,
         env: HashMap<String, String>,
     ) -> crate::Result<Result<WorkerId, Error>> {
+        // Not retried, a retry of a succeeded call would fail with an already exists error
         let response = self
             .worker_service()
             .create_worker(LaunchNewWorkerRequest {
@@ -291,12 +339,17 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
         worker_id: &WorkerId,
     ) -> crate::Result<Option<(WorkerMetadata, Option<String>)>> {
         let worker_id: golem_api_grpc::proto::golem::worker::WorkerId = worker_id.clone().into();
-        let response = self
-            .worker_service()
-            .get_worker_metadata(GetWorkerMetadataRequest {
-                worker_id: Some(worker_id),
-            })
-            .await?;
+        let response = with_grpc_retries(
+            &self.grpc_retry_config(),
+            &(
+                self.worker_service(),
+                GetWorkerMetadataRequest {
+                    worker_id: Some(worker_id),
+                },
+            ),
+            |(worker_service, request)| worker_service.get_worker_metadata(request.clone()),
+        )
+        .await?;
 
         match response.result {
             None => Err(anyhow!("No response from connect_worker")),
@@ -328,16 +381,21 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
     ) -> crate::Result<(Option<ScanCursor>, Vec<(WorkerMetadata, Option<String>)>)> {
         let component_id: golem_api_grpc::proto::golem::component::ComponentId =
             component_id.clone().into();
-        let response = self
-            .worker_service()
-            .get_workers_metadata(GetWorkersMetadataRequest {
-                component_id: Some(component_id),
-                filter: filter.map(|f| f.into()),
-                cursor: Some(cursor.into()),
-                count,
-                precise,
-            })
-            .await?;
+        let response = with_grpc_retries(
+            &self.grpc_retry_config(),
+            &(
+                self.worker_service(),
+                GetWorkersMetadataRequest {
+                    component_id: Some(component_id),
+                    filter: filter.map(|f| f.into()),
+                    cursor: Some(cursor.into()),
+                    count,
+                    precise,
+                },
+            ),
+            |(worker_service, request)| worker_service.get_workers_metadata(request.clone()),
+        )
+        .await?;
         match response.result {
             None => Err(anyhow!("No response from get_workers_metadata")),
             Some(get_workers_metadata_response::Result::Success(
@@ -353,12 +411,17 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
     }
 
     async fn delete_worker(&self, worker_id: &WorkerId) -> crate::Result<()> {
-        let _ = self
-            .worker_service()
-            .delete_worker(DeleteWorkerRequest {
-                worker_id: Some(worker_id.clone().into()),
-            })
-            .await?;
+        let _ = with_grpc_retries(
+            &self.grpc_retry_config(),
+            &(
+                self.worker_service(),
+                DeleteWorkerRequest {
+                    worker_id: Some(worker_id.clone().into()),
+                },
+            ),
+            |(worker_service, request)| worker_service.delete_worker(request.clone()),
+        )
+        .await?;
         Ok(())
     }
 
@@ -369,18 +432,24 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
         params: Vec<Value>,
     ) -> crate::Result<Result<(), Error>> {
         let target_worker_id: TargetWorkerId = worker_id.into();
-        let invoke_response = self
-            .worker_service()
-            .invoke(InvokeRequest {
-                worker_id: Some(target_worker_id.into()),
-                idempotency_key: None,
-                function: function_name.to_string(),
-                invoke_parameters: Some(InvokeParameters {
-                    params: params.into_iter().map(|v| v.into()).collect(),
-                }),
-                context: None,
-            })
-            .await?;
+        let retry_config = self.grpc_retry_config();
+        let invoke_response = with_grpc_retries(
+            &retry_config,
+            &(
+                self.worker_service(),
+                InvokeRequest {
+                    worker_id: Some(target_worker_id.into()),
+                    idempotency_key: retry_idempotency_key(&retry_config),
+                    function: function_name.to_string(),
+                    invoke_parameters: Some(InvokeParameters {
+                        params: params.into_iter().map(|v| v.into()).collect(),
+                    }),
+                    context: None,
+                },
+            ),
+            |(worker_service, request)| worker_service.invoke(request.clone()),
+        )
+        .await?;
 
         match invoke_response.result {
             None => Err(anyhow!("No response from invoke_worker")),
@@ -402,18 +471,23 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
         params: Vec<Value>,
     ) -> crate::Result<Result<(), Error>> {
         let target_worker_id: TargetWorkerId = worker_id.into();
-        let invoke_response = self
-            .worker_service()
-            .invoke(InvokeRequest {
-                worker_id: Some(target_worker_id.into()),
-                idempotency_key: Some(idempotency_key.clone().into()),
-                function: function_name.to_string(),
-                invoke_parameters: Some(InvokeParameters {
-                    params: params.into_iter().map(|v| v.into()).collect(),
-                }),
-                context: None,
-            })
-            .await?;
+        let invoke_response = with_grpc_retries(
+            &self.grpc_retry_config(),
+            &(
+                self.worker_service(),
+                InvokeRequest {
+                    worker_id: Some(target_worker_id.into()),
+                    idempotency_key: Some(idempotency_key.clone().into()),
+                    function: function_name.to_string(),
+                    invoke_parameters: Some(InvokeParameters {
+                        params: params.into_iter().map(|v| v.into()).collect(),
+                    }),
+                    context: None,
+                },
+            ),
+            |(worker_service, request)| worker_service.invoke(request.clone()),
+        )
+        .await?;
 
         match invoke_response.result {
             None => Err(anyhow!("No response from invoke_worker")),
@@ -478,18 +552,23 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
         params: Vec<Value>,
     ) -> crate::Result<Result<Vec<Value>, Error>> {
         let target_worker_id: TargetWorkerId = worker_id.into();
-        let invoke_response = self
-            .worker_service()
-            .invoke_and_await(InvokeAndAwaitRequest {
-                worker_id: Some(target_worker_id.into()),
-                idempotency_key: Some(idempotency_key.clone().into()),
-                function: function_name.to_string(),
-                invoke_parameters: Some(InvokeParameters {
-                    params: params.into_iter().map(|v| v.into()).collect(),
-                }),
-                context: None,
-            })
-            .await?;
+        let invoke_response = with_grpc_retries(
+            &self.grpc_retry_config(),
+            &(
+                self.worker_service(),
+                InvokeAndAwaitRequest {
+                    worker_id: Some(target_worker_id.into()),
+                    idempotency_key: Some(idempotency_key.clone().into()),
+                    function: function_name.to_string(),
+                    invoke_parameters: Some(InvokeParameters {
+                        params: params.into_iter().map(|v| v.into()).collect(),
+                    }),
+                    context: None,
+                },
+            ),
+            |(worker_service, request)| worker_service.invoke_and_await(request.clone()),
+        )
+        .await?;
 
         match invoke_response.result {
             None => Err(anyhow!("No response from invoke_and_await")),
@@ -516,16 +595,22 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
     ) -> crate::Result<Result<serde_json::Value, Error>> {
         let target_worker_id: TargetWorkerId = worker_id.into();
         let params = params.into_iter().map(|p| p.to_string()).collect();
-        let invoke_response = self
-            .worker_service()
-            .invoke_and_await_json(InvokeAndAwaitJsonRequest {
-                worker_id: Some(target_worker_id.into()),
-                idempotency_key: Some(IdempotencyKey::fresh().into()),
-                function: function_name.to_string(),
-                invoke_parameters: params,
-                context: None,
-            })
-            .await?;
+        let retry_config = self.grpc_retry_config();
+        let invoke_response = with_grpc_retries(
+            &retry_config,
+            &(
+                self.worker_service(),
+                InvokeAndAwaitJsonRequest {
+                    worker_id: Some(target_worker_id.into()),
+                    idempotency_key: retry_idempotency_key(&retry_config),
+                    function: function_name.to_string(),
+                    invoke_parameters: params,
+                    context: None,
+                },
+            ),
+            |(worker_service, request)| worker_service.invoke_and_await_json(request.clone()),
+        )
+        .await?;
 
         match invoke_response.result {
             None => Err(anyhow!("No response from invoke_and_await_json")),
@@ -659,12 +744,17 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
     }
 
     async fn resume(&self, worker_id: &WorkerId) -> crate::Result<()> {
-        let response = self
-            .worker_service()
-            .resume_worker(ResumeWorkerRequest {
-                worker_id: Some(worker_id.clone().into()),
-            })
-            .await?;
+        let response = with_grpc_retries(
+            &self.grpc_retry_config(),
+            &(
+                self.worker_service(),
+                ResumeWorkerRequest {
+                    worker_id: Some(worker_id.clone().into()),
+                },
+            ),
+            |(worker_service, request)| worker_service.resume_worker(request.clone()),
+        )
+        .await?;
 
         match response.result {
             None => Err(anyhow!("No response from connect_worker")),
@@ -774,15 +864,20 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
         let mut cursor = None;
 
         loop {
-            let chunk = self
-                .worker_service()
-                .get_oplog(GetOplogRequest {
-                    worker_id: Some(worker_id.clone().into()),
-                    from_oplog_index: from.into(),
-                    cursor: cursor.clone(),
-                    count: 100,
-                })
-                .await?;
+            let chunk = with_grpc_retries(
+                &self.grpc_retry_config(),
+                &(
+                    self.worker_service(),
+                    GetOplogRequest {
+                        worker_id: Some(worker_id.clone().into()),
+                        from_oplog_index: from.into(),
+                        cursor: cursor.clone(),
+                        count: 100,
+                    },
+                ),
+                |(worker_service, request)| worker_service.get_oplog(request.clone()),
+            )
+            .await?;
 
             if let Some(chunk) = chunk.result {
                 match chunk {
@@ -814,6 +909,189 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
 
         Ok(result)
     }
+
+    async fn get_routing_table(&self) -> crate::Result<RoutingTable> {
+        let response = with_grpc_retries(
+            &self.grpc_retry_config(),
+            &self.shard_manager(),
+            |shard_manager| {
+                Box::pin(async move {
+                    Ok(shard_manager
+                        .client()
+                        .await
+                        .get_routing_table(GetRoutingTableRequest {})
+                        .await?
+                        .into_inner())
+                })
+            },
+        )
+        .await?;
+
+        match response.result {
+            None => Err(anyhow!("No response from get_routing_table")),
+            Some(get_routing_table_response::Result::Success(routing_table)) => {
+                Ok(routing_table.into())
+            }
+            Some(get_routing_table_response::Result::Error(error)) => {
+                Err(anyhow!("Failed to get routing table: {error:?}"))
+            }
+        }
+    }
+
+    async fn create_promise(&self, worker_id: &WorkerId) -> crate::Result<PromiseId> {
+        let result =
+            TestDsl::invoke_and_await(self, worker_id.clone(), CREATE_PROMISE_FUNCTION, vec![])
+                .await?
+                .map_err(|error| {
+                    anyhow!("Failed to invoke {CREATE_PROMISE_FUNCTION}: {error:?}")
+                })?;
+
+        match result.as_slice() {
+            [value] => promise_id_from_value(value).ok_or(anyhow!(
+                "{CREATE_PROMISE_FUNCTION} did not return a promise-id, got {value:?}"
+            )),
+            _ => Err(anyhow!(
+                "{CREATE_PROMISE_FUNCTION} did not return a promise-id, got {result:?}"
+            )),
+        }
+    }
+
+    async fn complete_promise(
+        &self,
+        promise_id: &PromiseId,
+        payload: Vec<u8>,
+    ) -> crate::Result<bool> {
+        // Not retried, a retry of a succeeded call would report the promise as already completed
+        let response = self
+            .worker_service()
+            .complete_promise(CompletePromiseRequest {
+                worker_id: Some(promise_id.worker_id.clone().into()),
+                complete_parameters: Some(CompleteParameters {
+                    oplog_idx: promise_id.oplog_idx.into(),
+                    data: payload,
+                }),
+            })
+            .await?;
+
+        match response.result {
+            None => Err(anyhow!("No response from complete_promise")),
+            Some(complete_promise_response::Result::Success(completed)) => Ok(completed),
+            Some(complete_promise_response::Result::Error(error)) => {
+                Err(anyhow!("Failed to complete promise: {error:?}"))
+            }
+        }
+    }
+
+    async fn await_promise_result(
+        &self,
+        worker_id: &WorkerId,
+        function_name: &str,
+        params: Vec<Value>,
+        promise_id: &PromiseId,
+        payload: Vec<u8>,
+    ) -> crate::Result<Result<Vec<Value>, Error>> {
+        // A promise can be completed before it gets awaited, so no need to wait for the
+        // worker to get suspended on it
+        let (result, completed) = tokio::join!(
+            TestDsl::invoke_and_await(self, worker_id.clone(), function_name, params),
+            TestDsl::complete_promise(self, promise_id, payload)
+        );
+
+        if !completed? {
+            return Err(anyhow!("Promise {promise_id} was already completed"));
+        }
+        result
+    }
+}
+
+/// Function used by `create_promise` to create a promise in a worker
+const CREATE_PROMISE_FUNCTION: &str = "golem:it/api.{create-promise}";
+
+/// Converts a `golem:api/host.promise-id` value returned by a worker to a `PromiseId`
+fn promise_id_from_value(value: &Value) -> Option<PromiseId> {
+    match value {
+        Value::Record(fields) => match fields.as_slice() {
+            [Value::Record(worker_id), Value::U64(oplog_idx)] => match worker_id.as_slice() {
+                [Value::Record(component_id), Value::String(worker_name)] => {
+                    match component_id.as_slice() {
+                        [Value::Record(uuid)] => match uuid.as_slice() {
+                            [Value::U64(high_bits), Value::U64(low_bits)] => Some(PromiseId {
+                                worker_id: WorkerId {
+                                    component_id: ComponentId(Uuid::from_u64_pair(
+                                        *high_bits, *low_bits,
+                                    )),
+                                    worker_name: worker_name.clone(),
+                                },
+                                oplog_idx: OplogIndex::from_u64(*oplog_idx),
+                            }),
+                            _ => None,
+                        },
+                        _ => None,
+                    }
+                }
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Performs a gRPC call, retrying it according to `retry_config` as long as it fails
+/// with a transient error
+async fn with_grpc_retries<In, R, F>(
+    retry_config: &RetryConfig,
+    input: &In,
+    action: F,
+) -> crate::Result<R>
+where
+    F: for<'a> Fn(&'a In) -> Pin<Box<dyn Future<Output = crate::Result<R>> + Send + 'a>>,
+{
+    let mut retry_state = RetryState::new(retry_config);
+    loop {
+        retry_state.start_attempt();
+        match action(input).await {
+            Err(error) if is_transient_grpc_error(&error) => {
+                if retry_state.failed_attempt().await {
+                    warn!("Retrying gRPC call after transient error: {error}");
+                } else {
+                    return Err(error);
+                }
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Only failures to reach the service are transient, anything else may have been
+/// processed by the service already
+fn is_transient_grpc_error(error: &anyhow::Error) -> bool {
+    error.chain().any(|cause| {
+        if let Some(status) = cause.downcast_ref::<tonic::Status>() {
+            status.code() == tonic::Code::Unavailable
+        } else if let Some(error) = cause.downcast_ref::<std::io::Error>() {
+            matches!(
+                error.kind(),
+                std::io::ErrorKind::ConnectionRefused
+                    | std::io::ErrorKind::ConnectionReset
+                    | std::io::ErrorKind::ConnectionAborted
+                    | std::io::ErrorKind::NotConnected
+            )
+        } else {
+            false
+        }
+    })
+}
+
+/// A fresh idempotency key making the retries of an invocation safe, if it can be retried at all
+fn retry_idempotency_key(
+    retry_config: &RetryConfig,
+) -> Option<golem_api_grpc::proto::golem::worker::IdempotencyKey> {
+    if retry_config.max_attempts > 1 {
+        Some(IdempotencyKey::fresh().into())
+    } else {
+        None
+    }
 }
 
 pub fn stdout_events(events: impl Iterator<Item = LogEvent>) -> Vec<String> {
@@ -1245,6 +1523,17 @@ pub trait TestDslUnsafe {
     async fn auto_update_worker(&self, worker_id: &WorkerId, target_version: ComponentVersion);
     async fn manual_update_worker(&self, worker_id: &WorkerId, target_version: ComponentVersion);
     async fn get_oplog(&self, worker_id: &WorkerId, from: OplogIndex) -> Vec<PublicOplogEntry>;
+    async fn get_routing_table(&self) -> RoutingTable;
+    async fn create_promise(&self, worker_id: &WorkerId) -> PromiseId;
+    async fn complete_promise(&self, promise_id: &PromiseId, payload: Vec<u8>) -> bool;
+    async fn await_promise_result(
+        &self,
+        worker_id: &WorkerId,
+        function_name: &str,
+        params: Vec<Value>,
+        promise_id: &PromiseId,
+        payload: Vec<u8>,
+    ) -> Result<Vec<Value>, Error>;
 }
 
 #[async_trait]
@@ -1457,4 +1746,134 @@ impl<T: TestDsl + Sync> TestDslUnsafe for T {
             .await
             .expect("Failed to get oplog")
     }
+
+    async fn get_routing_table(&self) -> RoutingTable {
+        <T as TestDsl>::get_routing_table(self)
+            .await
+            .expect("Failed to get routing table")
+    }
+
+    async fn create_promise(&self, worker_id: &WorkerId) -> PromiseId {
+        <T as TestDsl>::create_promise(self, worker_id)
+            .await
+            .expect("Failed to create promise")
+    }
+
+    async fn complete_promise(&self, promise_id: &PromiseId, payload: Vec<u8>) -> bool {
+        <T as TestDsl>::complete_promise(self, promise_id, payload)
+            .await
+            .expect("Failed to complete promise")
+    }
+
+    async fn await_promise_result(
+        &self,
+        worker_id: &WorkerId,
+        function_name: &str,
+        params: Vec<Value>,
+        promise_id: &PromiseId,
+        payload: Vec<u8>,
+    ) -> Result<Vec<Value>, Error> {
+        <T as TestDsl>::await_promise_result(
+            self,
+            worker_id,
+            function_name,
+            params,
+            promise_id,
+            payload,
+        )
+        .await
+        .expect("Failed to await promise result")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::config::default_grpc_retry_config;
+    use crate::dsl::{retry_idempotency_key, with_grpc_retries};
+    use golem_common::config::RetryConfig;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::Duration;
+
+    fn retry_config(max_attempts: u32) -> RetryConfig {
+        RetryConfig {
+            max_attempts,
+            min_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(1),
+            ..default_grpc_retry_config()
+        }
+    }
+
+    async fn failing_call(attempts: &AtomicU32, status: tonic::Status) -> crate::Result<u32> {
+        with_grpc_retries(&retry_config(3), attempts, |attempts| {
+            let status = status.clone();
+            Box::pin(async move {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(status.into())
+            })
+        })
+        .await
+    }
+
+    #[test]
+    async fn transient_errors_are_retried() {
+        let attempts = AtomicU32::new(0);
+        let result = failing_call(&attempts, tonic::Status::unavailable("starting up")).await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    async fn other_errors_are_not_retried() {
+        let attempts = AtomicU32::new(0);
+        let result = failing_call(&attempts, tonic::Status::internal("failure")).await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    async fn connection_errors_are_retried() {
+        let attempts = AtomicU32::new(0);
+        let result: crate::Result<u32> =
+            with_grpc_retries(&retry_config(3), &attempts, |attempts| {
+                Box::pin(async move {
+                    attempts.fetch_add(1, Ordering::SeqCst);
+                    Err(anyhow::Error::new(std::io::Error::from(
+                        std::io::ErrorKind::ConnectionRefused,
+                    ))
+                    .context("Failed to connect to golem-worker-service"))
+                })
+            })
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn idempotency_key_is_only_sent_when_retrying() {
+        assert!(retry_idempotency_key(&retry_config(1)).is_none());
+        assert!(retry_idempotency_key(&retry_config(3)).is_some());
+    }
+
+    #[test]
+    async fn retries_stop_on_success() {
+        let attempts = AtomicU32::new(0);
+        let result = with_grpc_retries(&retry_config(5), &attempts, |attempts| {
+            Box::pin(async move {
+                if attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+                    Err(tonic::Status::unavailable("starting up").into())
+                } else {
+                    Ok(42)
+                }
+            })
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
 }
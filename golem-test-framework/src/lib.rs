@@ -21,6 +21,9 @@ pub mod config;
 /// Higher level DSL for expressing common steps of tests and benchmarks
 pub mod dsl;
 
+/// Publishing of benchmark results and failed test artifacts to object storage
+pub mod reporter;
+
 pub type Result<T> = anyhow::Result<T>;
 
 #[cfg(test)]
@@ -0,0 +1,196 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::dsl::{log_event_to_string, TestDsl};
+use crate::reporter::s3::S3ArtifactReporter;
+use crate::reporter::ArtifactReporter;
+use futures::FutureExt;
+use golem_api_grpc::proto::golem::worker::LogEvent;
+use golem_common::model::oplog::OplogIndex;
+use golem_common::model::public_oplog::PublicOplogEntry;
+use golem_common::model::WorkerId;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::sync::Mutex;
+use tokio::sync::mpsc::UnboundedReceiver;
+use tokio::sync::OnceCell;
+use tracing::warn;
+
+/// Artifacts collected for a failed test
+#[derive(Debug, Clone)]
+pub struct FailedTestArtifacts {
+    pub test_name: String,
+    pub logs: Vec<String>,
+    pub oplogs: Vec<(WorkerId, Vec<PublicOplogEntry>)>,
+}
+
+impl FailedTestArtifacts {
+    /// Collects the full oplog of each of the given workers. Workers whose oplog cannot
+    /// be retrieved are skipped, as the failure itself may have been caused by the
+    /// environment being unavailable.
+    pub async fn collect(
+        deps: &impl TestDsl,
+        test_name: impl AsRef<str>,
+        worker_ids: &[WorkerId],
+        logs: Vec<String>,
+    ) -> Self {
+        let mut oplogs = Vec::new();
+        for worker_id in worker_ids {
+            match deps.get_oplog(worker_id, OplogIndex::INITIAL).await {
+                Ok(oplog) => oplogs.push((worker_id.clone(), oplog)),
+                Err(err) => warn!("Failed to get the oplog of {worker_id} for the report: {err}"),
+            }
+        }
+
+        Self {
+            test_name: test_name.as_ref().to_string(),
+            logs,
+            oplogs,
+        }
+    }
+}
+
+/// Tracks the workers of a test, capturing their output so it can be published together
+/// with their oplogs if the test fails
+pub struct TestArtifactCollector {
+    test_name: String,
+    workers: Mutex<Vec<(WorkerId, UnboundedReceiver<LogEvent>)>>,
+}
+
+impl TestArtifactCollector {
+    pub fn new(test_name: impl AsRef<str>) -> Self {
+        Self {
+            test_name: test_name.as_ref().to_string(),
+            workers: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Starts capturing the output of the given worker and includes its oplog in the artifacts
+    pub async fn track_worker(&self, deps: &impl TestDsl, worker_id: &WorkerId) {
+        let output = deps.capture_output(worker_id).await;
+        self.workers
+            .lock()
+            .unwrap()
+            .push((worker_id.clone(), output));
+    }
+
+    /// Drains the output captured so far and gets the oplog of every tracked worker
+    pub async fn collect(&self, deps: &impl TestDsl) -> FailedTestArtifacts {
+        let mut worker_ids = Vec::new();
+        let mut logs = Vec::new();
+        for (worker_id, output) in self.workers.lock().unwrap().iter_mut() {
+            while let Ok(event) = output.try_recv() {
+                logs.extend(log_line(worker_id, &event));
+            }
+            worker_ids.push(worker_id.clone());
+        }
+
+        FailedTestArtifacts::collect(deps, &self.test_name, &worker_ids, logs).await
+    }
+
+    /// Runs the given test, publishing the artifacts of the tracked workers if it panics.
+    ///
+    /// Nothing is published unless the reporter is enabled in the environment, see
+    /// [`crate::reporter::ArtifactReporterConfig::from_env`].
+    pub async fn publish_on_failure<R>(
+        &self,
+        deps: &(impl TestDsl + Sync),
+        test: impl Future<Output = R>,
+    ) -> R {
+        match AssertUnwindSafe(test).catch_unwind().await {
+            Ok(result) => result,
+            Err(panic) => {
+                if let Some(reporter) = failed_test_reporter().await {
+                    let artifacts = self.collect(deps).await;
+                    if let Err(err) = reporter.publish_failed_test(&artifacts).await {
+                        warn!(
+                            "Failed to publish the artifacts of failed test {}: {err}",
+                            self.test_name
+                        );
+                    }
+                }
+                std::panic::resume_unwind(panic)
+            }
+        }
+    }
+}
+
+fn log_line(worker_id: &WorkerId, event: &LogEvent) -> Option<String> {
+    // Only output events have a message, invocation start and end markers are skipped
+    let message = event.event.as_ref().map(|_| log_event_to_string(event))?;
+    if message.is_empty() {
+        None
+    } else {
+        Some(format!("[{worker_id}] {}", message.trim_end()))
+    }
+}
+
+static FAILED_TEST_REPORTER: OnceCell<Option<S3ArtifactReporter>> = OnceCell::const_new();
+
+// All failed tests of a test binary are published as part of the same run, with its
+// metadata published once
+async fn failed_test_reporter() -> Option<&'static S3ArtifactReporter> {
+    FAILED_TEST_REPORTER
+        .get_or_init(|| async {
+            let test_binary = std::env::current_exe()
+                .ok()
+                .and_then(|path| {
+                    path.file_stem()
+                        .map(|stem| stem.to_string_lossy().to_string())
+                })
+                .unwrap_or_default();
+            let reporter = S3ArtifactReporter::from_env(BTreeMap::from([(
+                "test_binary".to_string(),
+                test_binary,
+            )]))
+            .await?;
+            if let Err(err) = reporter.publish_metadata().await {
+                warn!("Failed to publish the test run metadata: {err}");
+            }
+            Some(reporter)
+        })
+        .await
+        .as_ref()
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::reporter::failed_test::log_line;
+    use golem_api_grpc::proto::golem::worker::{log_event, LogEvent, StdOutLog};
+    use golem_common::model::{ComponentId, WorkerId};
+    use uuid::Uuid;
+
+    #[test]
+    fn log_lines_are_prefixed_with_the_worker() {
+        let worker_id = WorkerId {
+            component_id: ComponentId(Uuid::nil()),
+            worker_name: "worker-1".to_string(),
+        };
+        let event = LogEvent {
+            event: Some(log_event::Event::Stdout(StdOutLog {
+                message: "hello\n".to_string(),
+                ..Default::default()
+            })),
+        };
+
+        assert_eq!(
+            log_line(&worker_id, &event),
+            Some(format!("[{worker_id}] hello"))
+        );
+        assert_eq!(log_line(&worker_id, &LogEvent { event: None }), None);
+    }
+}
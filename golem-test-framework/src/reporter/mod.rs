@@ -0,0 +1,293 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod failed_test;
+pub mod s3;
+
+use crate::dsl::benchmark::BenchmarkResult;
+use crate::reporter::failed_test::FailedTestArtifacts;
+use async_trait::async_trait;
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tracing::info;
+use uuid::Uuid;
+
+/// Command line arguments enabling the artifact reporter.
+///
+/// Any argument not given falls back to the corresponding `GOLEM_TEST_ARTIFACT_*`
+/// environment variable, see [`ArtifactReporterConfig::from_env`].
+#[derive(Debug, Clone, Args)]
+pub struct ArtifactReporterArgs {
+    /// Bucket to upload benchmark results and failed test artifacts to. Nothing is
+    /// published if neither this nor GOLEM_TEST_ARTIFACT_BUCKET is set.
+    #[arg(long)]
+    pub artifact_bucket: Option<String>,
+
+    /// Prefix of all uploaded object keys
+    #[arg(long)]
+    pub artifact_prefix: Option<String>,
+
+    /// Region of the bucket
+    #[arg(long)]
+    pub artifact_region: Option<String>,
+
+    /// Endpoint of an S3-compatible object storage, if not AWS S3
+    #[arg(long)]
+    pub artifact_endpoint_url: Option<String>,
+
+    /// Identifier of the run, grouping all of its artifacts. Generated if not set.
+    #[arg(long)]
+    pub artifact_run_id: Option<String>,
+}
+
+impl ArtifactReporterArgs {
+    pub fn config(&self) -> Option<ArtifactReporterConfig> {
+        let env = ArtifactReporterConfig::from_env();
+
+        let bucket = self
+            .artifact_bucket
+            .clone()
+            .or_else(|| env.as_ref().map(|env| env.bucket.clone()))?;
+        let env = env.unwrap_or_else(|| ArtifactReporterConfig::new(bucket.clone()));
+
+        Some(ArtifactReporterConfig {
+            bucket,
+            prefix: self.artifact_prefix.clone().unwrap_or(env.prefix),
+            region: self.artifact_region.clone().unwrap_or(env.region),
+            endpoint_url: self.artifact_endpoint_url.clone().or(env.endpoint_url),
+            run_id: self.artifact_run_id.clone().unwrap_or(env.run_id),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArtifactReporterConfig {
+    pub bucket: String,
+    pub prefix: String,
+    pub region: String,
+    pub endpoint_url: Option<String>,
+    pub run_id: String,
+}
+
+impl ArtifactReporterConfig {
+    pub fn new(bucket: impl AsRef<str>) -> Self {
+        Self {
+            bucket: bucket.as_ref().to_string(),
+            prefix: "".to_string(),
+            region: "us-east-1".to_string(),
+            endpoint_url: None,
+            run_id: Uuid::new_v4().to_string(),
+        }
+    }
+
+    /// Reads the reporter configuration from the environment, returning `None` if
+    /// `GOLEM_TEST_ARTIFACT_BUCKET` is not set
+    pub fn from_env() -> Option<Self> {
+        let mut config = Self::new(std::env::var("GOLEM_TEST_ARTIFACT_BUCKET").ok()?);
+
+        if let Ok(prefix) = std::env::var("GOLEM_TEST_ARTIFACT_PREFIX") {
+            config.prefix = prefix;
+        }
+
+        if let Ok(region) = std::env::var("GOLEM_TEST_ARTIFACT_REGION") {
+            config.region = region;
+        }
+
+        if let Ok(endpoint_url) = std::env::var("GOLEM_TEST_ARTIFACT_ENDPOINT_URL") {
+            config.endpoint_url = Some(endpoint_url);
+        }
+
+        if let Ok(run_id) = std::env::var("GOLEM_TEST_RUN_ID") {
+            config.run_id = run_id;
+        }
+
+        Some(config)
+    }
+
+    /// Key of an artifact of this run, relative to the bucket
+    pub fn key(&self, path: &str) -> String {
+        let prefix = self.prefix.trim_matches('/');
+        if prefix.is_empty() {
+            format!("{}/{}", self.run_id, path)
+        } else {
+            format!("{}/{}/{}", prefix, self.run_id, path)
+        }
+    }
+}
+
+/// Metadata of a test or benchmark run, published once per run next to its artifacts
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunMetadata {
+    pub run_id: String,
+    pub git_sha: Option<String>,
+    pub started_at: chrono::DateTime<chrono::Utc>,
+    pub config: BTreeMap<String, String>,
+}
+
+impl RunMetadata {
+    pub fn new(run_id: impl AsRef<str>, config: BTreeMap<String, String>) -> Self {
+        Self {
+            run_id: run_id.as_ref().to_string(),
+            git_sha: current_git_sha(),
+            started_at: chrono::Utc::now(),
+            config,
+        }
+    }
+}
+
+/// Publishes benchmark results and artifacts of failed tests, so that runs on
+/// different machines can be compared later
+#[async_trait]
+pub trait ArtifactReporter {
+    fn config(&self) -> &ArtifactReporterConfig;
+
+    fn metadata(&self) -> &RunMetadata;
+
+    /// Uploads a single artifact to the given path within the run
+    async fn publish(&self, path: &str, content_type: &str, data: Vec<u8>) -> crate::Result<()>;
+
+    async fn publish_metadata(&self) -> crate::Result<()> {
+        let data = serde_json::to_vec_pretty(self.metadata())?;
+        self.publish("metadata.json", JSON_CONTENT_TYPE, data).await
+    }
+
+    async fn publish_benchmark_result(
+        &self,
+        benchmark_name: &str,
+        result: &BenchmarkResult,
+    ) -> crate::Result<()> {
+        let data = serde_json::to_vec(result)?;
+        let path = format!("benchmarks/{}.json", sanitize_path_segment(benchmark_name));
+        self.publish(&path, JSON_CONTENT_TYPE, data).await
+    }
+
+    async fn publish_failed_test(&self, artifacts: &FailedTestArtifacts) -> crate::Result<()> {
+        let root = format!(
+            "failed-tests/{}",
+            sanitize_path_segment(&artifacts.test_name)
+        );
+
+        let logs = artifacts.logs.join("\n").into_bytes();
+        self.publish(&format!("{root}/logs.txt"), TEXT_CONTENT_TYPE, logs)
+            .await?;
+
+        for (worker_id, oplog) in &artifacts.oplogs {
+            let path = format!(
+                "{root}/oplogs/{}/{}.json",
+                worker_id.component_id,
+                sanitize_path_segment(&worker_id.worker_name)
+            );
+            let data = serde_json::to_vec_pretty(oplog)?;
+            self.publish(&path, JSON_CONTENT_TYPE, data).await?;
+        }
+
+        info!(
+            "Published artifacts of failed test {} to {}",
+            artifacts.test_name,
+            self.config().key(&root)
+        );
+        Ok(())
+    }
+}
+
+const JSON_CONTENT_TYPE: &str = "application/json";
+const TEXT_CONTENT_TYPE: &str = "text/plain";
+
+// Test, benchmark and worker names may contain characters which are not safe in object keys
+fn sanitize_path_segment(segment: &str) -> String {
+    segment
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+// CI environments usually provide the commit being built, otherwise falling back to
+// asking git directly
+fn current_git_sha() -> Option<String> {
+    std::env::var("GOLEM_TEST_GIT_SHA")
+        .or_else(|_| std::env::var("GITHUB_SHA"))
+        .ok()
+        .or_else(|| {
+            std::process::Command::new("git")
+                .args(["rev-parse", "HEAD"])
+                .output()
+                .ok()
+                .filter(|output| output.status.success())
+                .and_then(|output| String::from_utf8(output.stdout).ok())
+                .map(|sha| sha.trim().to_string())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::reporter::{sanitize_path_segment, ArtifactReporterConfig, RunMetadata};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn artifact_keys_are_grouped_by_run() {
+        let mut config = ArtifactReporterConfig::new("bucket");
+        config.run_id = "run-1".to_string();
+
+        assert_eq!(config.key("metadata.json"), "run-1/metadata.json");
+
+        config.prefix = "/golem/ci/".to_string();
+        assert_eq!(
+            config.key("benchmarks/latency.json"),
+            "golem/ci/run-1/benchmarks/latency.json"
+        );
+    }
+
+    #[test]
+    fn path_segments_are_sanitized() {
+        assert_eq!(
+            sanitize_path_segment("cold start (cluster size 3)"),
+            "cold_start__cluster_size_3_"
+        );
+        assert_eq!(
+            sanitize_path_segment("worker::tests::interrupt (retry 2)"),
+            "worker__tests__interrupt__retry_2_"
+        );
+        assert_eq!(sanitize_path_segment("worker-1"), "worker-1");
+    }
+
+    #[test]
+    fn run_metadata_is_serializable_to_json() {
+        let metadata = RunMetadata {
+            run_id: "run-1".to_string(),
+            git_sha: Some("0123abcd".to_string()),
+            started_at: chrono::DateTime::parse_from_rfc3339("2024-08-21T07:42:15Z")
+                .unwrap()
+                .into(),
+            config: BTreeMap::from([("iterations".to_string(), "3".to_string())]),
+        };
+
+        let json = serde_json::to_string(&metadata).unwrap();
+        assert_eq!(
+            json,
+            r#"{"run_id":"run-1","git_sha":"0123abcd","started_at":"2024-08-21T07:42:15Z","config":{"iterations":"3"}}"#
+        );
+
+        let deserialized = serde_json::from_str::<RunMetadata>(&json).unwrap();
+        assert_eq!(deserialized, metadata);
+    }
+}
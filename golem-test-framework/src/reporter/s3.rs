@@ -0,0 +1,92 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::reporter::{ArtifactReporter, ArtifactReporterConfig, RunMetadata};
+use async_trait::async_trait;
+use aws_sdk_s3::config::{BehaviorVersion, Region};
+use aws_sdk_s3::primitives::ByteStream;
+use std::collections::BTreeMap;
+use tracing::{debug, info};
+
+/// Publishes artifacts to an S3-compatible bucket, using the default AWS credential chain
+pub struct S3ArtifactReporter {
+    client: aws_sdk_s3::Client,
+    config: ArtifactReporterConfig,
+    metadata: RunMetadata,
+}
+
+impl S3ArtifactReporter {
+    pub async fn new(config: ArtifactReporterConfig, metadata: RunMetadata) -> Self {
+        info!(
+            "Publishing test artifacts of run {} to bucket {}",
+            config.run_id, config.bucket
+        );
+
+        let mut config_builder = aws_config::defaults(BehaviorVersion::v2024_03_28())
+            .region(Region::new(config.region.clone()));
+
+        if let Some(endpoint_url) = &config.endpoint_url {
+            config_builder = config_builder.endpoint_url(endpoint_url);
+        }
+
+        let sdk_config = config_builder.load().await;
+
+        // S3-compatible storages such as MinIO usually don't support virtual-hosted buckets
+        let s3_config = aws_sdk_s3::config::Builder::from(&sdk_config)
+            .force_path_style(config.endpoint_url.is_some())
+            .build();
+
+        Self {
+            client: aws_sdk_s3::Client::from_conf(s3_config),
+            config,
+            metadata,
+        }
+    }
+
+    /// Creates a reporter configured from the environment, if enabled, describing the
+    /// run with the given configuration values
+    pub async fn from_env(run_config: BTreeMap<String, String>) -> Option<Self> {
+        let config = ArtifactReporterConfig::from_env()?;
+        let metadata = RunMetadata::new(&config.run_id, run_config);
+        Some(Self::new(config, metadata).await)
+    }
+}
+
+#[async_trait]
+impl ArtifactReporter for S3ArtifactReporter {
+    fn config(&self) -> &ArtifactReporterConfig {
+        &self.config
+    }
+
+    fn metadata(&self) -> &RunMetadata {
+        &self.metadata
+    }
+
+    async fn publish(&self, path: &str, content_type: &str, data: Vec<u8>) -> crate::Result<()> {
+        let key = self.config.key(path);
+
+        debug!("Publishing artifact {}/{}", self.config.bucket, key);
+
+        self.client
+            .put_object()
+            .bucket(&self.config.bucket)
+            .key(key)
+            .content_type(content_type)
+            .body(ByteStream::from(data))
+            .send()
+            .await?;
+
+        Ok(())
+    }
+}
@@ -7,11 +7,49 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let out_dir = var_os("OUT_DIR").unwrap();
     let target_file = Path::new(&out_dir).join("preview2_mod.rs");
 
-    std::fs::write(target_file.clone(), preview2_mod_gen(&golem_wit_root)).unwrap();
+    // The WIT packages of this crate are added as dependencies next to the ones of golem-wit
+    let wit_root = Path::new(&out_dir).join("wit");
+    if wit_root.exists() {
+        std::fs::remove_dir_all(&wit_root)?;
+    }
+    copy_dir(&Path::new(&golem_wit_root).join("wit"), &wit_root)?;
+    for entry in std::fs::read_dir(Path::new("wit").join("deps"))? {
+        let entry = entry?;
+        let target = wit_root.join("deps").join(entry.file_name());
+        if target.exists() {
+            return Err(format!(
+                "WIT package {:?} is already defined by golem-wit",
+                entry.path()
+            )
+            .into());
+        }
+        copy_dir(&entry.path(), &target)?;
+    }
+
+    std::fs::write(
+        target_file.clone(),
+        preview2_mod_gen(wit_root.to_str().unwrap()),
+    )
+    .unwrap();
 
     println!("cargo:rerun-if-changed=build.rs");
     println!("cargo:rerun-if-changed=Cargo.toml");
+    println!("cargo:rerun-if-changed=wit");
+
+    Ok(())
+}
 
+fn copy_dir(source: &Path, target: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(target)?;
+    for entry in std::fs::read_dir(source)? {
+        let entry = entry?;
+        let target = target.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else if entry.path().extension().is_some_and(|ext| ext == "wit") {
+            std::fs::copy(entry.path(), target)?;
+        }
+    }
     Ok(())
 }
 
@@ -50,14 +88,16 @@ fn find_package_root(name: &str) -> String {
     package.manifest_path.parent().unwrap().to_string()
 }
 
-fn preview2_mod_gen(golem_wit_path: &str) -> String {
+fn preview2_mod_gen(wit_path: &str) -> String {
     format!(
         r#"wasmtime::component::bindgen!({{
-        path: "{golem_wit_path}/wit",
+        path: "{wit_path}",
         interfaces: "
           import golem:api/host@0.2.0;
           import golem:api/host@1.1.0-rc1;
           import golem:api/oplog@1.1.0-rc1;
+          import golem:api/oplog@1.1.0-rc2;
+          import golem:api/cancellation@1.1.0-rc2;
 
           import wasi:blobstore/blobstore;
           import wasi:blobstore/container;
@@ -0,0 +1,50 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+
+use crate::durable_host::serialized::SerializableError;
+use crate::durable_host::{Durability, DurableWorkerCtx};
+use crate::metrics::wasm::record_host_function_call;
+use crate::preview2::golem::api1_1_0_rc2::cancellation::Host;
+use crate::services::HasWorker;
+use crate::workerctx::WorkerCtx;
+use golem_common::model::oplog::WrappedFunctionType;
+
+#[async_trait]
+impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
+    async fn is_cancelled(&mut self) -> anyhow::Result<bool> {
+        let _permit = self.begin_async_host_function().await?;
+        record_host_function_call("golem::api", "is_cancelled");
+        Durability::<Ctx, (), bool, SerializableError>::wrap(
+            self,
+            WrappedFunctionType::ReadLocal,
+            "golem api::is_cancelled",
+            (),
+            |ctx| {
+                Box::pin(
+                    async move { Ok(ctx.public_state.worker().acknowledge_cancellation().await) },
+                )
+            },
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl<Ctx: WorkerCtx> Host for &mut DurableWorkerCtx<Ctx> {
+    async fn is_cancelled(&mut self) -> anyhow::Result<bool> {
+        (*self).is_cancelled().await
+    }
+}
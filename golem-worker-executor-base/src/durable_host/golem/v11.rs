@@ -31,6 +31,7 @@ use crate::workerctx::WorkerCtx;
 use anyhow::anyhow;
 use async_trait::async_trait;
 use golem_common::config::RetryConfig;
+use golem_common::model::public_oplog::PublicOplogEntry;
 use golem_common::model::OwnedWorkerId;
 use std::time::Duration;
 use wasmtime::component::Resource;
@@ -180,12 +181,11 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
     }
 }
 
-#[async_trait]
-impl<Ctx: WorkerCtx> HostGetOplog for DurableWorkerCtx<Ctx> {
-    async fn new(
+impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
+    async fn new_get_oplog(
         &mut self,
-        worker_id: crate::preview2::golem::api1_1_0_rc1::oplog::WorkerId,
-        start: crate::preview2::golem::api1_1_0_rc1::oplog::OplogIndex,
+        worker_id: WorkerId,
+        start: OplogIndex,
     ) -> anyhow::Result<Resource<GetOplogEntry>> {
         let _permit = self.begin_async_host_function().await?;
         record_host_function_call("golem::api::get-oplog", "new");
@@ -203,17 +203,17 @@ impl<Ctx: WorkerCtx> HostGetOplog for DurableWorkerCtx<Ctx> {
         Ok(resource)
     }
 
-    async fn get_next(
+    async fn get_next_oplog_entries(
         &mut self,
-        self_: Resource<GetOplogEntry>,
-    ) -> anyhow::Result<Option<Vec<OplogEntry>>> {
+        self_: &Resource<GetOplogEntry>,
+    ) -> anyhow::Result<Option<Vec<PublicOplogEntry>>> {
         let _permit = self.begin_async_host_function().await?;
         record_host_function_call("golem::api::get-oplog", "get-next");
 
         let component_service = self.state.component_service.clone();
         let oplog_service = self.state.oplog_service();
 
-        let entry = self.as_wasi_view().table().get(&self_)?.clone();
+        let entry = self.as_wasi_view().table().get(self_)?.clone();
 
         let chunk = get_public_oplog_chunk(
             component_service,
@@ -229,27 +229,67 @@ impl<Ctx: WorkerCtx> HostGetOplog for DurableWorkerCtx<Ctx> {
         if chunk.next_oplog_index != entry.next_oplog_index {
             self.as_wasi_view()
                 .table()
-                .get_mut(&self_)?
+                .get_mut(self_)?
                 .update(chunk.next_oplog_index, chunk.current_component_version);
-            Ok(Some(
-                chunk
-                    .entries
-                    .into_iter()
-                    .map(|entry| entry.into())
-                    .collect(),
-            ))
+            Ok(Some(chunk.entries))
         } else {
             Ok(None)
         }
     }
 
-    fn drop(&mut self, rep: Resource<GetOplogEntry>) -> anyhow::Result<()> {
+    fn drop_get_oplog(&mut self, rep: Resource<GetOplogEntry>) -> anyhow::Result<()> {
         record_host_function_call("golem::api::get-oplog", "drop");
         self.as_wasi_view().table().delete(rep)?;
         Ok(())
     }
 }
 
+#[async_trait]
+impl<Ctx: WorkerCtx> HostGetOplog for DurableWorkerCtx<Ctx> {
+    async fn new(
+        &mut self,
+        worker_id: WorkerId,
+        start: OplogIndex,
+    ) -> anyhow::Result<Resource<GetOplogEntry>> {
+        self.new_get_oplog(worker_id, start).await
+    }
+
+    async fn get_next(
+        &mut self,
+        self_: Resource<GetOplogEntry>,
+    ) -> anyhow::Result<Option<Vec<OplogEntry>>> {
+        let entries = self.get_next_oplog_entries(&self_).await?;
+        Ok(entries.map(|entries| entries.into_iter().map(|entry| entry.into()).collect()))
+    }
+
+    fn drop(&mut self, rep: Resource<GetOplogEntry>) -> anyhow::Result<()> {
+        self.drop_get_oplog(rep)
+    }
+}
+
+#[async_trait]
+impl<Ctx: WorkerCtx> golem::api1_1_0_rc2::oplog::HostGetOplog for DurableWorkerCtx<Ctx> {
+    async fn new(
+        &mut self,
+        worker_id: WorkerId,
+        start: OplogIndex,
+    ) -> anyhow::Result<Resource<GetOplogEntry>> {
+        self.new_get_oplog(worker_id, start).await
+    }
+
+    async fn get_next(
+        &mut self,
+        self_: Resource<GetOplogEntry>,
+    ) -> anyhow::Result<Option<Vec<golem::api1_1_0_rc2::oplog::OplogEntry>>> {
+        let entries = self.get_next_oplog_entries(&self_).await?;
+        Ok(entries.map(|entries| entries.into_iter().map(|entry| entry.into()).collect()))
+    }
+
+    fn drop(&mut self, rep: Resource<GetOplogEntry>) -> anyhow::Result<()> {
+        self.drop_get_oplog(rep)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct GetOplogEntry {
     pub owned_worker_id: OwnedWorkerId,
@@ -286,6 +326,9 @@ impl GetOplogEntry {
 #[async_trait]
 impl<Ctx: WorkerCtx> OplogHost for DurableWorkerCtx<Ctx> {}
 
+#[async_trait]
+impl<Ctx: WorkerCtx> golem::api1_1_0_rc2::oplog::Host for DurableWorkerCtx<Ctx> {}
+
 #[async_trait]
 impl<Ctx: WorkerCtx> HostGetWorkers for &mut DurableWorkerCtx<Ctx> {
     async fn new(
@@ -409,8 +452,8 @@ impl<Ctx: WorkerCtx> Host for &mut DurableWorkerCtx<Ctx> {
 impl<Ctx: WorkerCtx> HostGetOplog for &mut DurableWorkerCtx<Ctx> {
     async fn new(
         &mut self,
-        worker_id: golem::api1_1_0_rc1::oplog::WorkerId,
-        start: golem::api1_1_0_rc1::oplog::OplogIndex,
+        worker_id: WorkerId,
+        start: OplogIndex,
     ) -> anyhow::Result<Resource<GetOplogEntry>> {
         HostGetOplog::new(*self, worker_id, start).await
     }
@@ -430,6 +473,31 @@ impl<Ctx: WorkerCtx> HostGetOplog for &mut DurableWorkerCtx<Ctx> {
 #[async_trait]
 impl<Ctx: WorkerCtx> OplogHost for &mut DurableWorkerCtx<Ctx> {}
 
+#[async_trait]
+impl<Ctx: WorkerCtx> golem::api1_1_0_rc2::oplog::HostGetOplog for &mut DurableWorkerCtx<Ctx> {
+    async fn new(
+        &mut self,
+        worker_id: WorkerId,
+        start: OplogIndex,
+    ) -> anyhow::Result<Resource<GetOplogEntry>> {
+        golem::api1_1_0_rc2::oplog::HostGetOplog::new(*self, worker_id, start).await
+    }
+
+    async fn get_next(
+        &mut self,
+        self_: Resource<GetOplogEntry>,
+    ) -> anyhow::Result<Option<Vec<golem::api1_1_0_rc2::oplog::OplogEntry>>> {
+        golem::api1_1_0_rc2::oplog::HostGetOplog::get_next(*self, self_).await
+    }
+
+    fn drop(&mut self, rep: Resource<GetOplogEntry>) -> anyhow::Result<()> {
+        golem::api1_1_0_rc2::oplog::HostGetOplog::drop(*self, rep)
+    }
+}
+
+#[async_trait]
+impl<Ctx: WorkerCtx> golem::api1_1_0_rc2::oplog::Host for &mut DurableWorkerCtx<Ctx> {}
+
 impl From<Uuid> for golem::api0_2_0::host::Uuid {
     fn from(value: Uuid) -> Self {
         golem::api0_2_0::host::Uuid {
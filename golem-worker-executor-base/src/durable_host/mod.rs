@@ -1124,6 +1124,25 @@ impl<Ctx: WorkerCtx + DurableWorkerCtxView<Ctx>> ExternalOperations<Ctx> for Dur
 
             Ok(RetryDecision::None)
         } else {
+            // The oplog entries covered by a restored memory snapshot do not have to be replayed
+            let restored_memory_snapshot = store
+                .as_context()
+                .data()
+                .get_public_state()
+                .worker()
+                .take_restored_memory_snapshot();
+            if let Some(oplog_index) = restored_memory_snapshot {
+                debug!("Skipping replay up to {oplog_index} restored from a memory snapshot");
+                store
+                    .as_context_mut()
+                    .data_mut()
+                    .durable_ctx_mut()
+                    .state
+                    .replay_state
+                    .skip_to(oplog_index)
+                    .await;
+            }
+
             // Handle the case when recovery immediately starts in a deleted region
             // (for example due to a manual update)
             store
@@ -77,6 +77,14 @@ impl ReplayState {
         self.last_replayed_index.set(self.replay_target.get());
     }
 
+    /// Continues replaying after `idx`, used when the worker's state at that point has been
+    /// restored from a memory snapshot
+    pub async fn skip_to(&mut self, idx: OplogIndex) {
+        if idx > self.last_replayed_index.get() && idx <= self.replay_target.get() {
+            self.move_replay_idx(idx).await;
+        }
+    }
+
     pub fn last_replayed_index(&self) -> OplogIndex {
         self.last_replayed_index.get()
     }
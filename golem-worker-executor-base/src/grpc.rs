@@ -17,7 +17,7 @@ use gethostname::gethostname;
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 use golem_wasm_rpc::protobuf::Val;
 use std::cmp::min;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt::{Debug, Display, Formatter};
 use std::marker::PhantomData;
 use std::pin::Pin;
@@ -46,7 +46,7 @@ use golem_common::grpc::{
     proto_promise_id_string, proto_target_worker_id_string, proto_worker_id_string,
 };
 use golem_common::metrics::api::record_new_grpc_api_active_stream;
-use golem_common::model::oplog::{OplogIndex, UpdateDescription};
+use golem_common::model::oplog::{CancellationReason, OplogIndex, UpdateDescription};
 use golem_common::model::{
     AccountId, ComponentId, ComponentType, IdempotencyKey, OwnedWorkerId, ScanCursor, ShardId,
     TargetWorkerId, TimestampedWorkerInvocation, WorkerEvent, WorkerFilter, WorkerId,
@@ -54,14 +54,16 @@ use golem_common::model::{
 };
 use golem_common::{model as common_model, recorded_grpc_api_request};
 
+use crate::metrics::sharding::record_standby_prefetch;
 use crate::model::public_oplog::{find_component_version_at, get_public_oplog_chunk};
 use crate::model::{InterruptKind, LastError};
 use crate::services::events::Event;
 use crate::services::worker_activator::{DefaultWorkerActivator, LazyWorkerActivator};
 use crate::services::worker_event::WorkerEventReceiver;
 use crate::services::{
-    All, HasActiveWorkers, HasAll, HasComponentService, HasEvents, HasOplogService,
-    HasPromiseService, HasRunningWorkerEnumerationService, HasShardManagerService, HasShardService,
+    All, HasActiveWorkers, HasAll, HasComponentService, HasConfig, HasEvents,
+    HasMemorySnapshotService, HasOplogService, HasPromiseService,
+    HasRunningWorkerEnumerationService, HasShardManagerService, HasShardService, HasWasmtimeEngine,
     HasWorkerEnumerationService, HasWorkerService, UsesAllDeps,
 };
 use crate::worker::Worker;
@@ -400,9 +402,21 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             }
 
             worker.stop().await;
+            // So the snapshot being saved is deleted too
+            worker.await_pending_memory_snapshot().await;
         }
 
         Ctx::on_worker_deleted(self, &worker_id).await?;
+        if self.config().memory_snapshot.enabled {
+            // Snapshots left behind are never restored for a new worker with the same id
+            if let Err(err) = self
+                .memory_snapshot_service()
+                .delete(&owned_worker_id)
+                .await
+            {
+                warn!("Failed to delete the memory snapshots of {owned_worker_id}: {err}");
+            }
+        }
         self.worker_service().remove(&owned_worker_id).await;
         self.active_workers().remove(&worker_id);
 
@@ -482,16 +496,27 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
                 let worker =
                     Worker::get_or_create_suspended(self, &owned_worker_id, None, None, None, None)
                         .await?;
-                worker
-                    .set_interrupting(if request.recover_immediately {
-                        InterruptKind::Restart
-                    } else {
-                        InterruptKind::Interrupt
-                    })
-                    .await;
 
-                // Explicitly drop from the active worker cache - this will drop websocket connections etc.
-                self.active_workers().remove(&worker_id);
+                // With a grace period configured the running invocation is first asked to cancel
+                // itself, and the worker only gets interrupted after it finished or the grace period elapsed
+                let cancelled = !request.recover_immediately
+                    && !self.config().limits.cancellation_grace_period.is_zero()
+                    && worker
+                        .cancel_current_invocation(CancellationReason::Interrupt)
+                        .await;
+
+                if !cancelled {
+                    worker
+                        .set_interrupting(if request.recover_immediately {
+                            InterruptKind::Restart
+                        } else {
+                            InterruptKind::Interrupt
+                        })
+                        .await;
+
+                    // Explicitly drop from the active worker cache - this will drop websocket connections etc.
+                    self.active_workers().remove(&worker_id);
+                }
             }
         }
 
@@ -717,6 +742,66 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         Ok(())
     }
 
+    async fn assign_standby_shards_internal(
+        &self,
+        request: golem::workerexecutor::v1::AssignStandbyShardsRequest,
+    ) -> Result<(), GolemError> {
+        let proto_shard_ids = request.shard_ids;
+
+        let shard_ids = proto_shard_ids.into_iter().map(ShardId::from).collect();
+
+        self.shard_service().assign_standby_shards(&shard_ids);
+
+        // Pre-fetching happens in the background, the shard manager does not have to wait for
+        // the artifacts to be downloaded and compiled
+        let this = self.clone();
+        tokio::spawn(async move { this.prefetch_standby_components().await }.in_current_span());
+
+        Ok(())
+    }
+
+    /// Downloads (and compiles) all the component versions used by the running workers of the
+    /// standby shards, so they are already in the component cache if the shards get assigned
+    /// to this executor.
+    async fn prefetch_standby_components(&self) {
+        let workers = self
+            .worker_service()
+            .get_running_workers_in_standby_shards()
+            .await;
+
+        let components = workers
+            .into_iter()
+            .map(|worker| {
+                (
+                    worker.worker_id.component_id,
+                    worker.last_known_status.component_version,
+                )
+            })
+            .collect::<HashSet<_>>();
+
+        debug!(
+            "Pre-fetching {} component versions for standby shards",
+            components.len()
+        );
+
+        let engine = self.engine();
+        for (component_id, component_version) in components {
+            match self
+                .component_service()
+                .get(&engine, &component_id, component_version)
+                .await
+            {
+                Ok(_) => record_standby_prefetch(true),
+                Err(err) => {
+                    warn!(
+                        "Failed to pre-fetch component {component_id}#{component_version} for standby shards: {err}"
+                    );
+                    record_standby_prefetch(false)
+                }
+            }
+        }
+    }
+
     async fn get_worker_metadata_internal(
         &self,
         request: golem::workerexecutor::v1::GetWorkerMetadataRequest,
@@ -1594,6 +1679,42 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         }
     }
 
+    async fn assign_standby_shards(
+        &self,
+        request: Request<golem::workerexecutor::v1::AssignStandbyShardsRequest>,
+    ) -> Result<Response<golem::workerexecutor::v1::AssignStandbyShardsResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!("assign_standby_shards",);
+
+        match self
+            .assign_standby_shards_internal(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(_) => record.succeed(Ok(Response::new(
+                golem::workerexecutor::v1::AssignStandbyShardsResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::assign_standby_shards_response::Result::Success(
+                            golem::common::Empty {},
+                        ),
+                    ),
+                },
+            ))),
+            Err(err) => record.fail(
+                Ok(Response::new(
+                    golem::workerexecutor::v1::AssignStandbyShardsResponse {
+                        result: Some(
+                            golem::workerexecutor::v1::assign_standby_shards_response::Result::Failure(
+                                err.clone().into(),
+                            ),
+                        ),
+                    },
+                )),
+                &err,
+            ),
+        }
+    }
+
     async fn get_worker_metadata(
         &self,
         request: Request<golem::workerexecutor::v1::GetWorkerMetadataRequest>,
@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::fmt::Display;
 use std::net::SocketAddr;
+use std::sync::Arc;
 
 use http_02::{Response, StatusCode};
 use prometheus::{Encoder, Registry, TextEncoder};
@@ -22,7 +24,11 @@ use tracing::info;
 use warp::hyper::Body;
 use warp::Filter;
 
-/// The worker executor's HTTP interface provides Prometheus metrics and a healthcheck endpoint
+use crate::services::oplog::ShadowOplogService;
+use golem_common::model::ShardId;
+
+/// The worker executor's HTTP interface provides Prometheus metrics and a healthcheck endpoint,
+/// and the cutover control of the oplog migration if it is enabled
 pub struct HttpServerImpl {
     handle: JoinHandle<()>,
 }
@@ -33,7 +39,16 @@ impl HttpServerImpl {
         registry: Registry,
         body_message: &'static str,
     ) -> HttpServerImpl {
-        let handle = tokio::spawn(server(addr, registry, body_message));
+        Self::new_with_oplog_migration(addr, registry, body_message, None)
+    }
+
+    pub fn new_with_oplog_migration(
+        addr: impl Into<SocketAddr> + Display + Send + 'static,
+        registry: Registry,
+        body_message: &'static str,
+        oplog_migration: Option<Arc<ShadowOplogService>>,
+    ) -> HttpServerImpl {
+        let handle = tokio::spawn(server(addr, registry, body_message, oplog_migration));
         HttpServerImpl { handle }
     }
 }
@@ -49,6 +64,7 @@ async fn server(
     addr: impl Into<SocketAddr> + Display + Send,
     registry: Registry,
     body_message: &'static str,
+    oplog_migration: Option<Arc<ShadowOplogService>>,
 ) {
     let healthcheck = warp::path!("healthcheck").map(move || {
         Response::builder()
@@ -59,8 +75,59 @@ async fn server(
 
     let metrics = warp::path!("metrics").map(move || prometheus_metrics(registry.clone()));
 
+    // POST cuts over a shard, DELETE rolls it back
+    let oplog_migration_cutover = warp::path!("oplog-migration" / "cutover-shards" / i64)
+        .and(
+            warp::post()
+                .map(|| true)
+                .or(warp::delete().map(|| false))
+                .unify(),
+        )
+        .map(move |shard_id: i64, cut_over: bool| {
+            oplog_migration_cutover(oplog_migration.as_deref(), ShardId::new(shard_id), cut_over)
+        });
+
     info!("Http server started on {addr}");
-    warp::serve(healthcheck.or(metrics)).run(addr).await;
+    warp::serve(healthcheck.or(metrics).or(oplog_migration_cutover))
+        .run(addr)
+        .await;
+}
+
+fn oplog_migration_cutover(
+    oplog_migration: Option<&ShadowOplogService>,
+    shard_id: ShardId,
+    cut_over: bool,
+) -> Response<Body> {
+    match oplog_migration {
+        Some(oplog_migration) => {
+            let shard_ids = HashSet::from([shard_id]);
+            if cut_over {
+                oplog_migration.cut_over(&shard_ids);
+            } else {
+                oplog_migration.roll_back(&shard_ids);
+            }
+
+            let mut cutover_shards = oplog_migration
+                .cutover_shards()
+                .into_iter()
+                .collect::<Vec<_>>();
+            cutover_shards.sort();
+            let cutover_shards = cutover_shards
+                .iter()
+                .map(|shard_id| shard_id.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            Response::builder()
+                .status(StatusCode::OK)
+                .body(Body::from(format!("Cut over shards: {cutover_shards}")))
+                .unwrap()
+        }
+        None => Response::builder()
+            .status(StatusCode::NOT_FOUND)
+            .body(Body::from("Oplog migration is not enabled"))
+            .unwrap(),
+    }
 }
 
 fn prometheus_metrics(registry: Registry) -> Response<Body> {
@@ -19,6 +19,7 @@ pub mod http_server;
 
 pub mod function_result_interpreter;
 pub mod invocation;
+pub mod linear_memory;
 pub mod metrics;
 pub mod model;
 pub mod preview2;
@@ -41,9 +42,10 @@ use crate::services::golem_config::{
     BlobStorageConfig, GolemConfig, IndexedStorageConfig, KeyValueStorageConfig,
 };
 use crate::services::key_value::{DefaultKeyValueService, KeyValueService};
+use crate::services::memory_snapshot::{DefaultMemorySnapshotService, MemorySnapshotService};
 use crate::services::oplog::{
     BlobOplogArchiveService, CompressedOplogArchiveService, MultiLayerOplogService,
-    OplogArchiveService, OplogService, PrimaryOplogService,
+    OplogArchiveService, OplogService, PrimaryOplogService, ShadowOplogService,
 };
 use crate::services::promise::{DefaultPromiseService, PromiseService};
 use crate::services::scheduler::{SchedulerService, SchedulerServiceDefault};
@@ -70,6 +72,7 @@ use async_trait::async_trait;
 use golem_api_grpc::proto;
 use golem_api_grpc::proto::golem::workerexecutor::v1::worker_executor_server::WorkerExecutorServer;
 use golem_common::golem_version;
+use golem_common::model::ShardId;
 use golem_common::redis::RedisPool;
 use humansize::{ISizeFormatter, BINARY};
 use nonempty_collections::NEVec;
@@ -117,6 +120,7 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
         worker_activator: Arc<dyn WorkerActivator + Send + Sync>,
         oplog_service: Arc<dyn OplogService + Send + Sync>,
         scheduler_service: Arc<dyn SchedulerService + Send + Sync>,
+        memory_snapshot_service: Arc<dyn MemorySnapshotService + Send + Sync>,
         worker_proxy: Arc<dyn WorkerProxy + Send + Sync>,
         events: Arc<Events>,
     ) -> anyhow::Result<All<Ctx>>;
@@ -168,12 +172,6 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
             .build()
             .unwrap();
 
-        let http_server = HttpServerImpl::new(
-            golem_config.http_addr()?,
-            prometheus_registry,
-            "Worker executor is running",
-        );
-
         let (redis, key_value_storage): (
             Option<RedisPool>,
             Arc<dyn KeyValueStorage + Send + Sync>,
@@ -202,25 +200,8 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
             }
         };
 
-        let indexed_storage: Arc<dyn IndexedStorage + Send + Sync> = match &golem_config
-            .indexed_storage
-        {
-            IndexedStorageConfig::KVStoreRedis => {
-                info!("Using the same Redis for indexed-storage");
-                let redis = redis
-                    .expect("Redis must be configured key-value storage when using KVStoreRedis");
-                Arc::new(RedisIndexedStorage::new(redis.clone()))
-            }
-            IndexedStorageConfig::Redis(redis) => {
-                info!("Using Redis for indexed-storage at {}", redis.url());
-                let pool = RedisPool::configured(redis).await?;
-                Arc::new(RedisIndexedStorage::new(pool.clone()))
-            }
-            IndexedStorageConfig::InMemory => {
-                info!("Using in-memory indexed storage");
-                Arc::new(storage::indexed::memory::InMemoryIndexedStorage::new())
-            }
-        };
+        let indexed_storage =
+            create_indexed_storage(&golem_config.indexed_storage, redis.as_ref()).await?;
         let blob_storage: Arc<dyn BlobStorage + Send + Sync> = match &golem_config.blob_storage {
             BlobStorageConfig::S3(config) => {
                 info!("Using S3 for blob storage");
@@ -257,50 +238,59 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
         let shard_service = Arc::new(ShardServiceDefault::new());
         let lazy_worker_activator = Arc::new(LazyWorkerActivator::new());
 
-        let mut oplog_archives: Vec<Arc<dyn OplogArchiveService + Send + Sync>> = Vec::new();
-        for idx in 1..golem_config.oplog.indexed_storage_layers {
-            let svc: Arc<dyn OplogArchiveService + Send + Sync> = Arc::new(
-                CompressedOplogArchiveService::new(indexed_storage.clone(), idx),
-            );
-            oplog_archives.push(svc);
-        }
-        for idx in 0..golem_config.oplog.blob_storage_layers {
-            let svc: Arc<dyn OplogArchiveService + Send + Sync> =
-                Arc::new(BlobOplogArchiveService::new(blob_storage.clone(), idx));
-            oplog_archives.push(svc);
-        }
-        let oplog_archives = NEVec::from_vec(oplog_archives);
+        let oplog_service = create_oplog_service(
+            indexed_storage.clone(),
+            blob_storage.clone(),
+            golem_config.oplog.indexed_storage_layers,
+            0,
+            golem_config.oplog.blob_storage_layers,
+            &golem_config,
+        )
+        .await;
 
-        let oplog_service: Arc<dyn OplogService + Send + Sync> = match oplog_archives {
-            None => Arc::new(
-                PrimaryOplogService::new(
-                    indexed_storage.clone(),
-                    blob_storage.clone(),
-                    golem_config.oplog.max_operations_before_commit,
-                    golem_config.oplog.max_payload_size,
-                )
-                .await,
-            ),
-            Some(oplog_archives) => {
-                let primary = Arc::new(
-                    PrimaryOplogService::new(
-                        indexed_storage.clone(),
-                        blob_storage.clone(),
-                        golem_config.oplog.max_operations_before_commit,
-                        golem_config.oplog.max_payload_size,
-                    )
-                    .await,
-                );
+        let (oplog_service, oplog_migration): (
+            Arc<dyn OplogService + Send + Sync>,
+            Option<Arc<ShadowOplogService>>,
+        ) = if golem_config.oplog_migration.enabled {
+            info!("Oplog migration enabled, shadowing oplog writes to the target layout");
+            let migration = &golem_config.oplog_migration;
+            let target_indexed_storage =
+                create_indexed_storage(&migration.target_indexed_storage, redis.as_ref()).await?;
+            let target_oplog_service = create_oplog_service(
+                target_indexed_storage,
+                blob_storage.clone(),
+                migration.target_indexed_storage_layers,
+                // The blob storage is shared, so the target's archive layers
+                // use the levels following the source's ones
+                golem_config.oplog.blob_storage_layers,
+                migration.target_blob_storage_layers,
+                &golem_config,
+            )
+            .await;
 
-                Arc::new(MultiLayerOplogService::new(
-                    primary,
-                    oplog_archives,
-                    golem_config.oplog.entry_count_limit,
-                    golem_config.oplog.max_operations_before_commit_ephemeral,
-                ))
-            }
+            let shadow_oplog_service = Arc::new(ShadowOplogService::new(
+                oplog_service,
+                target_oplog_service,
+                shard_service.clone(),
+                migration
+                    .cutover_shards
+                    .iter()
+                    .map(|shard_id| ShardId::new(*shard_id))
+                    .collect(),
+                migration.verify_reads,
+            ));
+            (shadow_oplog_service.clone(), Some(shadow_oplog_service))
+        } else {
+            (oplog_service, None)
         };
 
+        let http_server = HttpServerImpl::new_with_oplog_migration(
+            golem_config.http_addr()?,
+            prometheus_registry,
+            "Worker executor is running",
+            oplog_migration,
+        );
+
         let worker_service = Arc::new(DefaultWorkerService::new(
             key_value_storage.clone(),
             shard_service.clone(),
@@ -320,7 +310,10 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
 
         let shard_manager_service = shard_manager::configured(&golem_config.shard_manager_service);
 
-        let config = self.create_wasmtime_config();
+        let mut config = self.create_wasmtime_config();
+        if golem_config.memory_snapshot.enabled {
+            linear_memory::configure(&mut config);
+        }
         let engine = Arc::new(Engine::new(&config)?);
         let linker = self.create_wasmtime_linker(&engine)?;
 
@@ -349,6 +342,11 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
             golem_config.scheduler.refresh_interval,
         );
 
+        let memory_snapshot_service = Arc::new(DefaultMemorySnapshotService::new(
+            blob_storage.clone(),
+            golem_config.memory_snapshot.clone(),
+        ));
+
         let worker_proxy: Arc<dyn WorkerProxy + Send + Sync> = Arc::new(RemoteWorkerProxy::new(
             golem_config.public_worker_api.uri(),
             golem_config
@@ -381,6 +379,7 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
                 lazy_worker_activator.clone(),
                 oplog_service,
                 scheduler_service,
+                memory_snapshot_service,
                 worker_proxy,
                 events,
             )
@@ -408,3 +407,82 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
         Ok(())
     }
 }
+
+async fn create_indexed_storage(
+    config: &IndexedStorageConfig,
+    redis: Option<&RedisPool>,
+) -> anyhow::Result<Arc<dyn IndexedStorage + Send + Sync>> {
+    match config {
+        IndexedStorageConfig::KVStoreRedis => {
+            info!("Using the same Redis for indexed-storage");
+            let redis =
+                redis.expect("Redis must be configured key-value storage when using KVStoreRedis");
+            Ok(Arc::new(RedisIndexedStorage::new(redis.clone())))
+        }
+        IndexedStorageConfig::Redis(redis) => {
+            info!("Using Redis for indexed-storage at {}", redis.url());
+            let pool = RedisPool::configured(redis).await?;
+            Ok(Arc::new(RedisIndexedStorage::new(pool.clone())))
+        }
+        IndexedStorageConfig::InMemory => {
+            info!("Using in-memory indexed storage");
+            Ok(Arc::new(
+                storage::indexed::memory::InMemoryIndexedStorage::new(),
+            ))
+        }
+    }
+}
+
+async fn create_oplog_service(
+    indexed_storage: Arc<dyn IndexedStorage + Send + Sync>,
+    blob_storage: Arc<dyn BlobStorage + Send + Sync>,
+    indexed_storage_layers: usize,
+    first_blob_storage_level: usize,
+    blob_storage_layers: usize,
+    golem_config: &GolemConfig,
+) -> Arc<dyn OplogService + Send + Sync> {
+    let mut oplog_archives: Vec<Arc<dyn OplogArchiveService + Send + Sync>> = Vec::new();
+    for idx in 1..indexed_storage_layers {
+        let svc: Arc<dyn OplogArchiveService + Send + Sync> = Arc::new(
+            CompressedOplogArchiveService::new(indexed_storage.clone(), idx),
+        );
+        oplog_archives.push(svc);
+    }
+    for idx in 0..blob_storage_layers {
+        let svc: Arc<dyn OplogArchiveService + Send + Sync> = Arc::new(
+            BlobOplogArchiveService::new(blob_storage.clone(), first_blob_storage_level + idx),
+        );
+        oplog_archives.push(svc);
+    }
+    let oplog_archives = NEVec::from_vec(oplog_archives);
+
+    match oplog_archives {
+        None => Arc::new(
+            PrimaryOplogService::new(
+                indexed_storage.clone(),
+                blob_storage.clone(),
+                golem_config.oplog.max_operations_before_commit,
+                golem_config.oplog.max_payload_size,
+            )
+            .await,
+        ),
+        Some(oplog_archives) => {
+            let primary = Arc::new(
+                PrimaryOplogService::new(
+                    indexed_storage.clone(),
+                    blob_storage.clone(),
+                    golem_config.oplog.max_operations_before_commit,
+                    golem_config.oplog.max_payload_size,
+                )
+                .await,
+            );
+
+            Arc::new(MultiLayerOplogService::new(
+                primary,
+                oplog_archives,
+                golem_config.oplog.entry_count_limit,
+                golem_config.oplog.max_operations_before_commit_ephemeral,
+            ))
+        }
+    }
+}
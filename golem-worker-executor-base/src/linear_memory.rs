@@ -0,0 +1,239 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cell::RefCell;
+use std::future::Future;
+use std::ops::Range;
+use std::sync::{Arc, Mutex};
+
+use wasmtime::{Config, LinearMemory, MemoryCreator, MemoryType};
+
+/// Makes wasmtime allocate every linear memory through [`SnapshotMemoryCreator`], so the
+/// memories of an instance can be read and overwritten when taking or restoring memory snapshots.
+///
+/// Memories are forced to be dynamic without guard pages, so wasmtime performs explicit bounds
+/// checks and is free to move the buffer when it grows.
+pub fn configure(config: &mut Config) {
+    config.with_host_memory(Arc::new(SnapshotMemoryCreator));
+    config.static_memory_maximum_size(0);
+    config.dynamic_memory_guard_size(0);
+    config.dynamic_memory_reserved_for_growth(0);
+    config.guard_before_linear_memory(false);
+}
+
+/// The linear memories created while instantiating a component, in creation order
+#[derive(Clone, Default)]
+pub struct LinearMemories {
+    memories: Vec<Arc<MemoryBuffer>>,
+}
+
+impl LinearMemories {
+    /// Runs the instantiation future and collects the memories it creates. If `initial_sizes` is
+    /// given, the i-th created memory starts with at least `initial_sizes[i]` bytes, so the
+    /// contents of a snapshot taken with the same component version can be written into it.
+    pub async fn instantiate<T>(
+        initial_sizes: Vec<usize>,
+        instantiation: impl Future<Output = T>,
+    ) -> (T, LinearMemories) {
+        let scope = RefCell::new(Instantiation {
+            initial_sizes,
+            created: Vec::new(),
+        });
+        INSTANTIATION
+            .scope(scope, async move {
+                let result = instantiation.await;
+                let memories =
+                    INSTANTIATION.with(|scope| std::mem::take(&mut scope.borrow_mut().created));
+                (result, LinearMemories { memories })
+            })
+            .await
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.memories.is_empty()
+    }
+
+    /// Copies the contents of all memories.
+    ///
+    /// # Safety
+    /// The instance owning the memories must not be running, which is guaranteed by holding the
+    /// lock of its store.
+    pub unsafe fn read(&self) -> Vec<Vec<u8>> {
+        self.memories.iter().map(|memory| memory.read()).collect()
+    }
+
+    /// Overwrites all memories with the given contents, which must have the same sizes as the
+    /// memories.
+    ///
+    /// # Safety
+    /// The instance owning the memories must not be running, which is guaranteed by holding the
+    /// lock of its store.
+    pub unsafe fn write(&self, contents: &[Vec<u8>]) -> Result<(), String> {
+        if contents.len() != self.memories.len() {
+            return Err(format!(
+                "Snapshot has {} memories, but the instance has {}",
+                contents.len(),
+                self.memories.len()
+            ));
+        }
+        for (idx, (memory, content)) in self.memories.iter().zip(contents).enumerate() {
+            if memory.byte_size() != content.len() {
+                return Err(format!(
+                    "Snapshot of memory {idx} has {} bytes, but the memory has {}",
+                    content.len(),
+                    memory.byte_size()
+                ));
+            }
+        }
+        for (memory, content) in self.memories.iter().zip(contents) {
+            memory.write(content);
+        }
+        Ok(())
+    }
+}
+
+tokio::task_local! {
+    static INSTANTIATION: RefCell<Instantiation>;
+}
+
+struct Instantiation {
+    initial_sizes: Vec<usize>,
+    created: Vec<Arc<MemoryBuffer>>,
+}
+
+/// Memory creator registered by [`configure`]
+pub struct SnapshotMemoryCreator;
+
+unsafe impl MemoryCreator for SnapshotMemoryCreator {
+    fn new_memory(
+        &self,
+        _ty: MemoryType,
+        minimum: usize,
+        maximum: Option<usize>,
+        reserved_size_in_bytes: Option<usize>,
+        guard_size_in_bytes: usize,
+    ) -> Result<Box<dyn LinearMemory>, String> {
+        if reserved_size_in_bytes.is_some() || guard_size_in_bytes != 0 {
+            return Err(
+                "Snapshot-able memories must be dynamic and have no guard pages".to_string(),
+            );
+        }
+
+        // Outside of an instantiation scope the memory is still usable, it just cannot be
+        // snapshotted
+        let initial_size = INSTANTIATION
+            .try_with(|scope| {
+                let scope = scope.borrow();
+                scope.initial_sizes.get(scope.created.len()).copied()
+            })
+            .ok()
+            .flatten()
+            .map_or(minimum, |size| size.max(minimum));
+        if let Some(maximum) = maximum {
+            if initial_size > maximum {
+                return Err(format!(
+                    "Initial memory size {initial_size} exceeds the maximum of {maximum}"
+                ));
+            }
+        }
+
+        let buffer = Arc::new(MemoryBuffer::new(initial_size));
+        let _ = INSTANTIATION.try_with(|scope| scope.borrow_mut().created.push(buffer.clone()));
+        Ok(Box::new(SnapshotMemory { buffer, maximum }))
+    }
+}
+
+struct SnapshotMemory {
+    buffer: Arc<MemoryBuffer>,
+    maximum: Option<usize>,
+}
+
+unsafe impl LinearMemory for SnapshotMemory {
+    fn byte_size(&self) -> usize {
+        self.buffer.byte_size()
+    }
+
+    fn maximum_byte_size(&self) -> Option<usize> {
+        self.maximum
+    }
+
+    fn grow_to(&mut self, new_size: usize) -> anyhow::Result<()> {
+        if let Some(maximum) = self.maximum {
+            if new_size > maximum {
+                return Err(anyhow::anyhow!(
+                    "Cannot grow memory to {new_size} bytes, the maximum is {maximum}"
+                ));
+            }
+        }
+        self.buffer.grow_to(new_size);
+        Ok(())
+    }
+
+    fn as_ptr(&self) -> *mut u8 {
+        self.buffer.as_ptr()
+    }
+
+    fn wasm_accessible(&self) -> Range<usize> {
+        let base = self.as_ptr() as usize;
+        base..base + self.byte_size()
+    }
+}
+
+/// Zero-initialized, 8-byte aligned buffer shared by a memory and the instance's
+/// [`LinearMemories`]. The pointer returned by `as_ptr` stays valid until the next `grow_to`.
+struct MemoryBuffer {
+    inner: Mutex<BufferState>,
+}
+
+struct BufferState {
+    words: Vec<u64>,
+    byte_size: usize,
+}
+
+impl MemoryBuffer {
+    fn new(byte_size: usize) -> Self {
+        Self {
+            inner: Mutex::new(BufferState {
+                words: vec![0; byte_size.div_ceil(8)],
+                byte_size,
+            }),
+        }
+    }
+
+    fn byte_size(&self) -> usize {
+        self.inner.lock().unwrap().byte_size
+    }
+
+    fn as_ptr(&self) -> *mut u8 {
+        self.inner.lock().unwrap().words.as_mut_ptr() as *mut u8
+    }
+
+    fn grow_to(&self, new_size: usize) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.words.resize(new_size.div_ceil(8), 0);
+        inner.byte_size = new_size;
+    }
+
+    unsafe fn read(&self) -> Vec<u8> {
+        let inner = self.inner.lock().unwrap();
+        std::slice::from_raw_parts(inner.words.as_ptr() as *const u8, inner.byte_size).to_vec()
+    }
+
+    unsafe fn write(&self, content: &[u8]) {
+        let mut inner = self.inner.lock().unwrap();
+        let len = content.len().min(inner.byte_size);
+        std::slice::from_raw_parts_mut(inner.words.as_mut_ptr() as *mut u8, len)
+            .copy_from_slice(&content[..len]);
+    }
+}
@@ -149,6 +149,28 @@ pub mod promises {
     }
 }
 
+pub mod memory_snapshots {
+    use lazy_static::lazy_static;
+    use prometheus::*;
+
+    lazy_static! {
+        static ref MEMORY_SNAPSHOT_TOTAL: CounterVec = register_counter_vec!(
+            "memory_snapshot_total",
+            "Number of saved memory snapshots and of workers recovered from one",
+            &["operation"]
+        )
+        .unwrap();
+    }
+
+    pub fn record_memory_snapshot_saved() {
+        MEMORY_SNAPSHOT_TOTAL.with_label_values(&["save"]).inc();
+    }
+
+    pub fn record_memory_snapshot_restored() {
+        MEMORY_SNAPSHOT_TOTAL.with_label_values(&["restore"]).inc();
+    }
+}
+
 pub mod sharding {
     use lazy_static::lazy_static;
     use prometheus::*;
@@ -156,11 +178,44 @@ pub mod sharding {
     lazy_static! {
         static ref ASSIGNED_SHARD_COUNT: Gauge =
             register_gauge!("assigned_shard_count", "Current number of assigned shards").unwrap();
+        static ref STANDBY_SHARD_COUNT: Gauge = register_gauge!(
+            "standby_shard_count",
+            "Current number of shards this executor is a warm standby for"
+        )
+        .unwrap();
+        static ref STANDBY_TAKEOVER_TOTAL: CounterVec = register_counter_vec!(
+            "standby_takeover_total",
+            "Number of newly assigned shards, by whether they were prepared as warm standby",
+            &["result"]
+        )
+        .unwrap();
+        static ref STANDBY_PREFETCH_TOTAL: CounterVec = register_counter_vec!(
+            "standby_prefetch_total",
+            "Number of component artifacts pre-fetched for standby shards",
+            &["result"]
+        )
+        .unwrap();
     }
 
     pub fn record_assigned_shard_count(size: usize) {
         ASSIGNED_SHARD_COUNT.set(size as f64);
     }
+
+    pub fn record_standby_shard_count(size: usize) {
+        STANDBY_SHARD_COUNT.set(size as f64);
+    }
+
+    pub fn record_standby_takeover(hit: bool) {
+        STANDBY_TAKEOVER_TOTAL
+            .with_label_values(&[if hit { "hit" } else { "miss" }])
+            .inc();
+    }
+
+    pub fn record_standby_prefetch(success: bool) {
+        STANDBY_PREFETCH_TOTAL
+            .with_label_values(&[if success { "success" } else { "failure" }])
+            .inc();
+    }
 }
 
 pub mod wasm {
@@ -285,6 +340,32 @@ pub mod oplog {
             golem_common::metrics::DEFAULT_TIME_BUCKETS.to_vec()
         )
         .unwrap();
+        static ref OPLOG_MIGRATION_CUTOVER_SHARD_COUNT: Gauge = register_gauge!(
+            "oplog_migration_cutover_shard_count",
+            "Number of shards whose oplogs are served by the migration target"
+        )
+        .unwrap();
+        static ref OPLOG_MIGRATION_PENDING_BACKFILL_COUNT: Gauge = register_gauge!(
+            "oplog_migration_pending_backfill_count",
+            "Number of workers of cut over shards still served by the migration source until their oplogs are backfilled"
+        )
+        .unwrap();
+        static ref OPLOG_MIGRATION_BACKFILLED_WORKERS_TOTAL: Counter = register_counter!(
+            "oplog_migration_backfilled_workers_total",
+            "Number of worker oplogs backfilled into the migration shadow"
+        )
+        .unwrap();
+        static ref OPLOG_MIGRATION_BACKFILLED_ENTRIES_TOTAL: Counter = register_counter!(
+            "oplog_migration_backfilled_entries_total",
+            "Number of oplog entries backfilled into the migration shadow"
+        )
+        .unwrap();
+        static ref OPLOG_MIGRATION_VERIFIED_READS_TOTAL: CounterVec = register_counter_vec!(
+            "oplog_migration_verified_reads_total",
+            "Number of oplog reads compared with the migration shadow, by result",
+            &["result"]
+        )
+        .unwrap();
     }
 
     pub fn record_oplog_call(api_name: &'static str) {
@@ -300,4 +381,23 @@ pub mod oplog {
             })
             .observe(duration.as_secs_f64());
     }
+
+    pub fn record_oplog_migration_cutover_shard_count(count: usize) {
+        OPLOG_MIGRATION_CUTOVER_SHARD_COUNT.set(count as f64);
+    }
+
+    pub fn record_oplog_migration_pending_backfill_count(count: usize) {
+        OPLOG_MIGRATION_PENDING_BACKFILL_COUNT.set(count as f64);
+    }
+
+    pub fn record_oplog_migration_backfill(entry_count: usize) {
+        OPLOG_MIGRATION_BACKFILLED_WORKERS_TOTAL.inc();
+        OPLOG_MIGRATION_BACKFILLED_ENTRIES_TOTAL.inc_by(entry_count as f64);
+    }
+
+    pub fn record_oplog_migration_verified_read(matching: bool) {
+        OPLOG_MIGRATION_VERIFIED_READS_TOTAL
+            .with_label_values(&[if matching { "match" } else { "mismatch" }])
+            .inc();
+    }
 }
@@ -38,6 +38,7 @@ use golem_api_grpc::proto::golem::worker::UpdateMode;
 use golem_common::model::exports::{find_resource_site, function_by_name};
 use golem_common::model::oplog::{OplogEntry, OplogIndex, UpdateDescription};
 use golem_common::model::public_oplog::{
+    CancellationAcknowledgedParameters, CancellationRequestedParameters,
     ChangeRetryPolicyParameters, CreateParameters, DescribeResourceParameters, Empty,
     EndRegionParameters, ErrorParameters, ExportedFunctionCompletedParameters,
     ExportedFunctionInvokedParameters, ExportedFunctionParameters, FailedUpdateParameters,
@@ -547,6 +548,26 @@ impl PublicOplogEntryOps for PublicOplogEntry {
             OplogEntry::Restart { timestamp } => {
                 Ok(PublicOplogEntry::Restart(TimestampParameter { timestamp }))
             }
+            OplogEntry::CancellationRequested {
+                timestamp,
+                idempotency_key,
+                reason,
+            } => Ok(PublicOplogEntry::CancellationRequested(
+                CancellationRequestedParameters {
+                    timestamp,
+                    idempotency_key,
+                    reason,
+                },
+            )),
+            OplogEntry::CancellationAcknowledged {
+                timestamp,
+                idempotency_key,
+            } => Ok(PublicOplogEntry::CancellationAcknowledged(
+                CancellationAcknowledgedParameters {
+                    timestamp,
+                    idempotency_key,
+                },
+            )),
         }
     }
 }
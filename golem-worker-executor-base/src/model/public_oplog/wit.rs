@@ -14,8 +14,10 @@
 
 use crate::model::public_oplog::{PublicOplogEntry, PublicUpdateDescription};
 use crate::preview2::golem::api1_1_0_rc1::oplog;
+use crate::preview2::golem::api1_1_0_rc2;
 use crate::preview2::wasi::clocks::wall_clock::Datetime;
 use golem_common::model::public_oplog::{
+    CancellationAcknowledgedParameters, CancellationRequestedParameters,
     ChangeRetryPolicyParameters, CreateParameters, DescribeResourceParameters, EndRegionParameters,
     ErrorParameters, ExportedFunctionCompletedParameters, ExportedFunctionInvokedParameters,
     ExportedFunctionParameters, FailedUpdateParameters, GrowMemoryParameters,
@@ -27,7 +29,7 @@ use golem_common::model::public_oplog::{
 };
 use golem_common::model::Timestamp;
 
-impl From<PublicOplogEntry> for oplog::OplogEntry {
+impl From<PublicOplogEntry> for api1_1_0_rc2::oplog::OplogEntry {
     fn from(value: PublicOplogEntry) -> Self {
         match value {
             PublicOplogEntry::Create(CreateParameters {
@@ -218,6 +220,104 @@ impl From<PublicOplogEntry> for oplog::OplogEntry {
             PublicOplogEntry::Restart(TimestampParameter { timestamp }) => {
                 Self::Restart(timestamp.into())
             }
+            PublicOplogEntry::CancellationRequested(CancellationRequestedParameters {
+                timestamp,
+                idempotency_key,
+                reason,
+            }) => {
+                Self::CancellationRequested(api1_1_0_rc2::oplog::CancellationRequestedParameters {
+                    timestamp: timestamp.into(),
+                    idempotency_key: idempotency_key.value,
+                    reason: reason.into(),
+                })
+            }
+            PublicOplogEntry::CancellationAcknowledged(CancellationAcknowledgedParameters {
+                timestamp,
+                idempotency_key,
+            }) => Self::CancellationAcknowledged(
+                api1_1_0_rc2::oplog::CancellationAcknowledgedParameters {
+                    timestamp: timestamp.into(),
+                    idempotency_key: idempotency_key.value,
+                },
+            ),
+        }
+    }
+}
+
+/// `golem:api/oplog@1.1.0-rc1` has no cancellation cases, so these entries are exposed as log entries
+impl From<PublicOplogEntry> for oplog::OplogEntry {
+    fn from(value: PublicOplogEntry) -> Self {
+        match api1_1_0_rc2::oplog::OplogEntry::from(value) {
+            api1_1_0_rc2::oplog::OplogEntry::Create(params) => Self::Create(params),
+            api1_1_0_rc2::oplog::OplogEntry::ImportedFunctionInvoked(params) => {
+                Self::ImportedFunctionInvoked(params)
+            }
+            api1_1_0_rc2::oplog::OplogEntry::ExportedFunctionInvoked(params) => {
+                Self::ExportedFunctionInvoked(params)
+            }
+            api1_1_0_rc2::oplog::OplogEntry::ExportedFunctionCompleted(params) => {
+                Self::ExportedFunctionCompleted(params)
+            }
+            api1_1_0_rc2::oplog::OplogEntry::Suspend(timestamp) => Self::Suspend(timestamp),
+            api1_1_0_rc2::oplog::OplogEntry::Error(params) => Self::Error(params),
+            api1_1_0_rc2::oplog::OplogEntry::NoOp(timestamp) => Self::NoOp(timestamp),
+            api1_1_0_rc2::oplog::OplogEntry::Jump(params) => Self::Jump(params),
+            api1_1_0_rc2::oplog::OplogEntry::Interrupted(timestamp) => Self::Interrupted(timestamp),
+            api1_1_0_rc2::oplog::OplogEntry::Exited(timestamp) => Self::Exited(timestamp),
+            api1_1_0_rc2::oplog::OplogEntry::ChangeRetryPolicy(params) => {
+                Self::ChangeRetryPolicy(params)
+            }
+            api1_1_0_rc2::oplog::OplogEntry::BeginAtomicRegion(timestamp) => {
+                Self::BeginAtomicRegion(timestamp)
+            }
+            api1_1_0_rc2::oplog::OplogEntry::EndAtomicRegion(params) => {
+                Self::EndAtomicRegion(params)
+            }
+            api1_1_0_rc2::oplog::OplogEntry::BeginRemoteWrite(timestamp) => {
+                Self::BeginRemoteWrite(timestamp)
+            }
+            api1_1_0_rc2::oplog::OplogEntry::EndRemoteWrite(params) => Self::EndRemoteWrite(params),
+            api1_1_0_rc2::oplog::OplogEntry::PendingWorkerInvocation(params) => {
+                Self::PendingWorkerInvocation(params)
+            }
+            api1_1_0_rc2::oplog::OplogEntry::PendingUpdate(params) => Self::PendingUpdate(params),
+            api1_1_0_rc2::oplog::OplogEntry::SuccessfulUpdate(params) => {
+                Self::SuccessfulUpdate(params)
+            }
+            api1_1_0_rc2::oplog::OplogEntry::FailedUpdate(params) => Self::FailedUpdate(params),
+            api1_1_0_rc2::oplog::OplogEntry::GrowMemory(params) => Self::GrowMemory(params),
+            api1_1_0_rc2::oplog::OplogEntry::CreateResource(params) => Self::CreateResource(params),
+            api1_1_0_rc2::oplog::OplogEntry::DropResource(params) => Self::DropResource(params),
+            api1_1_0_rc2::oplog::OplogEntry::DescribeResource(params) => {
+                Self::DescribeResource(params)
+            }
+            api1_1_0_rc2::oplog::OplogEntry::Log(params) => Self::Log(params),
+            api1_1_0_rc2::oplog::OplogEntry::Restart(timestamp) => Self::Restart(timestamp),
+            api1_1_0_rc2::oplog::OplogEntry::CancellationRequested(params) => {
+                let reason = match params.reason {
+                    api1_1_0_rc2::oplog::CancellationReason::Interrupt => "interrupt",
+                    api1_1_0_rc2::oplog::CancellationReason::InvocationTimeout => {
+                        "invocation timeout"
+                    }
+                };
+                Self::Log(oplog::LogParameters {
+                    timestamp: params.timestamp,
+                    level: oplog::LogLevel::Info,
+                    context: "cancellation".to_string(),
+                    message: format!(
+                        "Cancellation of {} requested ({reason})",
+                        params.idempotency_key
+                    ),
+                })
+            }
+            api1_1_0_rc2::oplog::OplogEntry::CancellationAcknowledged(params) => {
+                Self::Log(oplog::LogParameters {
+                    timestamp: params.timestamp,
+                    level: oplog::LogLevel::Info,
+                    context: "cancellation".to_string(),
+                    message: format!("Cancellation of {} acknowledged", params.idempotency_key),
+                })
+            }
         }
     }
 }
@@ -257,6 +357,19 @@ impl From<PublicUpdateDescription> for oplog::UpdateDescription {
     }
 }
 
+impl From<golem_common::model::oplog::CancellationReason>
+    for api1_1_0_rc2::oplog::CancellationReason
+{
+    fn from(value: golem_common::model::oplog::CancellationReason) -> Self {
+        match value {
+            golem_common::model::oplog::CancellationReason::Interrupt => Self::Interrupt,
+            golem_common::model::oplog::CancellationReason::InvocationTimeout => {
+                Self::InvocationTimeout
+            }
+        }
+    }
+}
+
 impl From<golem_common::model::oplog::LogLevel> for oplog::LogLevel {
     fn from(value: golem_common::model::oplog::LogLevel) -> Self {
         match value {
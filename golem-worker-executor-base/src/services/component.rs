@@ -24,6 +24,7 @@ use crate::services::compiled_component::CompiledComponentService;
 use crate::services::golem_config::{
     CompiledComponentServiceConfig, ComponentCacheConfig, ComponentServiceConfig,
 };
+use crate::services::memory_snapshot::supports_memory_snapshots;
 use crate::storage::blob::BlobStorage;
 use async_trait::async_trait;
 use futures_util::TryStreamExt;
@@ -75,6 +76,13 @@ pub trait ComponentService {
         component_id: &ComponentId,
         forced_version: Option<ComponentVersion>,
     ) -> Result<ComponentMetadata, GolemError>;
+
+    /// Checks whether the workers of a component version can be recovered from memory snapshots
+    async fn supports_memory_snapshots(
+        &self,
+        component_id: &ComponentId,
+        component_version: ComponentVersion,
+    ) -> Result<bool, GolemError>;
 }
 
 pub async fn configured(
@@ -120,6 +128,7 @@ struct ComponentKey {
 pub struct ComponentServiceGrpc {
     component_cache: Cache<ComponentKey, (), Component, GolemError>,
     component_metadata_cache: Cache<ComponentKey, (), ComponentMetadata, GolemError>,
+    memory_snapshot_support_cache: Cache<ComponentKey, (), bool, GolemError>,
     access_token: Uuid,
     retry_config: RetryConfig,
     compiled_component_service: Arc<dyn CompiledComponentService + Send + Sync>,
@@ -143,6 +152,10 @@ impl ComponentServiceGrpc {
                 max_metadata_capacity,
                 time_to_idle,
             ),
+            memory_snapshot_support_cache: create_memory_snapshot_support_cache(
+                max_metadata_capacity,
+                time_to_idle,
+            ),
             access_token,
             retry_config: retry_config.clone(),
             compiled_component_service,
@@ -312,6 +325,36 @@ impl ComponentService for ComponentServiceGrpc {
             }
         }
     }
+
+    async fn supports_memory_snapshots(
+        &self,
+        component_id: &ComponentId,
+        component_version: ComponentVersion,
+    ) -> Result<bool, GolemError> {
+        let key = ComponentKey {
+            component_id: component_id.clone(),
+            component_version,
+        };
+        let client = self.client.clone();
+        let access_token = self.access_token;
+        let retry_config = self.retry_config.clone();
+        let component_id = component_id.clone();
+        self.memory_snapshot_support_cache
+            .get_or_insert_simple(&key, || {
+                Box::pin(async move {
+                    let bytes = download_via_grpc(
+                        &client,
+                        &access_token,
+                        &retry_config,
+                        &component_id,
+                        component_version,
+                    )
+                    .await?;
+                    analyse_memory_snapshot_support(component_id, component_version, bytes).await
+                })
+            })
+            .await
+    }
 }
 
 async fn download_via_grpc(
@@ -525,6 +568,37 @@ fn create_component_metadata_cache(
     )
 }
 
+fn create_memory_snapshot_support_cache(
+    max_capacity: usize,
+    time_to_idle: Duration,
+) -> Cache<ComponentKey, (), bool, GolemError> {
+    Cache::new(
+        Some(max_capacity),
+        FullCacheEvictionMode::LeastRecentlyUsed(1),
+        BackgroundEvictionMode::OlderThan {
+            ttl: time_to_idle,
+            period: Duration::from_secs(60),
+        },
+        "memory_snapshot_support",
+    )
+}
+
+async fn analyse_memory_snapshot_support(
+    component_id: ComponentId,
+    component_version: ComponentVersion,
+    bytes: Vec<u8>,
+) -> Result<bool, GolemError> {
+    spawn_blocking(move || {
+        supports_memory_snapshots(&bytes).map_err(|err| GolemError::ComponentParseFailed {
+            component_id,
+            component_version,
+            reason: err.to_string(),
+        })
+    })
+    .await
+    .map_err(|join_err| GolemError::unknown(join_err.to_string()))?
+}
+
 impl From<std::io::Error> for GolemError {
     fn from(value: std::io::Error) -> Self {
         GolemError::Unknown {
@@ -537,6 +611,7 @@ pub struct ComponentServiceLocalFileSystem {
     root: PathBuf,
     component_cache: Cache<ComponentKey, (), Component, GolemError>,
     component_metadata_cache: Cache<ComponentKey, (), ComponentMetadata, GolemError>,
+    memory_snapshot_support_cache: Cache<ComponentKey, (), bool, GolemError>,
     compiled_component_service: Arc<dyn CompiledComponentService + Send + Sync>,
 }
 
@@ -558,10 +633,26 @@ impl ComponentServiceLocalFileSystem {
                 max_metadata_capacity,
                 time_to_idle,
             ),
+            memory_snapshot_support_cache: create_memory_snapshot_support_cache(
+                max_metadata_capacity,
+                time_to_idle,
+            ),
             compiled_component_service,
         }
     }
 
+    fn component_path(&self, component_id: &ComponentId, metadata: &ComponentMetadata) -> PathBuf {
+        let postfix = match metadata.component_type {
+            ComponentType::Ephemeral => "-ephemeral",
+            ComponentType::Durable => "",
+        };
+
+        self.root.join(format!(
+            "{}-{}{postfix}.wasm",
+            component_id, metadata.version
+        ))
+    }
+
     async fn get_from_path(
         &self,
         path: &Path,
@@ -786,15 +877,7 @@ impl ComponentService for ComponentServiceLocalFileSystem {
             .get_metadata(component_id, Some(component_version))
             .await?;
 
-        let postfix = match metadata.component_type {
-            ComponentType::Ephemeral => "-ephemeral",
-            ComponentType::Durable => "",
-        };
-
-        let path = self.root.join(format!(
-            "{}-{}{postfix}.wasm",
-            component_id, component_version
-        ));
+        let path = self.component_path(component_id, &metadata);
 
         Ok((
             self.get_from_path(&path, engine, component_id, component_version)
@@ -838,4 +921,28 @@ impl ComponentService for ComponentServiceLocalFileSystem {
             }
         }
     }
+
+    async fn supports_memory_snapshots(
+        &self,
+        component_id: &ComponentId,
+        component_version: ComponentVersion,
+    ) -> Result<bool, GolemError> {
+        let metadata = self
+            .get_metadata(component_id, Some(component_version))
+            .await?;
+        let path = self.component_path(component_id, &metadata);
+        let key = ComponentKey {
+            component_id: component_id.clone(),
+            component_version,
+        };
+        let component_id = component_id.clone();
+        self.memory_snapshot_support_cache
+            .get_or_insert_simple(&key, || {
+                Box::pin(async move {
+                    let bytes = tokio::fs::read(path).await?;
+                    analyse_memory_snapshot_support(component_id, component_version, bytes).await
+                })
+            })
+            .await
+    }
 }
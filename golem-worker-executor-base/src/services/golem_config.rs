@@ -43,11 +43,13 @@ pub struct GolemConfig {
     pub compiled_component_service: CompiledComponentServiceConfig,
     pub shard_manager_service: ShardManagerServiceConfig,
     pub oplog: OplogConfig,
+    pub oplog_migration: OplogMigrationConfig,
     pub suspend: SuspendConfig,
     pub active_workers: ActiveWorkersConfig,
     pub scheduler: SchedulerConfig,
     pub public_worker_api: WorkerServiceGrpcConfig,
     pub memory: MemoryConfig,
+    pub memory_snapshot: MemorySnapshotConfig,
     pub grpc_address: String,
     pub port: u16,
     pub http_address: String,
@@ -65,6 +67,13 @@ pub struct Limits {
     #[serde(with = "humantime_serde")]
     pub epoch_interval: Duration,
     pub epoch_ticks: u64,
+    /// If set, cooperative cancellation is requested for invocations running longer than this
+    #[serde(with = "humantime_serde")]
+    pub invocation_timeout: Option<Duration>,
+    /// Time given to an invocation to observe a cancellation request before the worker gets
+    /// interrupted. Zero disables cooperative cancellation of interrupts.
+    #[serde(with = "humantime_serde")]
+    pub cancellation_grace_period: Duration,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -248,6 +257,25 @@ pub struct OplogConfig {
     pub archive_interval: Duration,
 }
 
+/// Migration of the oplogs to a new storage layout. While enabled, oplog writes go to both
+/// the layout configured in `oplog` and the target layout described here.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OplogMigrationConfig {
+    pub enabled: bool,
+    /// Indexed storage of the target layout, must not share keys with `indexed_storage`
+    pub target_indexed_storage: IndexedStorageConfig,
+    pub target_indexed_storage_layers: usize,
+    /// Archive layers of the target layout in the shared blob storage, stored at the levels
+    /// following the `oplog.blob_storage_layers` levels of the source layout
+    pub target_blob_storage_layers: usize,
+    /// Compare every read with the non-authoritative layout
+    pub verify_reads: bool,
+    /// Shards whose oplogs are served by the target layout on startup. Shards can be cut over
+    /// and rolled back at runtime on the HTTP port, with `POST` and `DELETE` requests to
+    /// `/oplog-migration/cutover-shards/{shard}`
+    pub cutover_shards: Vec<i64>,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(tag = "type", content = "config")]
 pub enum KeyValueStorageConfig {
@@ -283,6 +311,7 @@ pub struct S3BlobStorageConfig {
     pub custom_data_bucket: String,
     pub oplog_payload_bucket: String,
     pub compressed_oplog_buckets: Vec<String>,
+    pub memory_snapshot_bucket: String,
     pub use_minio_credentials: bool,
 }
 
@@ -301,6 +330,22 @@ pub struct MemoryConfig {
     pub oom_retry_config: RetryConfig,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MemorySnapshotConfig {
+    /// Snapshots the linear memories of durable workers after successful invocations, and
+    /// recovers them from the last snapshot instead of replaying the oplog up to it. Only used
+    /// for workers whose whole state is in their linear memories, all others are recovered by
+    /// replaying their oplog.
+    pub enabled: bool,
+    /// Size of the pages compared between two snapshots, in bytes
+    pub page_size: usize,
+    /// Number of delta snapshots written before a new full snapshot is taken
+    pub full_snapshot_interval: u64,
+    /// Minimum time between two snapshots of the same worker
+    #[serde(with = "humantime_serde")]
+    pub min_interval: Duration,
+}
+
 impl MemoryConfig {
     pub fn total_system_memory(&self) -> u64 {
         self.system_memory_override.unwrap_or_else(|| {
@@ -336,11 +381,13 @@ impl Default for GolemConfig {
             compiled_component_service: CompiledComponentServiceConfig::default(),
             shard_manager_service: ShardManagerServiceConfig::default(),
             oplog: OplogConfig::default(),
+            oplog_migration: OplogMigrationConfig::default(),
             suspend: SuspendConfig::default(),
             scheduler: SchedulerConfig::default(),
             active_workers: ActiveWorkersConfig::default(),
             public_worker_api: WorkerServiceGrpcConfig::default(),
             memory: MemoryConfig::default(),
+            memory_snapshot: MemorySnapshotConfig::default(),
             grpc_address: "0.0.0.0".to_string(),
             port: 9000,
             http_address: "0.0.0.0".to_string(),
@@ -386,6 +433,8 @@ impl Default for Limits {
             fuel_to_borrow: 10000,
             epoch_interval: Duration::from_millis(10),
             epoch_ticks: 1,
+            invocation_timeout: None,
+            cancellation_grace_period: Duration::from_secs(0),
         }
     }
 }
@@ -445,6 +494,7 @@ impl Default for S3BlobStorageConfig {
             object_prefix: "".to_string(),
             aws_endpoint_url: None,
             compressed_oplog_buckets: vec!["oplog-archive-1".to_string()],
+            memory_snapshot_bucket: "memory-snapshots".to_string(),
             use_minio_credentials: false,
         }
     }
@@ -488,6 +538,19 @@ impl Default for OplogConfig {
     }
 }
 
+impl Default for OplogMigrationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            target_indexed_storage: IndexedStorageConfig::InMemory,
+            target_indexed_storage_layers: 2,
+            target_blob_storage_layers: 1,
+            verify_reads: true,
+            cutover_shards: vec![],
+        }
+    }
+}
+
 impl Default for SuspendConfig {
     fn default() -> Self {
         Self {
@@ -579,6 +642,17 @@ impl Default for MemoryConfig {
     }
 }
 
+impl Default for MemorySnapshotConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            page_size: 65536,
+            full_snapshot_interval: 16,
+            min_interval: Duration::from_secs(10),
+        }
+    }
+}
+
 pub fn make_config_loader() -> ConfigLoader<GolemConfig> {
     ConfigLoader::new_with_examples(Path::new("config/worker-executor.toml"))
 }
@@ -0,0 +1,963 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::hash::Hasher;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bincode::{Decode, Encode};
+use dashmap::DashMap;
+use golem_common::model::oplog::{OplogEntry, OplogIndex};
+use golem_common::model::{ComponentVersion, OwnedWorkerId, Timestamp};
+use metrohash::MetroHash128;
+use tokio::task::spawn_blocking;
+use tracing::{debug, warn};
+use wasmparser::{
+    BinaryReaderError, CanonicalFunction, ComponentDefinedType, ComponentType,
+    ComponentTypeDeclaration, InstanceTypeDeclaration, Operator, Parser, Payload,
+};
+
+use crate::error::GolemError;
+use crate::services::golem_config::MemorySnapshotConfig;
+use crate::storage::blob::{
+    BlobStorage, BlobStorageLabelledApi, BlobStorageNamespace, ExistsResult,
+};
+
+/// Service for storing snapshots of a worker's linear memories.
+///
+/// The first snapshot of a worker (and every `full_snapshot_interval`-th one after it) stores
+/// the whole memories; the ones in between only store the pages that changed since the previous
+/// snapshot, detected by comparing per-page hashes.
+///
+/// A snapshot only replaces replaying the oplog if the linear memories are the complete state of
+/// the worker, see [supports_memory_snapshots] and [is_restorable_entry].
+#[async_trait]
+pub trait MemorySnapshotService {
+    /// Stores a new snapshot of the worker's linear memories
+    async fn save(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        snapshot: LinearMemorySnapshot,
+    ) -> Result<(), GolemError>;
+
+    /// Reconstructs the linear memories from the last full snapshot and the deltas following it
+    async fn restore(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<Option<LinearMemorySnapshot>, GolemError>;
+
+    /// Deletes all snapshots of the worker
+    async fn delete(&self, owned_worker_id: &OwnedWorkerId) -> Result<(), GolemError>;
+}
+
+/// The contents of all linear memories of a worker right after the oplog entry at `oplog_index`
+/// has been written, taken with the given component version
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearMemorySnapshot {
+    /// Timestamp of the worker's `Create` oplog entry, so the snapshots of a deleted worker are
+    /// never used for a new one with the same id
+    pub created_at: Timestamp,
+    pub oplog_index: OplogIndex,
+    pub component_version: ComponentVersion,
+    pub memories: Vec<Vec<u8>>,
+}
+
+/// Checks whether restoring the linear memories of a fresh instance of the component restores
+/// the whole instance. This is the case if no core module writes globals, modifies tables or
+/// drops segments, and the component does not use resource handles, whose tables are not part of
+/// the linear memories either.
+pub fn supports_memory_snapshots(component: &[u8]) -> Result<bool, BinaryReaderError> {
+    for payload in Parser::new(0).parse_all(component) {
+        match payload? {
+            Payload::CodeSectionEntry(body) => {
+                for operator in body.get_operators_reader()? {
+                    if matches!(
+                        operator?,
+                        Operator::GlobalSet { .. }
+                            | Operator::TableSet { .. }
+                            | Operator::TableGrow { .. }
+                            | Operator::TableFill { .. }
+                            | Operator::TableCopy { .. }
+                            | Operator::TableInit { .. }
+                            | Operator::ElemDrop { .. }
+                            | Operator::DataDrop { .. }
+                    ) {
+                        return Ok(false);
+                    }
+                }
+            }
+            Payload::ComponentTypeSection(reader) => {
+                for component_type in reader {
+                    if uses_resources(&component_type?) {
+                        return Ok(false);
+                    }
+                }
+            }
+            Payload::ComponentCanonicalSection(reader) => {
+                for function in reader {
+                    if matches!(
+                        function?,
+                        CanonicalFunction::ResourceNew { .. }
+                            | CanonicalFunction::ResourceDrop { .. }
+                            | CanonicalFunction::ResourceRep { .. }
+                    ) {
+                        return Ok(false);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(true)
+}
+
+fn uses_resources(component_type: &ComponentType) -> bool {
+    match component_type {
+        ComponentType::Resource { .. }
+        | ComponentType::Defined(ComponentDefinedType::Own(_) | ComponentDefinedType::Borrow(_)) => {
+            true
+        }
+        ComponentType::Component(declarations) => {
+            declarations.iter().any(|declaration| match declaration {
+                ComponentTypeDeclaration::Type(component_type) => uses_resources(component_type),
+                _ => false,
+            })
+        }
+        ComponentType::Instance(declarations) => {
+            declarations.iter().any(|declaration| match declaration {
+                InstanceTypeDeclaration::Type(component_type) => uses_resources(component_type),
+                _ => false,
+            })
+        }
+        ComponentType::Defined(_) | ComponentType::Func(_) => false,
+    }
+}
+
+/// Checks whether skipping the replay of the oplog entry leaves no state behind that the linear
+/// memories do not contain. Host function calls do not qualify, as they may have created host
+/// side resources or changed the durability settings of the worker.
+pub fn is_restorable_entry(entry: &OplogEntry) -> bool {
+    matches!(
+        entry,
+        OplogEntry::Create { .. }
+            | OplogEntry::ExportedFunctionInvoked { .. }
+            | OplogEntry::ExportedFunctionCompleted { .. }
+            | OplogEntry::Suspend { .. }
+            | OplogEntry::Error { .. }
+            | OplogEntry::NoOp { .. }
+            | OplogEntry::Interrupted { .. }
+            | OplogEntry::PendingWorkerInvocation { .. }
+            | OplogEntry::GrowMemory { .. }
+            | OplogEntry::Log { .. }
+            | OplogEntry::Restart { .. }
+            | OplogEntry::CancellationRequested { .. }
+    )
+}
+
+/// Hashes of the pages of each memory
+type PageHashes = Vec<Vec<(u64, u64)>>;
+
+/// Changed pages as (memory index, page index, contents)
+type Pages = Vec<(u32, u64, Vec<u8>)>;
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+enum MemorySnapshot {
+    Full {
+        created_at: Timestamp,
+        oplog_index: OplogIndex,
+        component_version: ComponentVersion,
+        memories: Vec<Vec<u8>>,
+    },
+    Delta {
+        /// Oplog index of the full snapshot the delta belongs to. Deltas left behind by a
+        /// previous full snapshot are ignored.
+        base: OplogIndex,
+        oplog_index: OplogIndex,
+        component_version: ComponentVersion,
+        memory_sizes: Vec<u64>,
+        pages: Pages,
+    },
+}
+
+#[derive(Debug, Clone)]
+struct SnapshotState {
+    base: OplogIndex,
+    component_version: ComponentVersion,
+    memory_sizes: Vec<usize>,
+    page_hashes: PageHashes,
+    deltas: u64,
+}
+
+impl SnapshotState {
+    /// A delta can only describe the new memories if their layout is compatible with the previous ones
+    fn accepts_delta(&self, snapshot: &LinearMemorySnapshot) -> bool {
+        self.component_version == snapshot.component_version
+            && self.memory_sizes.len() == snapshot.memories.len()
+            && self
+                .memory_sizes
+                .iter()
+                .zip(&snapshot.memories)
+                .all(|(previous_size, memory)| memory.len() >= *previous_size)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DefaultMemorySnapshotService {
+    blob_storage: Arc<dyn BlobStorage + Send + Sync>,
+    config: MemorySnapshotConfig,
+    states: Arc<DashMap<OwnedWorkerId, SnapshotState>>,
+}
+
+impl DefaultMemorySnapshotService {
+    pub fn new(
+        blob_storage: Arc<dyn BlobStorage + Send + Sync>,
+        config: MemorySnapshotConfig,
+    ) -> Self {
+        Self {
+            blob_storage,
+            config,
+            states: Arc::new(DashMap::new()),
+        }
+    }
+
+    fn namespace(owned_worker_id: &OwnedWorkerId) -> BlobStorageNamespace {
+        BlobStorageNamespace::MemorySnapshot {
+            account_id: owned_worker_id.account_id(),
+            worker_id: owned_worker_id.worker_id(),
+        }
+    }
+
+    fn path(idx: u64) -> PathBuf {
+        Path::new(&idx.to_string()).to_path_buf()
+    }
+
+    fn page_hashes(&self, memories: &[Vec<u8>]) -> PageHashes {
+        memories
+            .iter()
+            .map(|memory| {
+                memory
+                    .chunks(self.config.page_size)
+                    .map(|page| {
+                        let mut hasher = MetroHash128::new();
+                        hasher.write(page);
+                        hasher.finish128()
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Hashes the pages of the snapshot and collects the ones that changed since `previous`,
+    /// on a blocking thread as it goes through the whole memories
+    async fn diff(
+        &self,
+        snapshot: LinearMemorySnapshot,
+        previous: Option<SnapshotState>,
+    ) -> Result<(LinearMemorySnapshot, PageHashes, Option<Pages>), GolemError> {
+        let service = self.clone();
+        spawn_blocking(move || {
+            let page_hashes = service.page_hashes(&snapshot.memories);
+            let pages =
+                previous.map(|previous| service.changed_pages(&previous, &snapshot, &page_hashes));
+            (snapshot, page_hashes, pages)
+        })
+        .await
+        .map_err(|err| GolemError::unknown(err.to_string()))
+    }
+
+    fn state_of(
+        snapshot: &LinearMemorySnapshot,
+        page_hashes: PageHashes,
+        base: OplogIndex,
+        deltas: u64,
+    ) -> SnapshotState {
+        SnapshotState {
+            base,
+            component_version: snapshot.component_version,
+            memory_sizes: snapshot
+                .memories
+                .iter()
+                .map(|memory| memory.len())
+                .collect(),
+            page_hashes,
+            deltas,
+        }
+    }
+
+    async fn get(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        idx: u64,
+    ) -> Result<Option<MemorySnapshot>, GolemError> {
+        self.blob_storage
+            .with("memory_snapshot", "get")
+            .get(Self::namespace(owned_worker_id), &Self::path(idx))
+            .await
+            .map_err(|err| {
+                GolemError::runtime(format!(
+                    "Failed to read memory snapshot {idx} of {}: {err}",
+                    owned_worker_id.worker_id
+                ))
+            })
+    }
+
+    async fn put(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        idx: u64,
+        snapshot: &MemorySnapshot,
+    ) -> Result<(), GolemError> {
+        self.blob_storage
+            .with("memory_snapshot", "put")
+            .put(Self::namespace(owned_worker_id), &Self::path(idx), snapshot)
+            .await
+            .map_err(|err| {
+                GolemError::runtime(format!(
+                    "Failed to write memory snapshot {idx} of {}: {err}",
+                    owned_worker_id.worker_id
+                ))
+            })
+    }
+
+    /// Deletes the snapshots at the given positions
+    async fn delete_range(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        first: u64,
+        last: u64,
+    ) -> Result<(), GolemError> {
+        if first <= last {
+            let paths = (first..=last).map(Self::path).collect::<Vec<_>>();
+            self.blob_storage
+                .with("memory_snapshot", "delete")
+                .delete_many(Self::namespace(owned_worker_id), &paths)
+                .await
+                .map_err(|err| {
+                    GolemError::runtime(format!(
+                        "Failed to delete memory snapshots of {}: {err}",
+                        owned_worker_id.worker_id
+                    ))
+                })?;
+        }
+        Ok(())
+    }
+
+    /// Finds the position of the last snapshot stored without a gap after position `after`
+    async fn last_stored(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        after: u64,
+    ) -> Result<u64, GolemError> {
+        let mut last = after;
+        loop {
+            let exists = self
+                .blob_storage
+                .with("memory_snapshot", "exists")
+                .exists(Self::namespace(owned_worker_id), &Self::path(last + 1))
+                .await
+                .map_err(|err| {
+                    GolemError::runtime(format!(
+                        "Failed to check memory snapshot {} of {}: {err}",
+                        last + 1,
+                        owned_worker_id.worker_id
+                    ))
+                })?;
+            if exists == ExistsResult::DoesNotExist {
+                break Ok(last);
+            }
+            last += 1;
+        }
+    }
+
+    /// Deletes the deltas stored from position `first` on, logging failures as they are ignored
+    /// by `restore` anyway
+    async fn delete_stale_deltas(&self, owned_worker_id: &OwnedWorkerId, first: u64) {
+        let result = match self.last_stored(owned_worker_id, first - 1).await {
+            Ok(last) => self.delete_range(owned_worker_id, first, last).await,
+            Err(err) => Err(err),
+        };
+        if let Err(err) = result {
+            warn!("Failed to delete stale memory snapshot deltas: {err}");
+        }
+    }
+
+    fn changed_pages(
+        &self,
+        previous: &SnapshotState,
+        snapshot: &LinearMemorySnapshot,
+        page_hashes: &[Vec<(u64, u64)>],
+    ) -> Pages {
+        let mut pages = Vec::new();
+        for (memory_idx, (memory, hashes)) in snapshot.memories.iter().zip(page_hashes).enumerate()
+        {
+            let previous_hashes = &previous.page_hashes[memory_idx];
+            for (page_idx, hash) in hashes.iter().enumerate() {
+                if previous_hashes.get(page_idx) != Some(hash) {
+                    let start = page_idx * self.config.page_size;
+                    let end = (start + self.config.page_size).min(memory.len());
+                    pages.push((
+                        memory_idx as u32,
+                        page_idx as u64,
+                        memory[start..end].to_vec(),
+                    ));
+                }
+            }
+        }
+        pages
+    }
+
+    /// Applies a delta on top of the memories, failing instead of panicking if the stored delta
+    /// does not fit them
+    fn apply_delta(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        memories: &mut [Vec<u8>],
+        memory_sizes: Vec<u64>,
+        pages: Pages,
+    ) -> Result<(), GolemError> {
+        let invalid = |details: String| {
+            GolemError::unknown(format!(
+                "Invalid memory snapshot delta for {}: {details}",
+                owned_worker_id.worker_id
+            ))
+        };
+
+        if memory_sizes.len() != memories.len() {
+            return Err(invalid(format!(
+                "it describes {} memories instead of {}",
+                memory_sizes.len(),
+                memories.len()
+            )));
+        }
+        for (memory, size) in memories.iter_mut().zip(memory_sizes) {
+            let size = usize::try_from(size)
+                .map_err(|_| invalid(format!("memory size {size} is too large")))?;
+            memory.resize(size, 0);
+        }
+
+        for (memory_idx, page_idx, page) in pages {
+            let memory = memories
+                .get_mut(memory_idx as usize)
+                .ok_or_else(|| invalid(format!("memory {memory_idx} does not exist")))?;
+            let range = usize::try_from(page_idx)
+                .ok()
+                .and_then(|page_idx| page_idx.checked_mul(self.config.page_size))
+                .and_then(|start| Some(start..start.checked_add(page.len())?))
+                .ok_or_else(|| invalid(format!("page {page_idx} is out of range")))?;
+            memory
+                .get_mut(range)
+                .ok_or_else(|| {
+                    invalid(format!(
+                        "page {page_idx} does not fit in memory {memory_idx}"
+                    ))
+                })?
+                .copy_from_slice(&page);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl MemorySnapshotService for DefaultMemorySnapshotService {
+    async fn save(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        snapshot: LinearMemorySnapshot,
+    ) -> Result<(), GolemError> {
+        let previous = self.states.get(owned_worker_id).map(|state| state.clone());
+        let (delta_of, previous) = match previous {
+            Some(previous)
+                if previous.deltas < self.config.full_snapshot_interval
+                    && previous.accepts_delta(&snapshot) =>
+            {
+                (Some(previous), None)
+            }
+            previous => (None, previous),
+        };
+
+        let (snapshot, page_hashes, pages) = self.diff(snapshot, delta_of.clone()).await?;
+
+        let state = match (delta_of, pages) {
+            (Some(delta_of), Some(pages)) => {
+                let state =
+                    Self::state_of(&snapshot, page_hashes, delta_of.base, delta_of.deltas + 1);
+
+                debug!(
+                    "Storing memory snapshot delta {} of {} with {} changed pages",
+                    state.deltas,
+                    owned_worker_id.worker_id,
+                    pages.len()
+                );
+                self.put(
+                    owned_worker_id,
+                    state.deltas,
+                    &MemorySnapshot::Delta {
+                        base: delta_of.base,
+                        oplog_index: snapshot.oplog_index,
+                        component_version: snapshot.component_version,
+                        memory_sizes: snapshot
+                            .memories
+                            .iter()
+                            .map(|memory| memory.len() as u64)
+                            .collect(),
+                        pages,
+                    },
+                )
+                .await?;
+
+                state
+            }
+            _ => {
+                let previous_deltas = match previous {
+                    Some(previous) => previous.deltas,
+                    None => self.last_stored(owned_worker_id, 0).await?,
+                };
+
+                debug!(
+                    "Storing full memory snapshot of {} ({} bytes)",
+                    owned_worker_id.worker_id,
+                    snapshot
+                        .memories
+                        .iter()
+                        .map(|memory| memory.len())
+                        .sum::<usize>()
+                );
+                self.put(
+                    owned_worker_id,
+                    0,
+                    &MemorySnapshot::Full {
+                        created_at: snapshot.created_at,
+                        oplog_index: snapshot.oplog_index,
+                        component_version: snapshot.component_version,
+                        memories: snapshot.memories.clone(),
+                    },
+                )
+                .await?;
+                // The previous deltas belong to the replaced full snapshot, so restoring ignores
+                // them even if deleting them fails
+                if let Err(err) = self.delete_range(owned_worker_id, 1, previous_deltas).await {
+                    warn!("Failed to delete previous memory snapshot deltas: {err}");
+                }
+
+                Self::state_of(&snapshot, page_hashes, snapshot.oplog_index, 0)
+            }
+        };
+
+        self.states.insert(owned_worker_id.clone(), state);
+        Ok(())
+    }
+
+    async fn restore(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<Option<LinearMemorySnapshot>, GolemError> {
+        let mut snapshot = match self.get(owned_worker_id, 0).await? {
+            Some(MemorySnapshot::Full {
+                created_at,
+                oplog_index,
+                component_version,
+                memories,
+            }) => LinearMemorySnapshot {
+                created_at,
+                oplog_index,
+                component_version,
+                memories,
+            },
+            Some(MemorySnapshot::Delta { .. }) => {
+                return Err(GolemError::unknown(format!(
+                    "Memory snapshot chain of {} does not start with a full snapshot",
+                    owned_worker_id.worker_id
+                )))
+            }
+            None => return Ok(None),
+        };
+        let base = snapshot.oplog_index;
+
+        let mut deltas = 0;
+        loop {
+            match self.get(owned_worker_id, deltas + 1).await? {
+                Some(MemorySnapshot::Delta {
+                    base: delta_base,
+                    oplog_index,
+                    component_version,
+                    memory_sizes,
+                    pages,
+                }) if delta_base == base => {
+                    if component_version != snapshot.component_version {
+                        return Err(GolemError::unknown(format!(
+                            "Memory snapshot delta {} of {} was taken with component version {component_version} instead of {}",
+                            deltas + 1,
+                            owned_worker_id.worker_id,
+                            snapshot.component_version
+                        )));
+                    }
+                    self.apply_delta(owned_worker_id, &mut snapshot.memories, memory_sizes, pages)?;
+                    snapshot.oplog_index = oplog_index;
+                    deltas += 1;
+                }
+                Some(MemorySnapshot::Delta { .. }) => {
+                    debug!(
+                        "Ignoring memory snapshot deltas of {} from {} on, left behind by a replaced full snapshot",
+                        owned_worker_id.worker_id,
+                        deltas + 1
+                    );
+                    self.delete_stale_deltas(owned_worker_id, deltas + 1).await;
+                    break;
+                }
+                Some(MemorySnapshot::Full { .. }) => {
+                    return Err(GolemError::unknown(format!(
+                        "Unexpected full memory snapshot at position {} for {}",
+                        deltas + 1,
+                        owned_worker_id.worker_id
+                    )))
+                }
+                None => break,
+            }
+        }
+
+        let (snapshot, page_hashes, _) = self.diff(snapshot, None).await?;
+        self.states.insert(
+            owned_worker_id.clone(),
+            Self::state_of(&snapshot, page_hashes, base, deltas),
+        );
+        Ok(Some(snapshot))
+    }
+
+    async fn delete(&self, owned_worker_id: &OwnedWorkerId) -> Result<(), GolemError> {
+        self.states.remove(owned_worker_id);
+        // Probing the storage also finds the deltas left behind by a replaced full snapshot
+        let last = self.last_stored(owned_worker_id, 0).await?;
+        self.delete_range(owned_worker_id, 1, last).await?;
+
+        let error = |err: String| {
+            GolemError::runtime(format!(
+                "Failed to delete memory snapshot of {}: {err}",
+                owned_worker_id.worker_id
+            ))
+        };
+        let exists = self
+            .blob_storage
+            .with("memory_snapshot", "delete")
+            .exists(Self::namespace(owned_worker_id), &Self::path(0))
+            .await
+            .map_err(error)?;
+        if exists != ExistsResult::DoesNotExist {
+            self.blob_storage
+                .with("memory_snapshot", "delete")
+                .delete(Self::namespace(owned_worker_id), &Self::path(0))
+                .await
+                .map_err(error)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use std::path::Path;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use golem_common::model::oplog::OplogIndex;
+    use golem_common::model::{AccountId, ComponentId, OwnedWorkerId, Timestamp, WorkerId};
+    use uuid::Uuid;
+
+    use crate::services::golem_config::MemorySnapshotConfig;
+    use crate::services::memory_snapshot::{
+        DefaultMemorySnapshotService, LinearMemorySnapshot, MemorySnapshot, MemorySnapshotService,
+    };
+    use crate::storage::blob::memory::InMemoryBlobStorage;
+    use crate::storage::blob::BlobStorageLabelledApi;
+
+    fn owned_worker_id() -> OwnedWorkerId {
+        OwnedWorkerId {
+            account_id: AccountId {
+                value: "test-account".to_string(),
+            },
+            worker_id: WorkerId {
+                component_id: ComponentId(Uuid::new_v4()),
+                worker_name: "test-worker".to_string(),
+            },
+        }
+    }
+
+    fn service(
+        blob_storage: Arc<InMemoryBlobStorage>,
+        full_snapshot_interval: u64,
+    ) -> DefaultMemorySnapshotService {
+        DefaultMemorySnapshotService::new(
+            blob_storage,
+            MemorySnapshotConfig {
+                enabled: true,
+                page_size: 4,
+                full_snapshot_interval,
+                min_interval: Duration::ZERO,
+            },
+        )
+    }
+
+    fn snapshot(oplog_index: u64, memories: &[&[u8]]) -> LinearMemorySnapshot {
+        LinearMemorySnapshot {
+            created_at: Timestamp::from(1000),
+            oplog_index: OplogIndex::from_u64(oplog_index),
+            component_version: 0,
+            memories: memories.iter().map(|memory| memory.to_vec()).collect(),
+        }
+    }
+
+    async fn stored(
+        blob_storage: &InMemoryBlobStorage,
+        owned_worker_id: &OwnedWorkerId,
+        idx: u64,
+    ) -> Option<MemorySnapshot> {
+        blob_storage
+            .with("test", "get")
+            .get(
+                DefaultMemorySnapshotService::namespace(owned_worker_id),
+                Path::new(&idx.to_string()),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    async fn delta_contains_only_changed_pages() {
+        let blob_storage = Arc::new(InMemoryBlobStorage::new());
+        let service = service(blob_storage.clone(), 16);
+        let owned_worker_id = owned_worker_id();
+
+        let mut memory = vec![0u8; 16];
+        let other = vec![7u8; 8];
+        service
+            .save(&owned_worker_id, snapshot(3, &[&memory, &other]))
+            .await
+            .unwrap();
+
+        memory[5] = 1;
+        memory.extend_from_slice(&[2, 2]);
+        service
+            .save(&owned_worker_id, snapshot(8, &[&memory, &other]))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            stored(&blob_storage, &owned_worker_id, 1).await,
+            Some(MemorySnapshot::Delta {
+                base: OplogIndex::from_u64(3),
+                oplog_index: OplogIndex::from_u64(8),
+                component_version: 0,
+                memory_sizes: vec![18, 8],
+                pages: vec![(0, 1, vec![0, 1, 0, 0]), (0, 4, vec![2, 2])],
+            })
+        );
+
+        let restored = service.restore(&owned_worker_id).await.unwrap();
+        assert_eq!(restored, Some(snapshot(8, &[&memory, &other])));
+    }
+
+    #[test]
+    async fn restore_from_storage_with_new_service() {
+        let blob_storage = Arc::new(InMemoryBlobStorage::new());
+        let owned_worker_id = owned_worker_id();
+
+        let mut memory = vec![0u8; 12];
+        {
+            let service = service(blob_storage.clone(), 16);
+            for i in 0..3 {
+                memory[i * 4] = i as u8 + 1;
+                service
+                    .save(&owned_worker_id, snapshot(i as u64 + 1, &[&memory]))
+                    .await
+                    .unwrap();
+            }
+        }
+
+        let service = service(blob_storage.clone(), 16);
+        let restored = service.restore(&owned_worker_id).await.unwrap();
+        assert_eq!(restored, Some(snapshot(3, &[&memory])));
+
+        memory[0] = 42;
+        service
+            .save(&owned_worker_id, snapshot(4, &[&memory]))
+            .await
+            .unwrap();
+        assert_eq!(
+            stored(&blob_storage, &owned_worker_id, 3).await,
+            Some(MemorySnapshot::Delta {
+                base: OplogIndex::from_u64(1),
+                oplog_index: OplogIndex::from_u64(4),
+                component_version: 0,
+                memory_sizes: vec![12],
+                pages: vec![(0, 0, vec![42, 0, 0, 0])],
+            })
+        );
+    }
+
+    #[test]
+    async fn periodic_full_snapshot_drops_previous_deltas() {
+        let blob_storage = Arc::new(InMemoryBlobStorage::new());
+        let service = service(blob_storage.clone(), 2);
+        let owned_worker_id = owned_worker_id();
+
+        let mut memory = vec![0u8; 8];
+        for i in 0..4 {
+            memory[0] = i;
+            service
+                .save(&owned_worker_id, snapshot(i as u64 + 1, &[&memory]))
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(
+            stored(&blob_storage, &owned_worker_id, 0).await,
+            Some(MemorySnapshot::Full {
+                created_at: Timestamp::from(1000),
+                oplog_index: OplogIndex::from_u64(4),
+                component_version: 0,
+                memories: vec![vec![3, 0, 0, 0, 0, 0, 0, 0]]
+            })
+        );
+        assert_eq!(stored(&blob_storage, &owned_worker_id, 1).await, None);
+        assert_eq!(
+            service.restore(&owned_worker_id).await.unwrap(),
+            Some(snapshot(4, &[&memory]))
+        );
+    }
+
+    #[test]
+    async fn deltas_of_a_replaced_full_snapshot_are_ignored() {
+        let blob_storage = Arc::new(InMemoryBlobStorage::new());
+        let owned_worker_id = owned_worker_id();
+        {
+            let service = service(blob_storage.clone(), 16);
+            service
+                .save(&owned_worker_id, snapshot(1, &[&[1; 8]]))
+                .await
+                .unwrap();
+            service
+                .save(&owned_worker_id, snapshot(2, &[&[2; 8]]))
+                .await
+                .unwrap();
+        }
+
+        // A full snapshot replaced the previous one, but its deltas were not deleted
+        blob_storage
+            .with("test", "put")
+            .put(
+                DefaultMemorySnapshotService::namespace(&owned_worker_id),
+                Path::new("0"),
+                &MemorySnapshot::Full {
+                    created_at: Timestamp::from(1000),
+                    oplog_index: OplogIndex::from_u64(5),
+                    component_version: 0,
+                    memories: vec![vec![5; 8]],
+                },
+            )
+            .await
+            .unwrap();
+
+        let service = service(blob_storage.clone(), 16);
+        assert_eq!(
+            service.restore(&owned_worker_id).await.unwrap(),
+            Some(snapshot(5, &[&[5; 8]]))
+        );
+        assert_eq!(stored(&blob_storage, &owned_worker_id, 1).await, None);
+    }
+
+    #[test]
+    async fn new_component_version_starts_a_full_snapshot() {
+        let blob_storage = Arc::new(InMemoryBlobStorage::new());
+        let service = service(blob_storage.clone(), 16);
+        let owned_worker_id = owned_worker_id();
+
+        service
+            .save(&owned_worker_id, snapshot(1, &[&[1; 8]]))
+            .await
+            .unwrap();
+        let updated = LinearMemorySnapshot {
+            component_version: 1,
+            ..snapshot(5, &[&[2; 8]])
+        };
+        service
+            .save(&owned_worker_id, updated.clone())
+            .await
+            .unwrap();
+
+        assert_eq!(stored(&blob_storage, &owned_worker_id, 1).await, None);
+        assert_eq!(
+            service.restore(&owned_worker_id).await.unwrap(),
+            Some(updated)
+        );
+    }
+
+    #[test]
+    async fn out_of_bounds_delta_fails_to_restore() {
+        let blob_storage = Arc::new(InMemoryBlobStorage::new());
+        let service = service(blob_storage.clone(), 16);
+        let owned_worker_id = owned_worker_id();
+
+        service
+            .save(&owned_worker_id, snapshot(1, &[&[0; 8]]))
+            .await
+            .unwrap();
+        blob_storage
+            .with("test", "put")
+            .put(
+                DefaultMemorySnapshotService::namespace(&owned_worker_id),
+                Path::new("1"),
+                &MemorySnapshot::Delta {
+                    base: OplogIndex::from_u64(1),
+                    oplog_index: OplogIndex::from_u64(2),
+                    component_version: 0,
+                    memory_sizes: vec![8],
+                    pages: vec![(0, 2, vec![1, 1, 1, 1])],
+                },
+            )
+            .await
+            .unwrap();
+
+        assert!(service.restore(&owned_worker_id).await.is_err());
+    }
+
+    #[test]
+    async fn delete_without_snapshots_succeeds() {
+        let blob_storage = Arc::new(InMemoryBlobStorage::new());
+        let service = service(blob_storage.clone(), 16);
+
+        service.delete(&owned_worker_id()).await.unwrap();
+    }
+
+    #[test]
+    async fn delete_removes_all_snapshots() {
+        let blob_storage = Arc::new(InMemoryBlobStorage::new());
+        let service = service(blob_storage.clone(), 16);
+        let owned_worker_id = owned_worker_id();
+
+        service
+            .save(&owned_worker_id, snapshot(1, &[&[1; 8]]))
+            .await
+            .unwrap();
+        service
+            .save(&owned_worker_id, snapshot(2, &[&[2; 8]]))
+            .await
+            .unwrap();
+        service.delete(&owned_worker_id).await.unwrap();
+
+        assert_eq!(service.restore(&owned_worker_id).await.unwrap(), None);
+        assert_eq!(stored(&blob_storage, &owned_worker_id, 1).await, None);
+    }
+}
@@ -26,6 +26,7 @@ pub mod component;
 pub mod events;
 pub mod golem_config;
 pub mod key_value;
+pub mod memory_snapshot;
 pub mod oplog;
 pub mod promise;
 pub mod rpc;
@@ -106,6 +107,12 @@ pub trait HasSchedulerService {
     fn scheduler_service(&self) -> Arc<dyn scheduler::SchedulerService + Send + Sync>;
 }
 
+pub trait HasMemorySnapshotService {
+    fn memory_snapshot_service(
+        &self,
+    ) -> Arc<dyn memory_snapshot::MemorySnapshotService + Send + Sync>;
+}
+
 pub trait HasExtraDeps<Ctx: WorkerCtx> {
     fn extra_deps(&self) -> Ctx::ExtraDeps;
 }
@@ -145,6 +152,7 @@ pub trait HasAll<Ctx: WorkerCtx>:
     + HasOplogService
     + HasRpc
     + HasSchedulerService
+    + HasMemorySnapshotService
     + HasWorkerActivator
     + HasWorkerProxy
     + HasEvents
@@ -170,6 +178,7 @@ impl<
             + HasOplogService
             + HasRpc
             + HasSchedulerService
+            + HasMemorySnapshotService
             + HasWorkerActivator
             + HasWorkerProxy
             + HasEvents
@@ -202,6 +211,7 @@ pub struct All<Ctx: WorkerCtx> {
     oplog_service: Arc<dyn oplog::OplogService + Send + Sync>,
     rpc: Arc<dyn rpc::Rpc + Send + Sync>,
     scheduler_service: Arc<dyn scheduler::SchedulerService + Send + Sync>,
+    memory_snapshot_service: Arc<dyn memory_snapshot::MemorySnapshotService + Send + Sync>,
     worker_activator: Arc<dyn WorkerActivator + Send + Sync>,
     worker_proxy: Arc<dyn worker_proxy::WorkerProxy + Send + Sync>,
     events: Arc<Events>,
@@ -228,6 +238,7 @@ impl<Ctx: WorkerCtx> Clone for All<Ctx> {
             oplog_service: self.oplog_service.clone(),
             rpc: self.rpc.clone(),
             scheduler_service: self.scheduler_service.clone(),
+            memory_snapshot_service: self.memory_snapshot_service.clone(),
             worker_activator: self.worker_activator.clone(),
             worker_proxy: self.worker_proxy.clone(),
             events: self.events.clone(),
@@ -260,6 +271,7 @@ impl<Ctx: WorkerCtx> All<Ctx> {
         oplog_service: Arc<dyn oplog::OplogService + Send + Sync>,
         rpc: Arc<dyn rpc::Rpc + Send + Sync>,
         scheduler_service: Arc<dyn scheduler::SchedulerService + Send + Sync>,
+        memory_snapshot_service: Arc<dyn memory_snapshot::MemorySnapshotService + Send + Sync>,
         worker_activator: Arc<dyn WorkerActivator + Send + Sync>,
         worker_proxy: Arc<dyn worker_proxy::WorkerProxy + Send + Sync>,
         events: Arc<Events>,
@@ -283,6 +295,7 @@ impl<Ctx: WorkerCtx> All<Ctx> {
             oplog_service,
             rpc,
             scheduler_service,
+            memory_snapshot_service,
             worker_activator,
             worker_proxy,
             events,
@@ -309,6 +322,7 @@ impl<Ctx: WorkerCtx> All<Ctx> {
             this.oplog_service(),
             this.rpc(),
             this.scheduler_service(),
+            this.memory_snapshot_service(),
             this.worker_activator(),
             this.worker_proxy(),
             this.events(),
@@ -433,6 +447,14 @@ impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasSchedulerService for T {
     }
 }
 
+impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasMemorySnapshotService for T {
+    fn memory_snapshot_service(
+        &self,
+    ) -> Arc<dyn memory_snapshot::MemorySnapshotService + Send + Sync> {
+        self.all().memory_snapshot_service.clone()
+    }
+}
+
 impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasWorkerActivator for T {
     fn worker_activator(&self) -> Arc<dyn WorkerActivator + Send + Sync> {
         self.all().worker_activator.clone()
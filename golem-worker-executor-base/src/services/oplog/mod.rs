@@ -36,6 +36,7 @@ use golem_common::model::{
 use golem_common::serialization::{serialize, try_deserialize};
 pub use multilayer::{MultiLayerOplog, MultiLayerOplogService, OplogArchiveService};
 pub use primary::PrimaryOplogService;
+pub use shadow::{ShadowOplog, ShadowOplogService};
 use tracing::Instrument;
 
 use crate::error::GolemError;
@@ -45,6 +46,7 @@ mod compressed;
 mod ephemeral;
 mod multilayer;
 mod primary;
+mod shadow;
 
 #[cfg(test)]
 mod tests;
@@ -62,6 +64,8 @@ mod tests;
 ///    compressed chunks. Reads a whole chunk in memory when accessed. Should not be used on top level.
 /// - `MultiLayerOplogService` - a service that can be used to stack multiple oplog services on each
 ///    other. Old entries are moved down the stack based on configurable conditions.
+/// - `ShadowOplogService` - writes to two oplog services at the same time while migrating between
+///    storage layouts, serving reads from the one that is authoritative for the worker's shard.
 ///
 #[async_trait]
 pub trait OplogService: Debug {
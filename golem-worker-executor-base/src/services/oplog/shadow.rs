@@ -0,0 +1,597 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::{Debug, Formatter};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use tracing::{debug, info, warn};
+
+use crate::error::GolemError;
+use crate::metrics::oplog::{
+    record_oplog_migration_backfill, record_oplog_migration_cutover_shard_count,
+    record_oplog_migration_pending_backfill_count, record_oplog_migration_verified_read,
+};
+use crate::services::oplog::multilayer::MultiLayerOplog;
+use crate::services::oplog::{
+    downcast_oplog, CommitLevel, OpenOplogs, Oplog, OplogConstructor, OplogService,
+};
+use crate::services::shard::ShardService;
+use golem_common::model::oplog::{OplogEntry, OplogIndex, OplogPayload};
+use golem_common::model::{
+    AccountId, ComponentId, ComponentType, OwnedWorkerId, ScanCursor, ShardId, WorkerId,
+};
+
+/// Oplog service used for migrating oplogs between two storage layouts without downtime.
+///
+/// Every write goes to both the `source` and the `target` service. For each worker one of them
+/// is authoritative: the source until the worker's shard is cut over, the target afterwards.
+/// Reads are served by the authoritative service, and if `verify_reads` is enabled they are
+/// also performed on the other one and compared.
+///
+/// Oplogs which are behind in the target service (for example because the worker was created
+/// before the migration started) are backfilled from the source when they get opened. Until
+/// then the source stays authoritative for them, even if their shard is cut over.
+///
+/// Shards can be cut over and rolled back at runtime. This only affects oplogs opened after it,
+/// already open oplogs keep their authoritative service until they are closed.
+#[derive(Clone)]
+pub struct ShadowOplogService {
+    source: Arc<dyn OplogService + Send + Sync>,
+    target: Arc<dyn OplogService + Send + Sync>,
+    shard_service: Arc<dyn ShardService + Send + Sync>,
+    cutover_shards: Arc<RwLock<HashSet<ShardId>>>,
+    backfill: Arc<RwLock<BackfillState>>,
+    verify_reads: bool,
+    oplogs: OpenOplogs,
+}
+
+/// Workers whose oplog in the target service is known to be complete, and the ones of cut over
+/// shards still waiting for it
+#[derive(Default)]
+struct BackfillState {
+    backfilled: HashSet<WorkerId>,
+    pending: HashSet<WorkerId>,
+}
+
+impl ShadowOplogService {
+    pub fn new(
+        source: Arc<dyn OplogService + Send + Sync>,
+        target: Arc<dyn OplogService + Send + Sync>,
+        shard_service: Arc<dyn ShardService + Send + Sync>,
+        cutover_shards: HashSet<ShardId>,
+        verify_reads: bool,
+    ) -> Self {
+        record_oplog_migration_cutover_shard_count(cutover_shards.len());
+        Self {
+            source,
+            target,
+            shard_service,
+            cutover_shards: Arc::new(RwLock::new(cutover_shards)),
+            backfill: Arc::new(RwLock::new(BackfillState::default())),
+            verify_reads,
+            oplogs: OpenOplogs::new("shadow oplog"),
+        }
+    }
+
+    /// Makes the target service authoritative for the given shards
+    pub fn cut_over(&self, shard_ids: &HashSet<ShardId>) {
+        let mut cutover_shards = self.cutover_shards.write().unwrap();
+        cutover_shards.extend(shard_ids.iter().copied());
+        info!(
+            "Oplog migration cut over {} shards, {} in total",
+            shard_ids.len(),
+            cutover_shards.len()
+        );
+        record_oplog_migration_cutover_shard_count(cutover_shards.len());
+    }
+
+    /// Makes the source service authoritative again for the given shards
+    pub fn roll_back(&self, shard_ids: &HashSet<ShardId>) {
+        let mut cutover_shards = self.cutover_shards.write().unwrap();
+        cutover_shards.retain(|shard_id| !shard_ids.contains(shard_id));
+        info!(
+            "Oplog migration rolled back {} shards, {} remain cut over",
+            shard_ids.len(),
+            cutover_shards.len()
+        );
+        record_oplog_migration_cutover_shard_count(cutover_shards.len());
+        drop(cutover_shards);
+
+        // Workers of the rolled back shards are no longer waiting to be served by the target
+        let mut backfill = self.backfill.write().unwrap();
+        backfill
+            .pending
+            .retain(|worker_id| !self.is_cut_over(worker_id));
+        record_oplog_migration_pending_backfill_count(backfill.pending.len());
+    }
+
+    pub fn cutover_shards(&self) -> HashSet<ShardId> {
+        self.cutover_shards.read().unwrap().clone()
+    }
+
+    fn is_cut_over(&self, worker_id: &WorkerId) -> bool {
+        match self.shard_service.try_get_current_assignment() {
+            Some(shard_assignment) => {
+                let shard_id =
+                    ShardId::from_worker_id(worker_id, shard_assignment.number_of_shards);
+                self.cutover_shards.read().unwrap().contains(&shard_id)
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the authoritative and the shadow service for the given worker
+    async fn services_of(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> (
+        &Arc<dyn OplogService + Send + Sync>,
+        &Arc<dyn OplogService + Send + Sync>,
+    ) {
+        if self.is_cut_over(&owned_worker_id.worker_id) && self.is_backfilled(owned_worker_id).await
+        {
+            (&self.target, &self.source)
+        } else {
+            (&self.source, &self.target)
+        }
+    }
+
+    /// Whether the target service has all the oplog entries of the source for the given worker
+    async fn is_backfilled(&self, owned_worker_id: &OwnedWorkerId) -> bool {
+        if self
+            .backfill
+            .read()
+            .unwrap()
+            .backfilled
+            .contains(&owned_worker_id.worker_id)
+        {
+            return true;
+        }
+
+        // Both services get all writes, so once the target caught up it stays complete
+        let backfilled = self.target.get_last_index(owned_worker_id).await
+            >= self.source.get_last_index(owned_worker_id).await;
+        self.record_backfill_state(&owned_worker_id.worker_id, backfilled);
+        backfilled
+    }
+
+    fn record_backfill_state(&self, worker_id: &WorkerId, backfilled: bool) {
+        let mut backfill = self.backfill.write().unwrap();
+        if backfilled {
+            backfill.pending.remove(worker_id);
+            backfill.backfilled.insert(worker_id.clone());
+        } else if self.is_cut_over(worker_id) {
+            backfill.pending.insert(worker_id.clone());
+        }
+        record_oplog_migration_pending_backfill_count(backfill.pending.len());
+    }
+
+    /// Opens the oplog in the target service, first copying the entries missing
+    /// from it up to `last_oplog_index` from the source service
+    async fn open_backfilled(
+        source: &Arc<dyn OplogService + Send + Sync>,
+        target: &Arc<dyn OplogService + Send + Sync>,
+        owned_worker_id: &OwnedWorkerId,
+        last_oplog_index: OplogIndex,
+        component_type: ComponentType,
+    ) -> Arc<dyn Oplog + Send + Sync> {
+        let target_last_index = target.get_last_index(owned_worker_id).await;
+        if target_last_index >= last_oplog_index {
+            return target
+                .open(owned_worker_id, target_last_index, component_type)
+                .await;
+        }
+
+        let missing_entries = source
+            .read_range(owned_worker_id, target_last_index.next(), last_oplog_index)
+            .await;
+        let count = missing_entries.len();
+        let mut missing_entries = missing_entries.into_iter();
+
+        debug!("Backfilling {count} oplog entries of {owned_worker_id} after {target_last_index}");
+
+        let oplog = match missing_entries.next() {
+            Some((_, initial_entry)) if target_last_index == OplogIndex::NONE => {
+                target
+                    .create(owned_worker_id, initial_entry, component_type)
+                    .await
+            }
+            Some((_, entry)) => {
+                let oplog = target
+                    .open(owned_worker_id, target_last_index, component_type)
+                    .await;
+                oplog.add(entry).await;
+                oplog
+            }
+            None => {
+                target
+                    .open(owned_worker_id, target_last_index, component_type)
+                    .await
+            }
+        };
+
+        for (_, entry) in missing_entries {
+            oplog.add(entry).await;
+        }
+        oplog.commit(CommitLevel::Always).await;
+
+        record_oplog_migration_backfill(count);
+        oplog
+    }
+}
+
+impl Debug for ShadowOplogService {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ShadowOplogService")
+            .field("source", &self.source)
+            .field("target", &self.target)
+            .field("verify_reads", &self.verify_reads)
+            .finish()
+    }
+}
+
+#[derive(Clone)]
+struct ShadowOplogConstructor {
+    owned_worker_id: OwnedWorkerId,
+    initial_entry: Option<OplogEntry>,
+    service: ShadowOplogService,
+    last_oplog_index: OplogIndex,
+    component_type: ComponentType,
+}
+
+impl ShadowOplogConstructor {
+    fn new(
+        owned_worker_id: OwnedWorkerId,
+        initial_entry: Option<OplogEntry>,
+        service: ShadowOplogService,
+        last_oplog_index: OplogIndex,
+        component_type: ComponentType,
+    ) -> Self {
+        Self {
+            owned_worker_id,
+            initial_entry,
+            service,
+            last_oplog_index,
+            component_type,
+        }
+    }
+}
+
+#[async_trait]
+impl OplogConstructor for ShadowOplogConstructor {
+    async fn create_oplog(
+        self,
+        close: Box<dyn FnOnce() + Send + Sync>,
+    ) -> Arc<dyn Oplog + Send + Sync> {
+        let source = &self.service.source;
+        let target = &self.service.target;
+
+        let (source_oplog, target_oplog) = match self.initial_entry {
+            Some(initial_entry) => {
+                let source_oplog = source
+                    .create(
+                        &self.owned_worker_id,
+                        initial_entry.clone(),
+                        self.component_type,
+                    )
+                    .await;
+                let target_oplog = target
+                    .create(&self.owned_worker_id, initial_entry, self.component_type)
+                    .await;
+                (source_oplog, target_oplog)
+            }
+            None => {
+                let source_oplog = source
+                    .open(
+                        &self.owned_worker_id,
+                        self.last_oplog_index,
+                        self.component_type,
+                    )
+                    .await;
+                let target_oplog = ShadowOplogService::open_backfilled(
+                    source,
+                    target,
+                    &self.owned_worker_id,
+                    self.last_oplog_index,
+                    self.component_type,
+                )
+                .await;
+                (source_oplog, target_oplog)
+            }
+        };
+        self.service
+            .record_backfill_state(&self.owned_worker_id.worker_id, true);
+
+        let (authoritative_oplog, shadow_oplog, shadow) =
+            if self.service.is_cut_over(&self.owned_worker_id.worker_id) {
+                (target_oplog, source_oplog, source)
+            } else {
+                (source_oplog, target_oplog, target)
+            };
+
+        Arc::new(ShadowOplog {
+            owned_worker_id: self.owned_worker_id,
+            authoritative: authoritative_oplog,
+            shadow: shadow_oplog,
+            shadow_service: shadow.clone(),
+            verify_reads: self.service.verify_reads,
+            close_fn: Some(close),
+        })
+    }
+}
+
+#[async_trait]
+impl OplogService for ShadowOplogService {
+    async fn create(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        initial_entry: OplogEntry,
+        component_type: ComponentType,
+    ) -> Arc<dyn Oplog + Send + Sync> {
+        self.oplogs
+            .get_or_open(
+                &owned_worker_id.worker_id,
+                ShadowOplogConstructor::new(
+                    owned_worker_id.clone(),
+                    Some(initial_entry),
+                    self.clone(),
+                    OplogIndex::INITIAL,
+                    component_type,
+                ),
+            )
+            .await
+    }
+
+    async fn open(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        last_oplog_index: OplogIndex,
+        component_type: ComponentType,
+    ) -> Arc<dyn Oplog + Send + Sync> {
+        debug!("ShadowOplogService::open {owned_worker_id}");
+        self.oplogs
+            .get_or_open(
+                &owned_worker_id.worker_id,
+                ShadowOplogConstructor::new(
+                    owned_worker_id.clone(),
+                    None,
+                    self.clone(),
+                    last_oplog_index,
+                    component_type,
+                ),
+            )
+            .await
+    }
+
+    async fn get_last_index(&self, owned_worker_id: &OwnedWorkerId) -> OplogIndex {
+        let (authoritative, _) = self.services_of(owned_worker_id).await;
+        authoritative.get_last_index(owned_worker_id).await
+    }
+
+    async fn delete(&self, owned_worker_id: &OwnedWorkerId) {
+        self.source.delete(owned_worker_id).await;
+        self.target.delete(owned_worker_id).await;
+
+        let mut backfill = self.backfill.write().unwrap();
+        backfill.backfilled.remove(&owned_worker_id.worker_id);
+        backfill.pending.remove(&owned_worker_id.worker_id);
+        record_oplog_migration_pending_backfill_count(backfill.pending.len());
+    }
+
+    async fn read(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        idx: OplogIndex,
+        n: u64,
+    ) -> BTreeMap<OplogIndex, OplogEntry> {
+        let (authoritative, shadow) = self.services_of(owned_worker_id).await;
+        let result = authoritative.read(owned_worker_id, idx, n).await;
+
+        if self.verify_reads {
+            let shadow_result = shadow.read(owned_worker_id, idx, n).await;
+            verify_entries(owned_worker_id, &result, &shadow_result);
+        }
+
+        result
+    }
+
+    async fn exists(&self, owned_worker_id: &OwnedWorkerId) -> bool {
+        let (authoritative, _) = self.services_of(owned_worker_id).await;
+        authoritative.exists(owned_worker_id).await
+    }
+
+    async fn scan_for_component(
+        &self,
+        account_id: &AccountId,
+        component_id: &ComponentId,
+        cursor: ScanCursor,
+        count: u64,
+    ) -> Result<(ScanCursor, Vec<OwnedWorkerId>), GolemError> {
+        // Until every shard is cut over the source is the only service guaranteed to know about all workers
+        self.source
+            .scan_for_component(account_id, component_id, cursor, count)
+            .await
+    }
+
+    async fn upload_payload(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        data: &[u8],
+    ) -> Result<OplogPayload, String> {
+        let (authoritative, shadow) = self.services_of(owned_worker_id).await;
+        let result = authoritative.upload_payload(owned_worker_id, data).await;
+        if let Err(error) = shadow.upload_payload(owned_worker_id, data).await {
+            warn!("Failed to upload oplog payload of {owned_worker_id} to the shadow oplog service: {error}");
+        }
+        result
+    }
+
+    async fn download_payload(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        payload: &OplogPayload,
+    ) -> Result<Bytes, String> {
+        let (authoritative, shadow) = self.services_of(owned_worker_id).await;
+        match authoritative
+            .download_payload(owned_worker_id, payload)
+            .await
+        {
+            Ok(bytes) => Ok(bytes),
+            Err(error) => {
+                warn!("Failed to download oplog payload of {owned_worker_id} from the authoritative oplog service, falling back to the shadow one: {error}");
+                shadow.download_payload(owned_worker_id, payload).await
+            }
+        }
+    }
+}
+
+/// An open oplog writing to both the authoritative and the shadow oplog service
+pub struct ShadowOplog {
+    owned_worker_id: OwnedWorkerId,
+    authoritative: Arc<dyn Oplog + Send + Sync>,
+    shadow: Arc<dyn Oplog + Send + Sync>,
+    shadow_service: Arc<dyn OplogService + Send + Sync>,
+    verify_reads: bool,
+    close_fn: Option<Box<dyn FnOnce() + Send + Sync>>,
+}
+
+impl ShadowOplog {
+    /// Archives both underlying oplogs if they support it, returning whether
+    /// any of them has more layers to archive
+    pub async fn try_archive(this: &Arc<dyn Oplog + Send + Sync>) -> Option<bool> {
+        let this = downcast_oplog::<ShadowOplog>(this)?;
+        let authoritative = MultiLayerOplog::try_archive(&this.authoritative).await;
+        let shadow = MultiLayerOplog::try_archive(&this.shadow).await;
+        match (authoritative, shadow) {
+            (None, None) => None,
+            (authoritative, shadow) => {
+                Some(authoritative.unwrap_or(false) || shadow.unwrap_or(false))
+            }
+        }
+    }
+}
+
+impl Drop for ShadowOplog {
+    fn drop(&mut self) {
+        if let Some(close_fn) = self.close_fn.take() {
+            close_fn();
+        }
+    }
+}
+
+impl Debug for ShadowOplog {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ShadowOplog")
+            .field("worker_id", &self.owned_worker_id)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl Oplog for ShadowOplog {
+    async fn add(&self, entry: OplogEntry) {
+        self.authoritative.add(entry.clone()).await;
+        self.shadow.add(entry).await;
+    }
+
+    async fn drop_prefix(&self, last_dropped_id: OplogIndex) {
+        self.authoritative.drop_prefix(last_dropped_id).await;
+        self.shadow.drop_prefix(last_dropped_id).await;
+    }
+
+    async fn commit(&self, level: CommitLevel) {
+        self.authoritative.commit(level).await;
+        self.shadow.commit(level).await;
+    }
+
+    async fn current_oplog_index(&self) -> OplogIndex {
+        self.authoritative.current_oplog_index().await
+    }
+
+    async fn wait_for_replicas(&self, replicas: u8, timeout: Duration) -> bool {
+        self.authoritative
+            .wait_for_replicas(replicas, timeout)
+            .await
+    }
+
+    async fn read(&self, oplog_index: OplogIndex) -> OplogEntry {
+        let entry = self.authoritative.read(oplog_index).await;
+
+        if self.verify_reads {
+            let shadow_result = self
+                .shadow_service
+                .read(&self.owned_worker_id, oplog_index, 1)
+                .await;
+            verify_entries(
+                &self.owned_worker_id,
+                &BTreeMap::from([(oplog_index, entry.clone())]),
+                &shadow_result,
+            );
+        }
+
+        entry
+    }
+
+    async fn length(&self) -> u64 {
+        self.authoritative.length().await
+    }
+
+    async fn upload_payload(&self, data: &[u8]) -> Result<OplogPayload, String> {
+        let result = self.authoritative.upload_payload(data).await;
+        if let Err(error) = self.shadow.upload_payload(data).await {
+            warn!(
+                "Failed to upload oplog payload of {} to the shadow oplog: {error}",
+                self.owned_worker_id
+            );
+        }
+        result
+    }
+
+    async fn download_payload(&self, payload: &OplogPayload) -> Result<Bytes, String> {
+        match self.authoritative.download_payload(payload).await {
+            Ok(bytes) => Ok(bytes),
+            Err(error) => {
+                warn!("Failed to download oplog payload of {} from the authoritative oplog, falling back to the shadow one: {error}", self.owned_worker_id);
+                self.shadow.download_payload(payload).await
+            }
+        }
+    }
+}
+
+fn verify_entries(
+    owned_worker_id: &OwnedWorkerId,
+    authoritative: &BTreeMap<OplogIndex, OplogEntry>,
+    shadow: &BTreeMap<OplogIndex, OplogEntry>,
+) {
+    if authoritative == shadow {
+        record_oplog_migration_verified_read(true);
+    } else {
+        let mismatching = authoritative
+            .iter()
+            .filter(|(idx, entry)| shadow.get(idx) != Some(entry))
+            .map(|(idx, _)| idx.to_string())
+            .collect::<Vec<_>>();
+        warn!(
+            "Oplog migration read mismatch for {owned_worker_id}: {} entries in the authoritative service, {} in the shadow, differing at [{}]",
+            authoritative.len(),
+            shadow.len(),
+            mismatching.join(", ")
+        );
+        record_oplog_migration_verified_read(false);
+    }
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+
 use test_r::{test, test_dep};
 
 use assert2::check;
@@ -22,12 +24,13 @@ use uuid::Uuid;
 use golem_common::config::RedisConfig;
 use golem_common::model::oplog::WorkerError;
 use golem_common::model::regions::OplogRegion;
-use golem_common::model::ComponentId;
+use golem_common::model::{ComponentId, ShardId};
 use golem_common::redis::RedisPool;
 use golem_common::tracing::{init_tracing, TracingConfig};
 
 use crate::services::oplog::compressed::CompressedOplogArchiveService;
 use crate::services::oplog::multilayer::OplogArchiveService;
+use crate::services::shard::{ShardService, ShardServiceDefault};
 use crate::storage::blob::memory::InMemoryBlobStorage;
 use crate::storage::indexed::memory::InMemoryIndexedStorage;
 use crate::storage::indexed::redis::RedisIndexedStorage;
@@ -236,6 +239,22 @@ fn rounded(entry: OplogEntry) -> OplogEntry {
         OplogEntry::Restart { timestamp } => OplogEntry::Restart {
             timestamp: rounded_ts(timestamp),
         },
+        OplogEntry::CancellationRequested {
+            timestamp,
+            idempotency_key,
+            reason,
+        } => OplogEntry::CancellationRequested {
+            timestamp: rounded_ts(timestamp),
+            idempotency_key,
+            reason,
+        },
+        OplogEntry::CancellationAcknowledged {
+            timestamp,
+            idempotency_key,
+        } => OplogEntry::CancellationAcknowledged {
+            timestamp: rounded_ts(timestamp),
+            idempotency_key,
+        },
     }
 }
 
@@ -1306,3 +1325,243 @@ async fn scheduled_archive_impl(use_blob: bool) {
 
     assert_eq!(last_oplog_index_2, last_oplog_index_3);
 }
+
+#[test]
+async fn shadow_oplog_writes_to_both_services(_tracing: &Tracing) {
+    let blob_storage = Arc::new(InMemoryBlobStorage::new());
+    let source = Arc::new(
+        PrimaryOplogService::new(
+            Arc::new(InMemoryIndexedStorage::new()),
+            blob_storage.clone(),
+            1,
+            100,
+        )
+        .await,
+    );
+    let target = Arc::new(
+        PrimaryOplogService::new(
+            Arc::new(InMemoryIndexedStorage::new()),
+            blob_storage.clone(),
+            1,
+            100,
+        )
+        .await,
+    );
+    let oplog_service = ShadowOplogService::new(
+        source.clone(),
+        target.clone(),
+        Arc::new(ShardServiceDefault::new()),
+        HashSet::new(),
+        true,
+    );
+    let account_id = AccountId {
+        value: "user1".to_string(),
+    };
+    let worker_id = WorkerId {
+        component_id: ComponentId(Uuid::new_v4()),
+        worker_name: "test".to_string(),
+    };
+    let owned_worker_id = OwnedWorkerId::new(&account_id, &worker_id);
+
+    let entry1 = rounded(OplogEntry::suspend());
+    let entry2 = rounded(OplogEntry::exited());
+
+    let oplog = oplog_service
+        .create(&owned_worker_id, entry1.clone(), ComponentType::Durable)
+        .await;
+    oplog.add(entry2.clone()).await;
+    oplog.commit(CommitLevel::Always).await;
+
+    let source_entries = source.read(&owned_worker_id, OplogIndex::INITIAL, 2).await;
+    let target_entries = target.read(&owned_worker_id, OplogIndex::INITIAL, 2).await;
+
+    assert_eq!(
+        source_entries.into_values().collect::<Vec<_>>(),
+        vec![entry1.clone(), entry2.clone()]
+    );
+    assert_eq!(
+        target_entries.into_values().collect::<Vec<_>>(),
+        vec![entry1, entry2]
+    );
+}
+
+#[test]
+async fn shadow_oplog_backfills_existing_oplog(_tracing: &Tracing) {
+    let blob_storage = Arc::new(InMemoryBlobStorage::new());
+    let source = Arc::new(
+        PrimaryOplogService::new(
+            Arc::new(InMemoryIndexedStorage::new()),
+            blob_storage.clone(),
+            1,
+            100,
+        )
+        .await,
+    );
+    let target = Arc::new(
+        PrimaryOplogService::new(
+            Arc::new(InMemoryIndexedStorage::new()),
+            blob_storage.clone(),
+            1,
+            100,
+        )
+        .await,
+    );
+    let account_id = AccountId {
+        value: "user1".to_string(),
+    };
+    let worker_id = WorkerId {
+        component_id: ComponentId(Uuid::new_v4()),
+        worker_name: "test".to_string(),
+    };
+    let owned_worker_id = OwnedWorkerId::new(&account_id, &worker_id);
+
+    let entries: Vec<OplogEntry> = (0..10)
+        .map(|i| {
+            rounded(OplogEntry::Error {
+                timestamp: Timestamp::now_utc(),
+                error: WorkerError::Unknown(i.to_string()),
+            })
+        })
+        .collect();
+
+    // Writing the oplog before the migration started
+    {
+        let oplog = source
+            .create(&owned_worker_id, entries[0].clone(), ComponentType::Durable)
+            .await;
+        for entry in &entries[1..] {
+            oplog.add(entry.clone()).await;
+        }
+        oplog.commit(CommitLevel::Always).await;
+    }
+
+    let oplog_service = ShadowOplogService::new(
+        source.clone(),
+        target.clone(),
+        Arc::new(ShardServiceDefault::new()),
+        HashSet::new(),
+        true,
+    );
+
+    let last_oplog_index = oplog_service.get_last_index(&owned_worker_id).await;
+    let oplog = oplog_service
+        .open(&owned_worker_id, last_oplog_index, ComponentType::Durable)
+        .await;
+    let entry = rounded(OplogEntry::exited());
+    oplog.add(entry.clone()).await;
+    oplog.commit(CommitLevel::Always).await;
+
+    let mut expected = entries.clone();
+    expected.push(entry);
+
+    let target_entries = target.read(&owned_worker_id, OplogIndex::INITIAL, 11).await;
+
+    assert_eq!(last_oplog_index, OplogIndex::from_u64(10));
+    assert_eq!(target_entries.into_values().collect::<Vec<_>>(), expected);
+    assert_eq!(
+        target.get_last_index(&owned_worker_id).await,
+        source.get_last_index(&owned_worker_id).await
+    );
+}
+
+#[test]
+async fn shadow_oplog_cutover_shards_select_authoritative_service(_tracing: &Tracing) {
+    let blob_storage = Arc::new(InMemoryBlobStorage::new());
+    let source = Arc::new(
+        PrimaryOplogService::new(
+            Arc::new(InMemoryIndexedStorage::new()),
+            blob_storage.clone(),
+            1,
+            100,
+        )
+        .await,
+    );
+    let target = Arc::new(
+        PrimaryOplogService::new(
+            Arc::new(InMemoryIndexedStorage::new()),
+            blob_storage.clone(),
+            1,
+            100,
+        )
+        .await,
+    );
+    let shard_service = Arc::new(ShardServiceDefault::new());
+    let shard_ids = HashSet::from([ShardId::new(0)]);
+    shard_service.register(1, &shard_ids);
+
+    let account_id = AccountId {
+        value: "user1".to_string(),
+    };
+    let worker_id = WorkerId {
+        component_id: ComponentId(Uuid::new_v4()),
+        worker_name: "test".to_string(),
+    };
+    let owned_worker_id = OwnedWorkerId::new(&account_id, &worker_id);
+
+    let entries = vec![
+        rounded(OplogEntry::suspend()),
+        rounded(OplogEntry::exited()),
+    ];
+
+    // Written before the migration started, so only available in the source
+    {
+        let oplog = source
+            .create(&owned_worker_id, entries[0].clone(), ComponentType::Durable)
+            .await;
+        oplog.add(entries[1].clone()).await;
+        oplog.commit(CommitLevel::Always).await;
+    }
+
+    let oplog_service = ShadowOplogService::new(
+        source.clone(),
+        target.clone(),
+        shard_service.clone(),
+        HashSet::new(),
+        false,
+    );
+
+    let index_before_cutover = oplog_service.get_last_index(&owned_worker_id).await;
+    oplog_service.cut_over(&shard_ids);
+    let cutover_shards = oplog_service.cutover_shards();
+
+    // Not backfilled yet, so still served by the source
+    let index_after_cutover = oplog_service.get_last_index(&owned_worker_id).await;
+    let entries_after_cutover = oplog_service
+        .read(&owned_worker_id, OplogIndex::INITIAL, 2)
+        .await;
+
+    // Opening the oplog backfills the target, which then serves it
+    let entry = rounded(OplogEntry::interrupted());
+    {
+        let oplog = oplog_service
+            .open(
+                &owned_worker_id,
+                index_after_cutover,
+                ComponentType::Durable,
+            )
+            .await;
+        oplog.add(entry.clone()).await;
+        oplog.commit(CommitLevel::Always).await;
+    }
+    let index_after_open = oplog_service.get_last_index(&owned_worker_id).await;
+    let target_entries = target.read(&owned_worker_id, OplogIndex::INITIAL, 3).await;
+
+    oplog_service.roll_back(&shard_ids);
+    let index_after_rollback = oplog_service.get_last_index(&owned_worker_id).await;
+    let source_entries = source.read(&owned_worker_id, OplogIndex::INITIAL, 3).await;
+
+    let mut expected = entries.clone();
+    expected.push(entry);
+
+    assert_eq!(index_before_cutover, OplogIndex::from_u64(2));
+    assert_eq!(cutover_shards, shard_ids);
+    assert_eq!(index_after_cutover, OplogIndex::from_u64(2));
+    assert_eq!(
+        entries_after_cutover.into_values().collect::<Vec<_>>(),
+        entries
+    );
+    assert_eq!(index_after_open, OplogIndex::from_u64(3));
+    assert_eq!(target_entries.into_values().collect::<Vec<_>>(), expected);
+    assert_eq!(index_after_rollback, OplogIndex::from_u64(3));
+    assert_eq!(source_entries.into_values().collect::<Vec<_>>(), expected);
+}
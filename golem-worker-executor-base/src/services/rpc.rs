@@ -30,10 +30,10 @@ use crate::services::events::Events;
 use crate::services::shard::ShardService;
 use crate::services::worker_proxy::{WorkerProxy, WorkerProxyError};
 use crate::services::{
-    active_workers, blob_store, component, golem_config, key_value, oplog, promise, scheduler,
-    shard, shard_manager, worker, worker_activator, worker_enumeration, HasActiveWorkers,
-    HasBlobStoreService, HasComponentService, HasConfig, HasEvents, HasExtraDeps,
-    HasKeyValueService, HasOplogService, HasPromiseService, HasRpc,
+    active_workers, blob_store, component, golem_config, key_value, memory_snapshot, oplog,
+    promise, scheduler, shard, shard_manager, worker, worker_activator, worker_enumeration,
+    HasActiveWorkers, HasBlobStoreService, HasComponentService, HasConfig, HasEvents, HasExtraDeps,
+    HasKeyValueService, HasMemorySnapshotService, HasOplogService, HasPromiseService, HasRpc,
     HasRunningWorkerEnumerationService, HasSchedulerService, HasShardManagerService,
     HasShardService, HasWasmtimeEngine, HasWorkerActivator, HasWorkerEnumerationService,
     HasWorkerProxy, HasWorkerService,
@@ -271,6 +271,7 @@ pub struct DirectWorkerInvocationRpc<Ctx: WorkerCtx> {
     blob_store_service: Arc<dyn blob_store::BlobStoreService + Send + Sync>,
     oplog_service: Arc<dyn oplog::OplogService + Send + Sync>,
     scheduler_service: Arc<dyn scheduler::SchedulerService + Send + Sync>,
+    memory_snapshot_service: Arc<dyn memory_snapshot::MemorySnapshotService + Send + Sync>,
     worker_activator: Arc<dyn worker_activator::WorkerActivator + Send + Sync>,
     events: Arc<Events>,
     extra_deps: Ctx::ExtraDeps,
@@ -296,6 +297,7 @@ impl<Ctx: WorkerCtx> Clone for DirectWorkerInvocationRpc<Ctx> {
             blob_store_service: self.blob_store_service.clone(),
             oplog_service: self.oplog_service.clone(),
             scheduler_service: self.scheduler_service.clone(),
+            memory_snapshot_service: self.memory_snapshot_service.clone(),
             worker_activator: self.worker_activator.clone(),
             events: self.events.clone(),
             extra_deps: self.extra_deps.clone(),
@@ -387,6 +389,14 @@ impl<Ctx: WorkerCtx> HasSchedulerService for DirectWorkerInvocationRpc<Ctx> {
     }
 }
 
+impl<Ctx: WorkerCtx> HasMemorySnapshotService for DirectWorkerInvocationRpc<Ctx> {
+    fn memory_snapshot_service(
+        &self,
+    ) -> Arc<dyn memory_snapshot::MemorySnapshotService + Send + Sync> {
+        self.memory_snapshot_service.clone()
+    }
+}
+
 impl<Ctx: WorkerCtx> HasOplogService for DirectWorkerInvocationRpc<Ctx> {
     fn oplog_service(&self) -> Arc<dyn oplog::OplogService + Send + Sync> {
         self.oplog_service.clone()
@@ -452,6 +462,7 @@ impl<Ctx: WorkerCtx> DirectWorkerInvocationRpc<Ctx> {
         blob_store_service: Arc<dyn blob_store::BlobStoreService + Send + Sync>,
         oplog_service: Arc<dyn oplog::OplogService + Send + Sync>,
         scheduler_service: Arc<dyn scheduler::SchedulerService + Send + Sync>,
+        memory_snapshot_service: Arc<dyn memory_snapshot::MemorySnapshotService + Send + Sync>,
         worker_activator: Arc<dyn worker_activator::WorkerActivator + Send + Sync>,
         events: Arc<Events>,
         extra_deps: Ctx::ExtraDeps,
@@ -474,6 +485,7 @@ impl<Ctx: WorkerCtx> DirectWorkerInvocationRpc<Ctx> {
             blob_store_service,
             oplog_service,
             scheduler_service,
+            memory_snapshot_service,
             worker_activator,
             events,
             extra_deps,
@@ -25,7 +25,7 @@ use tracing::{error, info, span, warn, Instrument, Level};
 
 use crate::metrics::oplog::record_scheduled_archive;
 use crate::metrics::promises::record_scheduled_promise_completed;
-use crate::services::oplog::{MultiLayerOplog, OplogService};
+use crate::services::oplog::{MultiLayerOplog, OplogService, ShadowOplog};
 use crate::services::promise::PromiseService;
 use crate::services::shard::ShardService;
 use crate::services::worker::WorkerService;
@@ -172,7 +172,11 @@ impl SchedulerServiceDefault {
                                 .await;
 
                             let start = Instant::now();
-                            if let Some(more) = MultiLayerOplog::try_archive(&oplog).await {
+                            let archive_result = match ShadowOplog::try_archive(&oplog).await {
+                                None => MultiLayerOplog::try_archive(&oplog).await,
+                                result => result,
+                            };
+                            if let Some(more) = archive_result {
                                 record_scheduled_archive(start.elapsed(), more);
                                 if more {
                                     self.schedule(
@@ -34,10 +34,14 @@ pub trait ShardService {
     fn revoke_shards(&self, shard_ids: &HashSet<ShardId>) -> Result<(), GolemError>;
     fn current_assignment(&self) -> Result<ShardAssignment, GolemError>;
     fn try_get_current_assignment(&self) -> Option<ShardAssignment>;
+    /// Replaces the set of shards this executor is a warm standby for
+    fn assign_standby_shards(&self, shard_ids: &HashSet<ShardId>);
+    fn standby_shards(&self) -> HashSet<ShardId>;
 }
 
 pub struct ShardServiceDefault {
     shard_assignment: Arc<RwLock<Option<ShardAssignment>>>,
+    standby_shards: Arc<RwLock<HashSet<ShardId>>>,
 }
 
 impl Default for ShardServiceDefault {
@@ -50,6 +54,7 @@ impl ShardServiceDefault {
     pub fn new() -> Self {
         Self {
             shard_assignment: Arc::new(RwLock::new(None)),
+            standby_shards: Arc::new(RwLock::new(HashSet::new())),
         }
     }
 
@@ -82,20 +87,34 @@ impl ShardService for ShardServiceDefault {
     }
 
     fn assign_shards(&self, shard_ids: &HashSet<ShardId>) -> Result<(), GolemError> {
-        self.with_write_shard_assignment(|shard_assignment| match shard_assignment {
+        let result = self.with_write_shard_assignment(|shard_assignment| match shard_assignment {
             Some(shard_assignment) => {
                 debug!(
                     shard_ids_current = shard_assignment.shard_ids.iter().join(", "),
                     shard_ids_to_assign = shard_ids.iter().join(", "),
                     "ShardService.assign_shards"
                 );
+                let newly_assigned = shard_ids
+                    .difference(&shard_assignment.shard_ids)
+                    .copied()
+                    .collect::<HashSet<_>>();
                 shard_assignment.assign_shards(shard_ids);
                 let assigned_shard_count = shard_assignment.shard_ids.len();
                 record_assigned_shard_count(assigned_shard_count);
-                Ok(())
+                Ok(newly_assigned)
             }
             None => Err(sharding_not_ready_error()),
-        })
+        });
+
+        let newly_assigned = result?;
+        let mut standby_shards = self.standby_shards.write().unwrap();
+        for shard_id in &newly_assigned {
+            // A shard taken over from a failed or rebalanced executor is a standby hit if its
+            // component artifacts have been pre-fetched by this executor.
+            record_standby_takeover(standby_shards.remove(shard_id));
+        }
+        record_standby_shard_count(standby_shards.len());
+        Ok(())
     }
 
     fn check_worker(&self, worker_id: &WorkerId) -> Result<(), GolemError> {
@@ -150,6 +169,21 @@ impl ShardService for ShardServiceDefault {
     fn try_get_current_assignment(&self) -> Option<ShardAssignment> {
         self.shard_assignment.read().unwrap().clone()
     }
+
+    fn assign_standby_shards(&self, shard_ids: &HashSet<ShardId>) {
+        let mut standby_shards = self.standby_shards.write().unwrap();
+        debug!(
+            standby_shard_ids_current = standby_shards.iter().join(", "),
+            standby_shard_ids_to_assign = shard_ids.iter().join(", "),
+            "ShardService.assign_standby_shards"
+        );
+        *standby_shards = shard_ids.clone();
+        record_standby_shard_count(standby_shards.len());
+    }
+
+    fn standby_shards(&self) -> HashSet<ShardId> {
+        self.standby_shards.read().unwrap().clone()
+    }
 }
 
 fn sharding_not_ready_error() -> GolemError {
@@ -44,6 +44,8 @@ pub trait WorkerService {
 
     async fn get_running_workers_in_shards(&self) -> Vec<WorkerMetadata>;
 
+    async fn get_running_workers_in_standby_shards(&self) -> Vec<WorkerMetadata>;
+
     async fn remove(&self, owned_worker_id: &OwnedWorkerId);
 
     async fn remove_cached_status(&self, owned_worker_id: &OwnedWorkerId);
@@ -274,6 +276,16 @@ impl WorkerService for DefaultWorkerService {
         result
     }
 
+    async fn get_running_workers_in_standby_shards(&self) -> Vec<WorkerMetadata> {
+        let mut result: Vec<WorkerMetadata> = vec![];
+        for shard_id in self.shard_service.standby_shards() {
+            let key = Self::running_in_shard_key(&shard_id);
+            let mut shard_worker = self.enum_workers_at_key(&key).await;
+            result.append(&mut shard_worker);
+        }
+        result
+    }
+
     async fn remove(&self, owned_worker_id: &OwnedWorkerId) {
         record_worker_call("remove");
 
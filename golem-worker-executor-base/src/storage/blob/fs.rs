@@ -82,6 +82,14 @@ impl FileSystemBlobStorage {
                 result.push(component_id.to_string());
                 result.push(level.to_string());
             }
+            BlobStorageNamespace::MemorySnapshot {
+                account_id,
+                worker_id,
+            } => {
+                result.push("memory_snapshot");
+                result.push(account_id.to_string());
+                result.push(worker_id.to_string());
+            }
         }
 
         result.push(path);
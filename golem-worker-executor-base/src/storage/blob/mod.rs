@@ -337,6 +337,10 @@ pub enum BlobStorageNamespace {
         component_id: ComponentId,
         level: usize,
     },
+    MemorySnapshot {
+        account_id: AccountId,
+        worker_id: WorkerId,
+    },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -71,6 +71,7 @@ impl S3BlobStorage {
             BlobStorageNamespace::CompressedOplog { level, .. } => {
                 &self.config.compressed_oplog_buckets[*level]
             }
+            BlobStorageNamespace::MemorySnapshot { .. } => &self.config.memory_snapshot_bucket,
         }
     }
 
@@ -92,6 +93,10 @@ impl S3BlobStorage {
             BlobStorageNamespace::OplogPayload {
                 account_id,
                 worker_id,
+            }
+            | BlobStorageNamespace::MemorySnapshot {
+                account_id,
+                worker_id,
             } => {
                 let account_id_string = account_id.to_string();
                 let worker_id_string = worker_id.to_string();
@@ -52,6 +52,13 @@ impl SqliteBlobStorage {
                 "compressed_oplog-{}-{}-{}",
                 account_id.value, component_id, level
             ),
+            BlobStorageNamespace::MemorySnapshot {
+                account_id,
+                worker_id,
+            } => format!(
+                "memory_snapshot-{}-{}-{}",
+                account_id.value, worker_id.component_id, worker_id.worker_name
+            ),
         }
     }
 
@@ -16,30 +16,35 @@ use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::mem;
 use std::ops::DerefMut;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, RwLock};
-use std::time::Duration;
+use std::sync::{Arc, RwLock, Weak};
+use std::time::{Duration, Instant};
 
 use crate::durable_host::recover_stderr_logs;
 use crate::error::{GolemError, WorkerOutOfMemory};
 use crate::function_result_interpreter::interpret_function_results;
 use crate::invocation::{invoke_worker, InvokeResult};
+use crate::linear_memory::LinearMemories;
+use crate::metrics::memory_snapshots::{
+    record_memory_snapshot_restored, record_memory_snapshot_saved,
+};
 use crate::model::{ExecutionStatus, InterruptKind, LookupResult, TrapType, WorkerConfig};
 use crate::services::component::ComponentMetadata;
 use crate::services::events::Event;
+use crate::services::memory_snapshot::{is_restorable_entry, LinearMemorySnapshot};
 use crate::services::oplog::{CommitLevel, Oplog, OplogOps};
 use crate::services::worker_event::{WorkerEventService, WorkerEventServiceDefault};
 use crate::services::{
     All, HasActiveWorkers, HasAll, HasBlobStoreService, HasComponentService, HasConfig, HasEvents,
-    HasExtraDeps, HasKeyValueService, HasOplog, HasOplogService, HasPromiseService, HasRpc,
-    HasSchedulerService, HasWasmtimeEngine, HasWorker, HasWorkerEnumerationService, HasWorkerProxy,
-    HasWorkerService, UsesAllDeps,
+    HasExtraDeps, HasKeyValueService, HasMemorySnapshotService, HasOplog, HasOplogService,
+    HasPromiseService, HasRpc, HasSchedulerService, HasWasmtimeEngine, HasWorker,
+    HasWorkerEnumerationService, HasWorkerProxy, HasWorkerService, UsesAllDeps,
 };
 use crate::workerctx::{PublicWorkerIo, WorkerCtx};
 use anyhow::anyhow;
 use golem_common::config::RetryConfig;
 use golem_common::model::oplog::{
-    OplogEntry, OplogIndex, TimestampedUpdateDescription, UpdateDescription, WorkerError,
-    WorkerResourceId,
+    CancellationReason, OplogEntry, OplogIndex, TimestampedUpdateDescription, UpdateDescription,
+    WorkerError, WorkerResourceId,
 };
 use golem_common::model::regions::{DeletedRegions, DeletedRegionsBuilder, OplogRegion};
 use golem_common::model::{exports, ComponentType};
@@ -54,10 +59,10 @@ use golem_wasm_rpc::Value;
 use tokio::sync::broadcast::error::RecvError;
 use tokio::sync::broadcast::Receiver;
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
-use tokio::sync::{Mutex, MutexGuard, OwnedSemaphorePermit};
+use tokio::sync::{Mutex, MutexGuard, Notify, OwnedSemaphorePermit};
 use tokio::task::JoinHandle;
 use tracing::{debug, error, info, span, warn, Instrument, Level};
-use wasmtime::component::Instance;
+use wasmtime::component::{Component, Instance};
 use wasmtime::{AsContext, Store, UpdateDeadline};
 
 /// Represents worker that may be running or suspended.
@@ -90,6 +95,12 @@ pub struct Worker<Ctx: WorkerCtx> {
 
     instance: Arc<Mutex<WorkerInstance>>,
     oom_retry_config: RetryConfig,
+
+    current_invocation: RwLock<Option<InvocationCancellation>>,
+    invocation_finished: Notify,
+
+    restored_memory_snapshot: RwLock<Option<OplogIndex>>,
+    memory_snapshot_progress: Mutex<MemorySnapshotProgress>,
 }
 
 impl<Ctx: WorkerCtx> HasOplog for Worker<Ctx> {
@@ -246,6 +257,10 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
             initial_worker_metadata: worker_metadata,
             worker_estimate_coefficient: deps.config().memory.worker_estimate_coefficient,
             oom_retry_config: deps.config().memory.oom_retry_config.clone(),
+            current_invocation: RwLock::new(None),
+            invocation_finished: Notify::new(),
+            restored_memory_snapshot: RwLock::new(None),
+            memory_snapshot_progress: Mutex::new(MemorySnapshotProgress::default()),
         })
     }
 
@@ -253,6 +268,20 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         &self.oom_retry_config
     }
 
+    /// Returns the oplog index the memory of the current instance has been restored to from a
+    /// memory snapshot, if any. Replaying the oplog can continue after this index.
+    pub fn take_restored_memory_snapshot(&self) -> Option<OplogIndex> {
+        self.restored_memory_snapshot.write().unwrap().take()
+    }
+
+    /// Waits until the memory snapshot saved in the background, if any, is stored
+    pub async fn await_pending_memory_snapshot(&self) {
+        let pending = self.memory_snapshot_progress.lock().await.pending.take();
+        if let Some(pending) = pending {
+            let _ = pending.await;
+        }
+    }
+
     pub async fn start_if_needed(this: Arc<Worker<Ctx>>) -> Result<bool, GolemError> {
         Self::start_if_needed_internal(this, 0).await
     }
@@ -440,6 +469,52 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         }
     }
 
+    /// Requests cooperative cancellation of the invocation the worker is currently processing.
+    ///
+    /// The invocation can observe the request through `golem:api/cancellation.{is-cancelled}`.
+    /// If a cancellation grace period is configured, the worker gets interrupted if the invocation
+    /// is still running when the grace period elapsed. Cancellations requested by an interrupt
+    /// also stop the worker when the invocation finished in time, so it does not process the
+    /// queued invocations until it gets resumed. Returns false if there was no invocation to cancel.
+    pub async fn cancel_current_invocation(self: &Arc<Self>, reason: CancellationReason) -> bool {
+        let idempotency_key = self
+            .current_invocation
+            .read()
+            .unwrap()
+            .as_ref()
+            .map(|invocation| invocation.idempotency_key.clone());
+        match idempotency_key {
+            Some(idempotency_key) => self.request_cancellation(idempotency_key, reason).await,
+            None => false,
+        }
+    }
+
+    /// Checks if cancellation was requested for the current invocation. The first positive
+    /// check of each invocation is recorded in the oplog as an acknowledgement.
+    pub async fn acknowledge_cancellation(&self) -> bool {
+        let (cancelled, newly_acknowledged) = {
+            let mut current_invocation = self.current_invocation.write().unwrap();
+            match &mut *current_invocation {
+                Some(invocation) if invocation.reason.is_some() => {
+                    if invocation.acknowledged {
+                        (true, None)
+                    } else {
+                        invocation.acknowledged = true;
+                        (true, Some(invocation.idempotency_key.clone()))
+                    }
+                }
+                _ => (false, None),
+            }
+        };
+        if let Some(idempotency_key) = newly_acknowledged {
+            debug!("Cancellation of invocation {idempotency_key} acknowledged");
+            self.oplog
+                .add(OplogEntry::cancellation_acknowledged(idempotency_key))
+                .await;
+        }
+        cancelled
+    }
+
     pub async fn invoke(
         &self,
         idempotency_key: IdempotencyKey,
@@ -727,6 +802,129 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         }
     }
 
+    /// Marks the start of processing an invocation, and schedules its cooperative cancellation
+    /// if an invocation timeout is configured
+    fn begin_invocation(
+        self: &Arc<Self>,
+        idempotency_key: IdempotencyKey,
+    ) -> Option<JoinHandle<()>> {
+        *self.current_invocation.write().unwrap() =
+            Some(InvocationCancellation::new(idempotency_key.clone()));
+
+        self.config().limits.invocation_timeout.map(|timeout| {
+            let worker = Arc::downgrade(self);
+            tokio::spawn(async move {
+                tokio::time::sleep(timeout).await;
+                if let Some(worker) = worker.upgrade() {
+                    debug!("Invocation {idempotency_key} timed out after {timeout:?}");
+                    worker
+                        .request_cancellation(
+                            idempotency_key,
+                            CancellationReason::InvocationTimeout,
+                        )
+                        .await;
+                }
+            })
+        })
+    }
+
+    /// Marks the end of processing the current invocation. Returns true if its cancellation was
+    /// requested by an interrupt, in which case the worker has to stop before it processes the
+    /// next invocation.
+    fn end_invocation(&self, invocation_timeout: Option<JoinHandle<()>>) -> bool {
+        if let Some(invocation_timeout) = invocation_timeout {
+            invocation_timeout.abort();
+        }
+        let invocation = self.current_invocation.write().unwrap().take();
+        self.invocation_finished.notify_waiters();
+        invocation
+            .is_some_and(|invocation| invocation.reason == Some(CancellationReason::Interrupt))
+    }
+
+    fn is_processing_invocation(&self, idempotency_key: &IdempotencyKey) -> bool {
+        self.current_invocation
+            .read()
+            .unwrap()
+            .as_ref()
+            .is_some_and(|invocation| invocation.idempotency_key == *idempotency_key)
+    }
+
+    async fn request_cancellation(
+        self: &Arc<Self>,
+        idempotency_key: IdempotencyKey,
+        reason: CancellationReason,
+    ) -> bool {
+        let requested = {
+            let mut current_invocation = self.current_invocation.write().unwrap();
+            match &mut *current_invocation {
+                Some(invocation)
+                    if invocation.idempotency_key == idempotency_key
+                        && invocation.reason.is_none() =>
+                {
+                    invocation.reason = Some(reason);
+                    true
+                }
+                _ => false,
+            }
+        };
+
+        if requested {
+            debug!("Requesting cancellation of invocation {idempotency_key} ({reason})");
+            self.oplog
+                .add_and_commit(OplogEntry::cancellation_requested(
+                    idempotency_key.clone(),
+                    reason,
+                ))
+                .await;
+
+            let grace_period = self.config().limits.cancellation_grace_period;
+            if !grace_period.is_zero() {
+                let worker = Arc::downgrade(self);
+                tokio::spawn(Self::interrupt_after_grace_period(
+                    worker,
+                    idempotency_key,
+                    grace_period,
+                ));
+            }
+        }
+        requested
+    }
+
+    /// Interrupts the worker when the cancelled invocation did not finish within the grace period.
+    ///
+    /// The worker is only interrupted while it is still processing the cancelled invocation, so an
+    /// invocation started after it is never affected. Invocations finishing within the grace period
+    /// are handled by the invocation loop (see `end_invocation`).
+    async fn interrupt_after_grace_period(
+        worker: Weak<Self>,
+        idempotency_key: IdempotencyKey,
+        grace_period: Duration,
+    ) {
+        if let Some(worker) = worker.upgrade() {
+            let finished = worker.invocation_finished.notified();
+            tokio::pin!(finished);
+            finished.as_mut().enable();
+
+            if worker.is_processing_invocation(&idempotency_key) {
+                tokio::select! {
+                    _ = tokio::time::sleep(grace_period) => {}
+                    _ = finished => {}
+                }
+            }
+
+            if worker.is_processing_invocation(&idempotency_key) {
+                debug!(
+                    "Invocation {idempotency_key} did not finish within the grace period of {grace_period:?}"
+                );
+                worker.set_interrupting(InterruptKind::Interrupt).await;
+                // Explicitly drop from the active worker cache - this will drop websocket connections etc.
+                worker
+                    .active_workers()
+                    .remove(&worker.owned_worker_id.worker_id);
+            }
+        }
+    }
+
     /// Enqueue invocation of an exported function
     async fn enqueue(
         &self,
@@ -1170,7 +1368,7 @@ impl RunningWorker {
 
     async fn create_instance<Ctx: WorkerCtx>(
         parent: Arc<Worker<Ctx>>,
-    ) -> Result<(Instance, async_mutex::Mutex<Store<Ctx>>), GolemError> {
+    ) -> Result<(Instance, async_mutex::Mutex<Store<Ctx>>, LinearMemories), GolemError> {
         let component_id = parent.owned_worker_id.component_id();
         let worker_metadata = parent.get_metadata().await?;
 
@@ -1198,9 +1396,77 @@ impl RunningWorker {
             .get(&parent.engine(), &component_id, component_version)
             .await?;
 
+        let memory_snapshot =
+            Self::restorable_memory_snapshot(&parent, &worker_metadata, &component_metadata).await;
+
+        let initial_memory_sizes = memory_snapshot
+            .as_ref()
+            .map(|snapshot| {
+                snapshot
+                    .memories
+                    .iter()
+                    .map(|memory| memory.len())
+                    .collect()
+            })
+            .unwrap_or_default();
+        let (mut instance, mut store, mut memories) = Self::instantiate(
+            &parent,
+            &worker_metadata,
+            &component,
+            &component_metadata,
+            initial_memory_sizes,
+        )
+        .await?;
+
+        let mut restored_oplog_index = None;
+        if let Some(memory_snapshot) = memory_snapshot {
+            // Safety: the instance has not been invoked yet
+            match unsafe { memories.write(&memory_snapshot.memories) } {
+                Ok(()) => {
+                    debug!(
+                        "Restored memory snapshot of {} at oplog index {}",
+                        parent.owned_worker_id, memory_snapshot.oplog_index
+                    );
+                    record_memory_snapshot_restored();
+                    restored_oplog_index = Some(memory_snapshot.oplog_index);
+
+                    // The oplog entries covered by the snapshot were checked when it was taken
+                    let mut progress = parent.memory_snapshot_progress.lock().await;
+                    progress.checked = progress.checked.max(memory_snapshot.oplog_index);
+                    progress.created_at = Some(memory_snapshot.created_at);
+                }
+                Err(err) => {
+                    warn!(
+                        "Failed to restore memory snapshot of {}, recovering by replaying the oplog: {err}",
+                        parent.owned_worker_id
+                    );
+                    (instance, store, memories) = Self::instantiate(
+                        &parent,
+                        &worker_metadata,
+                        &component,
+                        &component_metadata,
+                        Vec::new(),
+                    )
+                    .await?;
+                }
+            }
+        }
+        *parent.restored_memory_snapshot.write().unwrap() = restored_oplog_index;
+
+        let store = async_mutex::Mutex::new(store);
+        Ok((instance, store, memories))
+    }
+
+    async fn instantiate<Ctx: WorkerCtx>(
+        parent: &Arc<Worker<Ctx>>,
+        worker_metadata: &WorkerMetadata,
+        component: &Component,
+        component_metadata: &ComponentMetadata,
+        initial_memory_sizes: Vec<usize>,
+    ) -> Result<(Instance, Store<Ctx>, LinearMemories), GolemError> {
         let context = Ctx::create(
             OwnedWorkerId::new(&worker_metadata.account_id, &worker_metadata.worker_id),
-            component_metadata,
+            component_metadata.clone(),
             parent.promise_service(),
             parent.worker_service(),
             parent.worker_enumeration_service(),
@@ -1210,7 +1476,7 @@ impl RunningWorker {
             parent.active_workers(),
             parent.oplog_service(),
             parent.oplog.clone(),
-            Arc::downgrade(&parent),
+            Arc::downgrade(parent),
             parent.scheduler_service(),
             parent.rpc(),
             parent.worker_proxy(),
@@ -1250,7 +1516,7 @@ impl RunningWorker {
 
         store.limiter_async(|ctx| ctx.resource_limiter());
 
-        let instance_pre = parent.linker().instantiate_pre(&component).map_err(|e| {
+        let instance_pre = parent.linker().instantiate_pre(component).map_err(|e| {
             GolemError::worker_creation_failed(
                 parent.owned_worker_id.worker_id(),
                 format!(
@@ -1260,20 +1526,168 @@ impl RunningWorker {
             )
         })?;
 
-        let instance = instance_pre
-            .instantiate_async(&mut store)
+        let (instance, memories) = LinearMemories::instantiate(
+            initial_memory_sizes,
+            instance_pre.instantiate_async(&mut store),
+        )
+        .await;
+        let instance = instance.map_err(|e| {
+            GolemError::worker_creation_failed(
+                parent.owned_worker_id.worker_id(),
+                format!(
+                    "Failed to instantiate worker {}: {e}",
+                    parent.owned_worker_id
+                ),
+            )
+        })?;
+        Ok((instance, store, memories))
+    }
+
+    /// Stores a snapshot of the worker's memories after a successful invocation in the background,
+    /// so the next recovery does not have to replay the oplog up to this point.
+    ///
+    /// The snapshot is only taken if the memories are the whole state of the worker: the
+    /// component keeps no other state, and none of the oplog entries skipped when restoring it
+    /// left any state behind outside of the memories.
+    async fn save_memory_snapshot<Ctx: WorkerCtx>(
+        parent: &Worker<Ctx>,
+        store: &Store<Ctx>,
+        memories: &LinearMemories,
+    ) {
+        let config = &parent.config().memory_snapshot;
+        // No memories are collected if the engine was not configured for snapshots
+        if !config.enabled || memories.is_empty() {
+            return;
+        }
+
+        let mut progress = parent.memory_snapshot_progress.lock().await;
+        if progress.unrestorable
+            || progress
+                .pending
+                .as_ref()
+                .is_some_and(|pending| !pending.is_finished())
+            || progress
+                .last_saved
+                .is_some_and(|last_saved| last_saved.elapsed() < config.min_interval)
+        {
+            return;
+        }
+
+        let component_version = store.data().component_metadata().version;
+        match parent
+            .component_service()
+            .supports_memory_snapshots(&parent.owned_worker_id.component_id(), component_version)
             .await
-            .map_err(|e| {
-                GolemError::worker_creation_failed(
-                    parent.owned_worker_id.worker_id(),
-                    format!(
-                        "Failed to instantiate worker {}: {e}",
-                        parent.owned_worker_id
-                    ),
-                )
-            })?;
-        let store = async_mutex::Mutex::new(store);
-        Ok((instance, store))
+        {
+            Ok(true) => {}
+            Ok(false) => {
+                debug!(
+                    "Component of {} does not support memory snapshots",
+                    parent.owned_worker_id
+                );
+                progress.unrestorable = true;
+                return;
+            }
+            Err(err) => {
+                warn!(
+                    "Failed to check whether {} supports memory snapshots: {err}",
+                    parent.owned_worker_id
+                );
+                return;
+            }
+        }
+
+        // The entries covered by the snapshot are read back below
+        parent.oplog.commit(CommitLevel::DurableOnly).await;
+        let oplog_index = parent.oplog.current_oplog_index().await;
+        let mut idx = progress.checked.next();
+        while idx <= oplog_index {
+            if !is_restorable_entry(&parent.oplog.read(idx).await) {
+                debug!(
+                    "Oplog entry {idx} of {} can only be recovered by replaying it, no more memory snapshots are taken",
+                    parent.owned_worker_id
+                );
+                progress.unrestorable = true;
+                return;
+            }
+            idx = idx.next();
+        }
+        progress.checked = oplog_index;
+
+        let created_at = match progress.created_at {
+            Some(created_at) => created_at,
+            None => {
+                let created_at = parent.oplog.read(OplogIndex::INITIAL).await.timestamp();
+                progress.created_at = Some(created_at);
+                created_at
+            }
+        };
+        let snapshot = LinearMemorySnapshot {
+            created_at,
+            oplog_index,
+            component_version,
+            // Safety: the invocation has finished and the store is locked
+            memories: unsafe { memories.read() },
+        };
+
+        let memory_snapshot_service = parent.memory_snapshot_service();
+        let owned_worker_id = parent.owned_worker_id.clone();
+        progress.last_saved = Some(Instant::now());
+        progress.pending = Some(tokio::spawn(async move {
+            match memory_snapshot_service
+                .save(&owned_worker_id, snapshot)
+                .await
+            {
+                Ok(()) => record_memory_snapshot_saved(),
+                Err(err) => warn!("Failed to save memory snapshot of {owned_worker_id}: {err}"),
+            }
+        }));
+    }
+
+    /// Loads the worker's last memory snapshot if the worker can be recovered from it instead of
+    /// replaying the oplog up to the snapshot's oplog index
+    async fn restorable_memory_snapshot<Ctx: WorkerCtx>(
+        parent: &Worker<Ctx>,
+        worker_metadata: &WorkerMetadata,
+        component_metadata: &ComponentMetadata,
+    ) -> Option<LinearMemorySnapshot> {
+        let status = &worker_metadata.last_known_status;
+        if !parent.config().memory_snapshot.enabled
+            || component_metadata.component_type == ComponentType::Ephemeral
+            || !status.pending_updates.is_empty()
+        {
+            return None;
+        }
+
+        let snapshot = match parent
+            .memory_snapshot_service()
+            .restore(&parent.owned_worker_id)
+            .await
+        {
+            Ok(Some(snapshot)) => snapshot,
+            Ok(None) => return None,
+            Err(err) => {
+                warn!(
+                    "Failed to load the memory snapshot of {}: {err}",
+                    parent.owned_worker_id
+                );
+                return None;
+            }
+        };
+
+        // Snapshots left behind by a deleted worker with the same id are not used
+        let created_at = parent.oplog.read(OplogIndex::INITIAL).await.timestamp();
+        if snapshot.created_at == created_at
+            && snapshot.component_version == status.component_version
+            && snapshot.oplog_index <= parent.oplog.current_oplog_index().await
+            && !status
+                .deleted_regions
+                .is_in_deleted_region(snapshot.oplog_index)
+        {
+            Some(snapshot)
+        } else {
+            None
+        }
     }
 
     async fn invocation_loop<Ctx: WorkerCtx>(
@@ -1287,13 +1701,13 @@ impl RunningWorker {
         loop {
             debug!("Invocation queue loop creating the instance");
 
-            let (instance, store) = match Self::create_instance(parent.clone()).await {
-                Ok((instance, store)) => {
+            let (instance, store, memories) = match Self::create_instance(parent.clone()).await {
+                Ok((instance, store, memories)) => {
                     parent.events().publish(Event::WorkerLoaded {
                         worker_id: owned_worker_id.worker_id(),
                         result: Ok(()),
                     });
-                    (instance, store)
+                    (instance, store, memories)
                 }
                 Err(err) => {
                     warn!("Failed to start the worker: {err}");
@@ -1395,6 +1809,14 @@ impl RunningWorker {
                                         // the invocation writes the invocation start oplog entry
                                         store.data_mut().update_pending_invocations().await;
 
+                                        let invocation_timeout = store
+                                            .data()
+                                            .get_current_idempotency_key()
+                                            .await
+                                            .and_then(|idempotency_key| {
+                                                parent.begin_invocation(idempotency_key)
+                                            });
+
                                         let result = invoke_worker(
                                             full_function_name.clone(),
                                             function_input.clone(),
@@ -1403,7 +1825,10 @@ impl RunningWorker {
                                         )
                                         .await;
 
-                                        match result {
+                                        let stop_after_invocation =
+                                            parent.end_invocation(invocation_timeout);
+
+                                        let do_break = match result {
                                             Ok(InvokeResult::Succeeded {
                                                 output,
                                                 consumed_fuel,
@@ -1454,6 +1879,10 @@ impl RunningWorker {
                                                                         RetryDecision::None;
                                                                     true // stop after the invocation
                                                                 } else {
+                                                                    Self::save_memory_snapshot(
+                                                                        &parent, store, &memories,
+                                                                    )
+                                                                    .await;
                                                                     false // continue processing the queue
                                                                 }
                                                             }
@@ -1533,6 +1962,19 @@ impl RunningWorker {
                                                 final_decision = decision;
                                                 true // break
                                             }
+                                        };
+
+                                        if stop_after_invocation {
+                                            // The invocation was cancelled by an interrupt and finished within the
+                                            // grace period, so the worker stops before the next invocation. It stays
+                                            // idle, keeping the queued invocations until it gets resumed.
+                                            parent
+                                                .active_workers()
+                                                .remove(&parent.owned_worker_id.worker_id);
+                                            final_decision = RetryDecision::None;
+                                            true // break
+                                        } else {
+                                            do_break
                                         }
                                     }
                                     .instrument(span)
@@ -1791,6 +2233,37 @@ impl InvocationResult {
     }
 }
 
+/// Memory snapshot bookkeeping of a worker
+#[derive(Debug, Default)]
+struct MemorySnapshotProgress {
+    /// All oplog entries up to this index can be skipped by restoring a memory snapshot
+    checked: OplogIndex,
+    /// Set once the memories are known to not be the whole state of the worker anymore
+    unrestorable: bool,
+    /// Timestamp of the worker's `Create` oplog entry
+    created_at: Option<Timestamp>,
+    last_saved: Option<Instant>,
+    pending: Option<JoinHandle<()>>,
+}
+
+/// Cooperative cancellation state of the invocation the worker is currently processing
+#[derive(Debug)]
+struct InvocationCancellation {
+    idempotency_key: IdempotencyKey,
+    reason: Option<CancellationReason>,
+    acknowledged: bool,
+}
+
+impl InvocationCancellation {
+    fn new(idempotency_key: IdempotencyKey) -> Self {
+        Self {
+            idempotency_key,
+            reason: None,
+            acknowledged: false,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub enum RetryDecision {
     /// Immediately retry by recreating the instance using the existing permits
@@ -2022,6 +2495,8 @@ fn calculate_latest_worker_status(
             OplogEntry::Restart { .. } => {
                 result = WorkerStatus::Idle;
             }
+            OplogEntry::CancellationRequested { .. } => {}
+            OplogEntry::CancellationAcknowledged { .. } => {}
         }
     }
     result
@@ -35,7 +35,7 @@ use golem_common::model::{
     StringFilterComparator, TargetWorkerId, Timestamp, WorkerFilter, WorkerId, WorkerMetadata,
     WorkerResourceDescription, WorkerStatus,
 };
-use golem_wasm_rpc::Value;
+use golem_wasm_rpc::{IntoValue, Value};
 
 use crate::common::{start, TestContext, TestWorkerExecutor};
 use crate::{LastUniqueId, Tracing, WorkerExecutorTestDependencies};
@@ -485,6 +485,61 @@ async fn promise(
     check!(result == Ok(vec![Value::List(vec![Value::U8(42)])]));
 }
 
+#[test]
+#[tracing::instrument]
+async fn promise_created_by_worker_can_be_completed_once(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor.store_component("js-4").await;
+    let worker_id = executor.start_worker(&component_id, "js-4-promise").await;
+
+    let promise_id = executor.create_promise(&worker_id).await;
+    let completed = executor.complete_promise(&promise_id, vec![42]).await;
+    let completed_again = executor.complete_promise(&promise_id, vec![43]).await;
+
+    drop(executor);
+
+    check!(promise_id.worker_id == worker_id);
+    check!(completed);
+    check!(!completed_again);
+}
+
+#[test]
+#[tracing::instrument]
+async fn await_promise_result_returns_the_completed_payload(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor.store_component("promise").await;
+    let worker_id = executor.start_worker(&component_id, "promise-await").await;
+
+    let promise_id = executor.create_promise(&worker_id).await;
+    let result = executor
+        .await_promise_result(
+            &worker_id,
+            "golem:it/api.{await-promise}",
+            vec![promise_id.clone().into_value()],
+            &promise_id,
+            vec![42],
+        )
+        .await;
+    let completed_again = executor.complete_promise(&promise_id, vec![43]).await;
+
+    drop(executor);
+
+    check!(result == Ok(vec![Value::List(vec![Value::U8(42)])]));
+    check!(!completed_again);
+}
+
 #[test]
 #[tracing::instrument]
 async fn get_self_uri(
@@ -1172,6 +1227,7 @@ async fn error_handling_when_worker_is_invoked_with_fewer_than_expected_paramete
         .await;
 
     let failure = executor
+        .without_grpc_retries()
         .invoke_and_await(&worker_id, "golem:it/api.{echo}", vec![])
         .await;
     drop(executor);
@@ -1193,6 +1249,7 @@ async fn error_handling_when_worker_is_invoked_with_more_than_expected_parameter
         .await;
 
     let failure = executor
+        .without_grpc_retries()
         .invoke_and_await(
             &worker_id,
             "golem:it/api.{echo}",
@@ -1478,6 +1535,7 @@ async fn trying_to_use_an_old_wasm_provides_good_error_message(
 
     let component_id = executor.store_component_unverified("old-component").await;
     let result = executor
+        .without_grpc_retries()
         .try_start_worker(&component_id, "old-component-1")
         .await;
 
@@ -1519,7 +1577,10 @@ async fn trying_to_use_a_wasm_that_wasmtime_cannot_load_provides_good_error_mess
         file.flush().expect("Failed to flush component file");
     }
 
-    let result = executor.try_start_worker(&component_id, "bad-wasm-1").await;
+    let result = executor
+        .without_grpc_retries()
+        .try_start_worker(&component_id, "bad-wasm-1")
+        .await;
 
     check!(result.is_err());
     check!(is_worker_execution_error(
@@ -1577,7 +1638,10 @@ async fn trying_to_use_a_wasm_that_wasmtime_cannot_load_provides_good_error_mess
     }
 
     // trying to invoke the previously created worker
-    let result = executor.invoke_and_await(&worker_id, "run", vec![]).await;
+    let result = executor
+        .without_grpc_retries()
+        .invoke_and_await(&worker_id, "run", vec![])
+        .await;
 
     check!(result.is_err());
     check!(is_worker_execution_error(
@@ -2631,7 +2695,10 @@ async fn invoke_with_non_existing_function(
         .await;
 
     // First we invoke a function that does not exist and expect a failure
-    let failure = executor.invoke_and_await(&worker_id, "WRONG", vec![]).await;
+    let failure = executor
+        .without_grpc_retries()
+        .invoke_and_await(&worker_id, "WRONG", vec![])
+        .await;
 
     // Then we invoke an existing function, to prove the worker should not be in failed state
     let success = executor
@@ -2674,6 +2741,7 @@ async fn stderr_returned_for_failed_component(
         .await;
 
     let result2 = executor
+        .without_grpc_retries()
         .invoke_and_await(
             &worker_id,
             "golem:component/api.{add}",
@@ -2682,6 +2750,7 @@ async fn stderr_returned_for_failed_component(
         .await;
 
     let result3 = executor
+        .without_grpc_retries()
         .invoke_and_await(&worker_id, "golem:component/api.{get}", vec![])
         .await;
 
@@ -658,6 +658,12 @@ async fn create_buckets(host_port: u16, config: &S3BlobStorageConfig) {
         .send()
         .await
         .unwrap();
+    client
+        .create_bucket()
+        .bucket(&config.memory_snapshot_bucket)
+        .send()
+        .await
+        .unwrap();
     for bucket in &config.compressed_oplog_buckets {
         client.create_bucket().bucket(bucket).send().await.unwrap();
     }
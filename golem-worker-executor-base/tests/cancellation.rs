@@ -0,0 +1,253 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use test_r::{inherit_test_dep, test};
+
+use std::time::Duration;
+
+use assert2::check;
+
+use crate::common::{start, start_customized, TestContext};
+use crate::{LastUniqueId, Tracing, WorkerExecutorTestDependencies};
+use golem_common::model::oplog::{CancellationReason, OplogIndex};
+use golem_common::model::public_oplog::{CancellationRequestedParameters, PublicOplogEntry};
+use golem_common::model::{IdempotencyKey, WorkerStatus};
+use golem_test_framework::dsl::{worker_error_message, TestDslUnsafe};
+use golem_wasm_rpc::Value;
+use golem_worker_executor_base::services::golem_config::Limits;
+
+inherit_test_dep!(WorkerExecutorTestDependencies);
+inherit_test_dep!(LastUniqueId);
+inherit_test_dep!(Tracing);
+
+fn cancellation_requests(oplog: &[PublicOplogEntry]) -> Vec<CancellationReason> {
+    oplog
+        .iter()
+        .filter_map(|entry| match entry {
+            PublicOplogEntry::CancellationRequested(CancellationRequestedParameters {
+                reason,
+                ..
+            }) => Some(*reason),
+            _ => None,
+        })
+        .collect()
+}
+
+fn cancellation_acknowledgements(oplog: &[PublicOplogEntry]) -> usize {
+    oplog
+        .iter()
+        .filter(|entry| matches!(entry, PublicOplogEntry::CancellationAcknowledged(_)))
+        .count()
+}
+
+fn exported_function_invocations(oplog: &[PublicOplogEntry]) -> usize {
+    oplog
+        .iter()
+        .filter(|entry| matches!(entry, PublicOplogEntry::ExportedFunctionInvoked(_)))
+        .count()
+}
+
+#[test]
+#[tracing::instrument]
+async fn interrupt_is_cancelled_within_grace_period(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start_customized(
+        deps,
+        &context,
+        None,
+        Limits {
+            cancellation_grace_period: Duration::from_secs(10),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    let component_id = executor.store_component("cancellation").await;
+    let worker_id = executor.start_worker(&component_id, "cancellation-1").await;
+
+    let executor_clone = executor.clone();
+    let worker_id_clone = worker_id.clone();
+    let fiber = tokio::spawn(async move {
+        executor_clone
+            .invoke_and_await(
+                &worker_id_clone,
+                "golem:it/api.{poll-until-cancelled}",
+                vec![Value::U64(1000)],
+            )
+            .await
+    });
+
+    tokio::time::sleep(Duration::from_secs(2)).await;
+
+    // Queued behind the cancelled invocation, it must not run once the worker got interrupted
+    let _ = executor
+        .invoke(&worker_id, "golem:it/api.{get-last-result}", vec![])
+        .await;
+
+    let start = tokio::time::Instant::now();
+    let _ = executor.interrupt(&worker_id).await;
+    let result = fiber.await.unwrap();
+    let elapsed = start.elapsed();
+
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    let (metadata, _) = executor.get_worker_metadata(&worker_id).await.unwrap();
+    let oplog = executor.get_oplog(&worker_id, OplogIndex::INITIAL).await;
+
+    drop(executor);
+
+    // The invocation observed the cancellation and finished on its own, then the worker stopped
+    // without processing the queued invocation
+    let iterations = match result.as_deref() {
+        Ok([Value::U64(iterations)]) => *iterations,
+        _ => panic!("unexpected result: {:?}", result),
+    };
+    check!(iterations < 1000);
+    check!(elapsed < Duration::from_secs(10));
+    check!(metadata.last_known_status.status == WorkerStatus::Idle);
+    check!(exported_function_invocations(&oplog) == 1);
+    check!(cancellation_requests(&oplog) == vec![CancellationReason::Interrupt]);
+    check!(cancellation_acknowledgements(&oplog) == 1);
+}
+
+#[test]
+#[tracing::instrument]
+async fn interrupt_after_grace_period(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start_customized(
+        deps,
+        &context,
+        None,
+        Limits {
+            cancellation_grace_period: Duration::from_secs(2),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    let component_id = executor.store_component("cancellation").await;
+    let worker_id = executor.start_worker(&component_id, "cancellation-2").await;
+
+    let executor_clone = executor.clone();
+    let worker_id_clone = worker_id.clone();
+    let fiber = tokio::spawn(async move {
+        executor_clone
+            .invoke_and_await(
+                &worker_id_clone,
+                "golem:it/api.{ignore-cancellation}",
+                vec![Value::U64(600)],
+            )
+            .await
+    });
+
+    tokio::time::sleep(Duration::from_secs(2)).await;
+
+    let start = tokio::time::Instant::now();
+    let _ = executor.interrupt(&worker_id).await;
+    let result = fiber.await.unwrap();
+    let elapsed = start.elapsed();
+
+    let (metadata, _) = executor.get_worker_metadata(&worker_id).await.unwrap();
+    let oplog = executor.get_oplog(&worker_id, OplogIndex::INITIAL).await;
+
+    drop(executor);
+
+    // The invocation ignored the cancellation, so the worker got interrupted when the grace period elapsed
+    check!(result.is_err());
+    check!(worker_error_message(&result.err().unwrap()).contains("Interrupted via the Golem API"));
+    check!(elapsed >= Duration::from_secs(2));
+    check!(elapsed < Duration::from_secs(30));
+    check!(metadata.last_known_status.status == WorkerStatus::Interrupted);
+    check!(cancellation_requests(&oplog) == vec![CancellationReason::Interrupt]);
+    check!(cancellation_acknowledgements(&oplog) == 0);
+}
+
+#[test]
+#[tracing::instrument]
+async fn invocation_timeout_is_replayed(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start_customized(
+        deps,
+        &context,
+        None,
+        Limits {
+            invocation_timeout: Some(Duration::from_secs(2)),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    let component_id = executor.store_component("cancellation").await;
+    let worker_id = executor.start_worker(&component_id, "cancellation-3").await;
+
+    let idempotency_key = IdempotencyKey::fresh();
+    let result1 = executor
+        .invoke_and_await_with_key(
+            &worker_id,
+            &idempotency_key,
+            "golem:it/api.{poll-until-cancelled}",
+            vec![Value::U64(1000)],
+        )
+        .await
+        .unwrap();
+
+    let oplog = executor.get_oplog(&worker_id, OplogIndex::INITIAL).await;
+
+    drop(executor);
+
+    // Restarting without the timeout - the worker has to observe the cancellation at the same
+    // point while replaying, or its state would diverge from the first run
+    let executor = start(deps, &context).await.unwrap();
+
+    let last_result = executor
+        .invoke_and_await(&worker_id, "golem:it/api.{get-last-result}", vec![])
+        .await
+        .unwrap();
+    let result2 = executor
+        .invoke_and_await_with_key(
+            &worker_id,
+            &idempotency_key,
+            "golem:it/api.{poll-until-cancelled}",
+            vec![Value::U64(1000)],
+        )
+        .await
+        .unwrap();
+
+    drop(executor);
+
+    let iterations = match result1.as_slice() {
+        [Value::U64(iterations)] => *iterations,
+        _ => panic!("unexpected result: {:?}", result1),
+    };
+    check!(iterations > 0);
+    check!(iterations < 1000);
+    check!(cancellation_requests(&oplog) == vec![CancellationReason::InvocationTimeout]);
+    check!(cancellation_acknowledgements(&oplog) == 1);
+    check!(last_result == vec![Value::U64(iterations)]);
+    check!(result2 == result1);
+}
@@ -21,8 +21,8 @@ use golem_worker_executor_base::error::GolemError;
 use golem_worker_executor_base::services::golem_config::{
     BlobStorageConfig, CompiledComponentServiceConfig, CompiledComponentServiceEnabledConfig,
     ComponentServiceConfig, ComponentServiceLocalConfig, GolemConfig, IndexedStorageConfig,
-    KeyValueStorageConfig, LocalFileSystemBlobStorageConfig, MemoryConfig,
-    ShardManagerServiceConfig, WorkerServiceGrpcConfig,
+    KeyValueStorageConfig, Limits, LocalFileSystemBlobStorageConfig, ShardManagerServiceConfig,
+    WorkerServiceGrpcConfig,
 };
 
 use golem_worker_executor_base::durable_host::{
@@ -35,6 +35,7 @@ use golem_worker_executor_base::services::active_workers::ActiveWorkers;
 use golem_worker_executor_base::services::blob_store::BlobStoreService;
 use golem_worker_executor_base::services::component::{ComponentMetadata, ComponentService};
 use golem_worker_executor_base::services::key_value::KeyValueService;
+use golem_worker_executor_base::services::memory_snapshot::MemorySnapshotService;
 use golem_worker_executor_base::services::oplog::{Oplog, OplogService};
 use golem_worker_executor_base::services::promise::PromiseService;
 use golem_worker_executor_base::services::scheduler::SchedulerService;
@@ -73,7 +74,7 @@ use golem_test_framework::components::worker_executor_cluster::WorkerExecutorClu
 use golem_test_framework::config::TestDependencies;
 use golem_test_framework::dsl::to_worker_metadata;
 use golem_worker_executor_base::preview2::golem;
-use golem_worker_executor_base::preview2::golem::api1_1_0_rc1;
+use golem_worker_executor_base::preview2::golem::{api1_1_0_rc1, api1_1_0_rc2};
 use golem_worker_executor_base::services::events::Events;
 use golem_worker_executor_base::services::rpc::{
     DirectWorkerInvocationRpc, RemoteInvocationRpc, Rpc,
@@ -282,6 +283,27 @@ pub async fn start_limited(
     deps: &WorkerExecutorTestDependencies,
     context: &TestContext,
     system_memory_override: Option<u64>,
+) -> anyhow::Result<TestWorkerExecutor> {
+    start_customized(deps, context, system_memory_override, Limits::default()).await
+}
+
+pub async fn start_customized(
+    deps: &WorkerExecutorTestDependencies,
+    context: &TestContext,
+    system_memory_override: Option<u64>,
+    limits: Limits,
+) -> anyhow::Result<TestWorkerExecutor> {
+    start_configured(deps, context, |config| {
+        config.memory.system_memory_override = system_memory_override;
+        config.limits = limits;
+    })
+    .await
+}
+
+pub async fn start_configured(
+    deps: &WorkerExecutorTestDependencies,
+    context: &TestContext,
+    configure: impl FnOnce(&mut GolemConfig),
 ) -> anyhow::Result<TestWorkerExecutor> {
     let redis = deps.redis();
     let redis_monitor = deps.redis_monitor();
@@ -290,7 +312,7 @@ pub async fn start_limited(
     println!("Using Redis on port {}", redis.public_port());
 
     let prometheus = golem_worker_executor_base::metrics::register_all();
-    let config = GolemConfig {
+    let mut config = GolemConfig {
         key_value_storage: KeyValueStorageConfig::Redis(RedisConfig {
             port: redis.public_port(),
             key_prefix: context.redis_prefix(),
@@ -314,12 +336,9 @@ pub async fn start_limited(
             port: context.grpc_port(),
             access_token: "03494299-B515-4427-8C37-4C1C915679B7".to_string(),
         },
-        memory: MemoryConfig {
-            system_memory_override,
-            ..Default::default()
-        },
         ..Default::default()
     };
+    configure(&mut config);
 
     let handle = Handle::current();
 
@@ -763,6 +782,7 @@ impl Bootstrap<TestWorkerCtx> for ServerBootstrap {
         worker_activator: Arc<dyn WorkerActivator + Send + Sync>,
         oplog_service: Arc<dyn OplogService + Send + Sync>,
         scheduler_service: Arc<dyn SchedulerService + Send + Sync>,
+        memory_snapshot_service: Arc<dyn MemorySnapshotService + Send + Sync>,
         worker_proxy: Arc<dyn WorkerProxy + Send + Sync>,
         events: Arc<Events>,
     ) -> anyhow::Result<All<TestWorkerCtx>> {
@@ -787,6 +807,7 @@ impl Bootstrap<TestWorkerCtx> for ServerBootstrap {
             blob_store_service.clone(),
             oplog_service.clone(),
             scheduler_service.clone(),
+            memory_snapshot_service.clone(),
             worker_activator.clone(),
             events.clone(),
             (),
@@ -809,6 +830,7 @@ impl Bootstrap<TestWorkerCtx> for ServerBootstrap {
             oplog_service,
             rpc,
             scheduler_service,
+            memory_snapshot_service,
             worker_activator,
             worker_proxy,
             events.clone(),
@@ -820,6 +842,7 @@ impl Bootstrap<TestWorkerCtx> for ServerBootstrap {
         let mut linker = create_linker(engine, get_durable_ctx)?;
         api0_2_0::host::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
         api1_1_0_rc1::host::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
+        api1_1_0_rc2::cancellation::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
         golem_wasm_rpc::golem::rpc::types::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
         Ok(linker)
     }
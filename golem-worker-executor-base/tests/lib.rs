@@ -43,6 +43,7 @@ mod common;
 pub mod api;
 pub mod blob_storage;
 pub mod blobstore;
+pub mod cancellation;
 pub mod compatibility;
 pub mod guest_languages1;
 pub mod guest_languages2;
@@ -52,6 +53,7 @@ pub mod indexed_storage;
 pub mod key_value_storage;
 pub mod keyvalue;
 pub mod measure_test_component_mem;
+pub mod memory_snapshot;
 pub mod observability;
 pub mod rust_rpc;
 pub mod scalability;
@@ -69,7 +71,9 @@ tag_suite!(keyvalue, group1);
 tag_suite!(guest_languages1, group2);
 
 tag_suite!(transactions, group3);
+tag_suite!(cancellation, group3);
 tag_suite!(wasi, group3);
+tag_suite!(memory_snapshot, group3);
 
 tag_suite!(scalability, group4);
 tag_suite!(hot_update, group4);
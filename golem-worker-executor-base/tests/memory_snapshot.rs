@@ -0,0 +1,118 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use test_r::{inherit_test_dep, test};
+
+use std::time::Duration;
+
+use assert2::check;
+
+use crate::common::{start_configured, TestContext, TestWorkerExecutor};
+use crate::{LastUniqueId, Tracing, WorkerExecutorTestDependencies};
+use golem_test_framework::dsl::TestDslUnsafe;
+use golem_wasm_rpc::Value;
+use golem_worker_executor_base::services::golem_config::MemorySnapshotConfig;
+use golem_worker_executor_base::services::memory_snapshot::supports_memory_snapshots;
+
+inherit_test_dep!(WorkerExecutorTestDependencies);
+inherit_test_dep!(LastUniqueId);
+inherit_test_dep!(Tracing);
+
+async fn start_with_memory_snapshots(
+    deps: &WorkerExecutorTestDependencies,
+    context: &TestContext,
+) -> anyhow::Result<TestWorkerExecutor> {
+    start_configured(deps, context, |config| {
+        config.memory_snapshot = MemorySnapshotConfig {
+            enabled: true,
+            min_interval: Duration::ZERO,
+            ..Default::default()
+        };
+    })
+    .await
+}
+
+fn memory_snapshot_total(operation: &str) -> f64 {
+    prometheus::default_registry()
+        .gather()
+        .iter()
+        .filter(|family| family.get_name() == "memory_snapshot_total")
+        .flat_map(|family| family.get_metric())
+        .filter(|metric| {
+            metric
+                .get_label()
+                .iter()
+                .any(|label| label.get_value() == operation)
+        })
+        .map(|metric| metric.get_counter().get_value())
+        .sum()
+}
+
+#[test]
+fn only_memory_only_components_support_memory_snapshots() {
+    let supported = |name: &str| {
+        let bytes = std::fs::read(format!("../test-components/{name}.wasm")).unwrap();
+        supports_memory_snapshots(&bytes).unwrap()
+    };
+
+    check!(supported("memory-snapshot"));
+    check!(!supported("shopping-cart"));
+    check!(!supported("shopping-cart-resource"));
+}
+
+#[test]
+#[tracing::instrument]
+async fn worker_is_recovered_from_memory_snapshot(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start_with_memory_snapshots(deps, &context).await.unwrap();
+
+    let component_id = executor.store_component("memory-snapshot").await;
+    let worker_id = executor
+        .start_worker(&component_id, "memory-snapshot-1")
+        .await;
+
+    let saves_before = memory_snapshot_total("save");
+    for value in 1..=10u64 {
+        executor
+            .invoke_and_await(&worker_id, "golem:it/api.{add}", vec![Value::U64(value)])
+            .await
+            .unwrap();
+    }
+
+    // Snapshots are written in the background
+    let mut attempts = 0;
+    while memory_snapshot_total("save") <= saves_before && attempts < 50 {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        attempts += 1;
+    }
+    check!(memory_snapshot_total("save") > saves_before);
+
+    drop(executor);
+    let executor = start_with_memory_snapshots(deps, &context).await.unwrap();
+
+    let restores_before = memory_snapshot_total("restore");
+    let result = executor
+        .invoke_and_await(&worker_id, "golem:it/api.{get}", vec![])
+        .await
+        .unwrap();
+
+    drop(executor);
+
+    check!(result == vec![Value::U64(55)]);
+    check!(memory_snapshot_total("restore") > restores_before);
+}
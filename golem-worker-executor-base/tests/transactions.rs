@@ -19,6 +19,7 @@ use crate::{LastUniqueId, Tracing, WorkerExecutorTestDependencies};
 use assert2::check;
 use bytes::Bytes;
 use golem_common::model::{IdempotencyKey, TargetWorkerId};
+use golem_test_framework::config::TestDependencies;
 use golem_test_framework::dsl::{
     drain_connection, stdout_event_starting_with, stdout_events, worker_error_message,
     TestDslUnsafe,
@@ -237,6 +238,7 @@ async fn set_retry_policy(
 
     let start = SystemTime::now();
     let result1 = executor
+        .without_grpc_retries()
         .invoke_and_await(
             &worker_id,
             "golem:it/api.{fail-with-custom-max-retries}",
@@ -246,6 +248,7 @@ async fn set_retry_policy(
     let elapsed = start.elapsed().unwrap();
 
     let result2 = executor
+        .without_grpc_retries()
         .invoke_and_await(
             &worker_id,
             "golem:it/api.{fail-with-custom-max-retries}",
@@ -469,6 +472,7 @@ async fn golem_rust_set_retry_policy(
 
     let start = SystemTime::now();
     let result1 = executor
+        .without_grpc_retries()
         .invoke_and_await(
             &worker_id,
             "golem:it/api.{fail-with-custom-max-retries}",
@@ -478,6 +482,7 @@ async fn golem_rust_set_retry_policy(
     let elapsed = start.elapsed().unwrap();
 
     let result2 = executor
+        .without_grpc_retries()
         .invoke_and_await(
             &worker_id,
             "golem:it/api.{fail-with-custom-max-retries}",
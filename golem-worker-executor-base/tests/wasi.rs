@@ -24,6 +24,7 @@ use crate::common::{start, TestContext};
 use crate::{LastUniqueId, Tracing, WorkerExecutorTestDependencies};
 use assert2::{assert, check};
 use golem_common::model::{IdempotencyKey, WorkerStatus};
+use golem_test_framework::config::TestDependencies;
 use golem_test_framework::dsl::{
     drain_connection, stderr_events, stdout_events, worker_error_message, TestDslUnsafe,
 };
@@ -104,7 +105,10 @@ async fn read_stdin(
     let component_id = executor.store_component("read-stdin").await;
     let worker_id = executor.start_worker(&component_id, "read-stdin-1").await;
 
-    let result = executor.invoke_and_await(&worker_id, "run", vec![]).await;
+    let result = executor
+        .without_grpc_retries()
+        .invoke_and_await(&worker_id, "run", vec![])
+        .await;
 
     drop(executor);
 
@@ -822,6 +826,7 @@ async fn failing_worker(
         .await;
 
     let result2 = executor
+        .without_grpc_retries()
         .invoke_and_await(
             &worker_id,
             "golem:component/api.{add}",
@@ -830,6 +835,7 @@ async fn failing_worker(
         .await;
 
     let result3 = executor
+        .without_grpc_retries()
         .invoke_and_await(&worker_id, "golem:component/api.{get}", vec![])
         .await;
 
@@ -21,13 +21,14 @@ use crate::context::Context;
 use crate::services::AdditionalDeps;
 use async_trait::async_trait;
 use golem_worker_executor_base::durable_host::DurableWorkerCtx;
-use golem_worker_executor_base::preview2::golem::{api0_2_0, api1_1_0_rc1};
+use golem_worker_executor_base::preview2::golem::{api0_2_0, api1_1_0_rc1, api1_1_0_rc2};
 use golem_worker_executor_base::services::active_workers::ActiveWorkers;
 use golem_worker_executor_base::services::blob_store::BlobStoreService;
 use golem_worker_executor_base::services::component::ComponentService;
 use golem_worker_executor_base::services::events::Events;
 use golem_worker_executor_base::services::golem_config::GolemConfig;
 use golem_worker_executor_base::services::key_value::KeyValueService;
+use golem_worker_executor_base::services::memory_snapshot::MemorySnapshotService;
 use golem_worker_executor_base::services::oplog::OplogService;
 use golem_worker_executor_base::services::promise::PromiseService;
 use golem_worker_executor_base::services::rpc::{DirectWorkerInvocationRpc, RemoteInvocationRpc};
@@ -79,6 +80,7 @@ impl Bootstrap<Context> for ServerBootstrap {
         worker_activator: Arc<dyn WorkerActivator + Send + Sync>,
         oplog_service: Arc<dyn OplogService + Send + Sync>,
         scheduler_service: Arc<dyn SchedulerService + Send + Sync>,
+        memory_snapshot_service: Arc<dyn MemorySnapshotService + Send + Sync>,
         worker_proxy: Arc<dyn WorkerProxy + Send + Sync>,
         events: Arc<Events>,
     ) -> anyhow::Result<All<Context>> {
@@ -105,6 +107,7 @@ impl Bootstrap<Context> for ServerBootstrap {
             blob_store_service.clone(),
             oplog_service.clone(),
             scheduler_service.clone(),
+            memory_snapshot_service.clone(),
             worker_activator.clone(),
             events.clone(),
             additional_deps.clone(),
@@ -128,6 +131,7 @@ impl Bootstrap<Context> for ServerBootstrap {
             oplog_service,
             rpc,
             scheduler_service,
+            memory_snapshot_service,
             worker_activator.clone(),
             worker_proxy.clone(),
             events.clone(),
@@ -139,6 +143,7 @@ impl Bootstrap<Context> for ServerBootstrap {
         let mut linker = create_linker(engine, get_durable_ctx)?;
         api0_2_0::host::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
         api1_1_0_rc1::host::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
+        api1_1_0_rc2::cancellation::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
         golem_wasm_rpc::golem::rpc::types::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
         Ok(linker)
     }
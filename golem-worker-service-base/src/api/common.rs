@@ -183,6 +183,9 @@ mod conversion {
                 ApiDefinitionServiceError::RibCompilationErrors(_) => {
                     ApiEndpointError::bad_request(error)
                 }
+                ApiDefinitionServiceError::RouteExamplesFailed(_) => {
+                    ApiEndpointError::bad_request(error)
+                }
                 ApiDefinitionServiceError::InternalRepoError(_) => {
                     ApiEndpointError::internal(error)
                 }
@@ -278,6 +281,11 @@ mod conversion {
                         errors: vec![error.to_safe_string()],
                     })),
                 },
+                ApiDefinitionServiceError::RouteExamplesFailed(failures) => ApiDefinitionError {
+                    error: Some(api_definition_error::Error::BadRequest(ErrorsBody {
+                        errors: failures,
+                    })),
+                },
                 ApiDefinitionServiceError::ComponentNotFoundError(error) => ApiDefinitionError {
                     error: Some(api_definition_error::Error::NotFound(ErrorBody {
                         error: format!(
@@ -2,7 +2,9 @@ use std::future::Future;
 use std::sync::Arc;
 
 use crate::api_definition::http::CompiledHttpApiDefinition;
-use crate::worker_service_rib_interpreter::{DefaultRibInterpreter, WorkerServiceRibInterpreter};
+use crate::worker_service_rib_interpreter::{
+    DefaultRibInterpreter, RibLogContext, WorkerServiceRibInterpreter,
+};
 use futures_util::FutureExt;
 use hyper::header::HOST;
 use poem::http::StatusCode;
@@ -81,7 +83,7 @@ impl CustomHttpRequestApi {
             req_body: json_request_body,
         };
 
-        let possible_api_definitions = match self
+        let deployed_api_definitions = match self
             .api_definition_lookup_service
             .get(input_http_request.clone())
             .await
@@ -99,10 +101,20 @@ impl CustomHttpRequestApi {
         };
 
         match input_http_request
-            .resolve_worker_binding(possible_api_definitions)
+            .resolve_worker_binding(&deployed_api_definitions.definitions)
             .await
         {
-            Ok(resolved_worker_binding) => {
+            Ok(mut resolved_worker_binding) => {
+                if deployed_api_definitions.rib_logging {
+                    resolved_worker_binding.rib_log_context = Some(RibLogContext {
+                        deployment: host.clone(),
+                        route: format!(
+                            "{} {}",
+                            input_http_request.req_method, input_http_request.input_path.base_path
+                        ),
+                    });
+                }
+
                 resolved_worker_binding
                     .interpret_response_mapping(&self.worker_service_rib_interpreter)
                     .await
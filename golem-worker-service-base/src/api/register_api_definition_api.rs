@@ -2,6 +2,7 @@ use golem_api_grpc::proto::golem::apidefinition as grpc_apidefinition;
 use golem_service_base::model::VersionedComponentId;
 use poem_openapi::*;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::result::Result;
 use std::time::SystemTime;
 
@@ -10,7 +11,7 @@ use crate::api_definition::http::{
 };
 use crate::api_definition::{ApiDefinitionId, ApiSite, ApiVersion};
 use crate::worker_binding::CompiledGolemWorkerBinding;
-use rib::{Expr, RibInputTypeInfo};
+use rib::{Expr, RibInputTypeInfo, RibVersion};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
 #[serde(rename_all = "camelCase")]
@@ -18,6 +19,7 @@ use rib::{Expr, RibInputTypeInfo};
 pub struct ApiDeploymentRequest {
     pub api_definitions: Vec<ApiDefinitionInfo>,
     pub site: ApiSite,
+    pub rib_logging: Option<bool>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
@@ -26,6 +28,7 @@ pub struct ApiDeploymentRequest {
 pub struct ApiDeployment {
     pub api_definitions: Vec<ApiDefinitionInfo>,
     pub site: ApiSite,
+    pub rib_logging: bool,
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
@@ -49,6 +52,9 @@ pub struct HttpApiDefinitionRequest {
     pub routes: Vec<Route>,
     #[serde(default)]
     pub draft: bool,
+    #[serde(default = "latest_rib_version")]
+    #[oai(default = "latest_rib_version")]
+    pub rib_version: RibVersion,
 }
 
 // Mostly this data structures that represents the actual incoming request
@@ -63,9 +69,22 @@ pub struct HttpApiDefinition {
     pub routes: Vec<Route>,
     #[serde(default)]
     pub draft: bool,
+    #[serde(default = "legacy_rib_version")]
+    #[oai(default = "legacy_rib_version")]
+    pub rib_version: RibVersion,
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
+// New definitions that don't declare a Rib version are written against the latest one
+fn latest_rib_version() -> RibVersion {
+    RibVersion::LATEST
+}
+
+// Definitions read back without a Rib version were written before it was introduced
+fn legacy_rib_version() -> RibVersion {
+    RibVersion::LEGACY
+}
+
 // HttpApiDefinitionWithTypeInfo is CompiledHttpApiDefinition minus rib-byte-code
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
 #[serde(rename_all = "camelCase")]
@@ -76,6 +95,9 @@ pub struct HttpApiDefinitionWithTypeInfo {
     pub routes: Vec<RouteWithTypeInfo>,
     #[serde(default)]
     pub draft: bool,
+    #[serde(default = "legacy_rib_version")]
+    #[oai(default = "legacy_rib_version")]
+    pub rib_version: RibVersion,
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
@@ -88,6 +110,7 @@ impl From<CompiledHttpApiDefinition> for HttpApiDefinitionWithTypeInfo {
             version: value.version,
             routes,
             draft: value.draft,
+            rib_version: value.rib_version,
             created_at: Some(value.created_at),
         }
     }
@@ -98,6 +121,9 @@ pub struct Route {
     pub method: MethodPattern,
     pub path: String,
     pub binding: GolemWorkerBinding,
+    #[serde(default)]
+    #[oai(default)]
+    pub examples: Vec<RouteExample>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
@@ -105,6 +131,7 @@ pub struct RouteWithTypeInfo {
     pub method: MethodPattern,
     pub path: String,
     pub binding: GolemWorkerBindingWithTypeInfo,
+    pub examples: Vec<RouteExample>,
 }
 
 impl From<CompiledRoute> for RouteWithTypeInfo {
@@ -112,10 +139,136 @@ impl From<CompiledRoute> for RouteWithTypeInfo {
         let method = value.method;
         let path = value.path.to_string();
         let binding = value.binding.into();
+        let examples = value.examples.into_iter().map(|e| e.into()).collect();
         Self {
             method,
             path,
             binding,
+            examples,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct RouteExample {
+    pub name: String,
+    pub request: ExampleRequest,
+    #[serde(default)]
+    #[oai(default)]
+    pub worker_responses: Vec<MockedWorkerResponse>,
+    pub response: ExampleResponse,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ExampleRequest {
+    pub path: String,
+    #[serde(default)]
+    #[oai(default)]
+    pub headers: HashMap<String, String>,
+    pub body: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct MockedWorkerResponse {
+    pub function_name: String,
+    pub result: serde_json::Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ExampleResponse {
+    pub status: u16,
+    #[serde(default)]
+    #[oai(default)]
+    pub headers: HashMap<String, String>,
+    pub body: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct RouteExampleResult {
+    pub method: MethodPattern,
+    pub path: String,
+    pub example: String,
+    pub passed: bool,
+    pub failure: Option<String>,
+}
+
+impl From<crate::api_definition::http::RouteExampleResult> for RouteExampleResult {
+    fn from(value: crate::api_definition::http::RouteExampleResult) -> Self {
+        Self {
+            passed: value.passed(),
+            method: value.method,
+            path: value.path,
+            example: value.example,
+            failure: value.failure,
+        }
+    }
+}
+
+// Example bodies are stored as JSON strings, so that they can be persisted as is
+fn json_string_to_value(json: String) -> serde_json::Value {
+    serde_json::from_str(&json).unwrap_or(serde_json::Value::String(json))
+}
+
+impl From<crate::api_definition::http::RouteExample> for RouteExample {
+    fn from(value: crate::api_definition::http::RouteExample) -> Self {
+        Self {
+            name: value.name,
+            request: ExampleRequest {
+                path: value.request.path,
+                headers: value.request.headers,
+                body: value.request.body.map(json_string_to_value),
+            },
+            worker_responses: value
+                .worker_responses
+                .into_iter()
+                .map(|worker_response| MockedWorkerResponse {
+                    function_name: worker_response.function_name,
+                    result: json_string_to_value(worker_response.result),
+                })
+                .collect(),
+            response: ExampleResponse {
+                status: value.response.status,
+                headers: value.response.headers,
+                body: value.response.body.map(json_string_to_value),
+            },
+        }
+    }
+}
+
+impl From<RouteExample> for crate::api_definition::http::RouteExample {
+    fn from(value: RouteExample) -> Self {
+        Self {
+            name: value.name,
+            request: crate::api_definition::http::ExampleRequest {
+                path: value.request.path,
+                headers: value.request.headers,
+                body: value.request.body.map(|body| body.to_string()),
+            },
+            worker_responses: value
+                .worker_responses
+                .into_iter()
+                .map(
+                    |worker_response| crate::api_definition::http::MockedWorkerResponse {
+                        function_name: worker_response.function_name,
+                        result: worker_response.result.to_string(),
+                    },
+                )
+                .collect(),
+            response: crate::api_definition::http::ExampleResponse {
+                status: value.response.status,
+                headers: value.response.headers,
+                body: value.response.body.map(|body| body.to_string()),
+            },
         }
     }
 }
@@ -180,6 +333,7 @@ impl<N> From<crate::api_definition::ApiDeployment<N>> for ApiDeployment {
         Self {
             api_definitions,
             site: value.site,
+            rib_logging: value.rib_logging,
             created_at: Some(value.created_at),
         }
     }
@@ -202,6 +356,7 @@ impl TryFrom<crate::api_definition::http::HttpApiDefinition> for HttpApiDefiniti
             version: value.version,
             routes,
             draft: value.draft,
+            rib_version: value.rib_version,
             created_at: Some(value.created_at),
         })
     }
@@ -216,7 +371,7 @@ impl TryInto<crate::api_definition::http::HttpApiDefinitionRequest> for HttpApiD
         let mut routes = Vec::new();
 
         for route in self.routes {
-            let v = route.try_into()?;
+            let v = route.into_core_route(self.rib_version)?;
             routes.push(v);
         }
 
@@ -225,6 +380,7 @@ impl TryInto<crate::api_definition::http::HttpApiDefinitionRequest> for HttpApiD
             version: self.version,
             routes,
             draft: self.draft,
+            rib_version: self.rib_version,
         })
     }
 }
@@ -235,26 +391,31 @@ impl TryFrom<crate::api_definition::http::Route> for Route {
     fn try_from(value: crate::api_definition::http::Route) -> Result<Self, Self::Error> {
         let path = value.path.to_string();
         let binding = GolemWorkerBinding::try_from(value.binding)?;
+        let examples = value.examples.into_iter().map(|e| e.into()).collect();
 
         Ok(Self {
             method: value.method,
             path,
             binding,
+            examples,
         })
     }
 }
 
-impl TryInto<crate::api_definition::http::Route> for Route {
-    type Error = String;
-
-    fn try_into(self) -> Result<crate::api_definition::http::Route, Self::Error> {
+impl Route {
+    pub fn into_core_route(
+        self,
+        rib_version: RibVersion,
+    ) -> Result<crate::api_definition::http::Route, String> {
         let path = AllPathPatterns::parse(self.path.as_str()).map_err(|e| e.to_string())?;
-        let binding = self.binding.try_into()?;
+        let binding = self.binding.into_core_binding(rib_version)?;
+        let examples = self.examples.into_iter().map(|e| e.into()).collect();
 
         Ok(crate::api_definition::http::Route {
             method: self.method,
             path,
             binding,
+            examples,
         })
     }
 }
@@ -282,20 +443,24 @@ impl TryFrom<crate::worker_binding::GolemWorkerBinding> for GolemWorkerBinding {
     }
 }
 
-impl TryInto<crate::worker_binding::GolemWorkerBinding> for GolemWorkerBinding {
-    type Error = String;
-
-    fn try_into(self) -> Result<crate::worker_binding::GolemWorkerBinding, Self::Error> {
+impl GolemWorkerBinding {
+    // Rib expressions are parsed according to the given version of the grammar
+    pub fn into_core_binding(
+        self,
+        rib_version: RibVersion,
+    ) -> Result<crate::worker_binding::GolemWorkerBinding, String> {
         let response: crate::worker_binding::ResponseMapping = {
-            let r = rib::from_string(self.response.as_str()).map_err(|e| e.to_string())?;
+            let r = rib::from_string_with_version(self.response.as_str(), rib_version)
+                .map_err(|e| e.to_string())?;
             crate::worker_binding::ResponseMapping(r)
         };
 
         let worker_name: Expr =
-            rib::from_string(self.worker_name.as_str()).map_err(|e| e.to_string())?;
+            rib::from_string_with_version(self.worker_name.as_str(), rib_version)
+                .map_err(|e| e.to_string())?;
 
         let idempotency_key = if let Some(key) = &self.idempotency_key {
-            Some(rib::from_string(key).map_err(|e| e.to_string())?)
+            Some(rib::from_string_with_version(key, rib_version).map_err(|e| e.to_string())?)
         } else {
             None
         };
@@ -335,6 +500,7 @@ impl TryFrom<crate::api_definition::http::HttpApiDefinition> for grpc_apidefinit
             )),
             draft: value.draft,
             created_at: Some(created_at),
+            rib_version: Some(value.rib_version.as_u32()),
         };
 
         Ok(result)
@@ -354,6 +520,7 @@ impl TryFrom<grpc_apidefinition::ApiDefinition> for crate::api_definition::http:
         };
 
         let id = value.id.ok_or("Api Definition ID is missing")?;
+        let rib_version = get_rib_version(value.rib_version)?;
         let created_at = value
             .created_at
             .ok_or("Created At is missing")
@@ -364,6 +531,7 @@ impl TryFrom<grpc_apidefinition::ApiDefinition> for crate::api_definition::http:
             version: ApiVersion(value.version),
             routes,
             draft: value.draft,
+            rib_version,
             created_at: created_at.into(),
         };
 
@@ -386,18 +554,28 @@ impl TryFrom<grpc_apidefinition::v1::ApiDefinitionRequest>
         };
 
         let id = value.id.ok_or("Api Definition ID is missing")?;
+        let rib_version = get_rib_version(value.rib_version)?;
 
         let result = crate::api_definition::http::HttpApiDefinitionRequest {
             id: ApiDefinitionId(id.value),
             version: ApiVersion(value.version),
             routes,
             draft: value.draft,
+            rib_version,
         };
 
         Ok(result)
     }
 }
 
+// Definitions sent without a Rib version were written before it was introduced
+fn get_rib_version(rib_version: Option<u32>) -> Result<RibVersion, String> {
+    rib_version
+        .map(RibVersion::try_from)
+        .transpose()
+        .map(|version| version.unwrap_or(RibVersion::LEGACY))
+}
+
 impl TryFrom<crate::api_definition::http::Route> for grpc_apidefinition::HttpRoute {
     type Error = String;
 
@@ -406,10 +584,13 @@ impl TryFrom<crate::api_definition::http::Route> for grpc_apidefinition::HttpRou
         let binding = grpc_apidefinition::WorkerBinding::try_from(value.binding)?;
         let method: grpc_apidefinition::HttpMethod = value.method.into();
 
+        let examples = value.examples.into_iter().map(|e| e.into()).collect();
+
         let result = grpc_apidefinition::HttpRoute {
             method: method as i32,
             path,
             binding: Some(binding),
+            examples,
         };
 
         Ok(result)
@@ -423,10 +604,12 @@ impl TryFrom<CompiledRoute> for golem_api_grpc::proto::golem::apidefinition::Com
         let method = value.method as i32;
         let path = value.path.to_string();
         let binding = value.binding.try_into()?;
+        let examples = value.examples.into_iter().map(|e| e.into()).collect();
         Ok(Self {
             method,
             path,
             binding: Some(binding),
+            examples,
         })
     }
 }
@@ -440,10 +623,16 @@ impl TryFrom<golem_api_grpc::proto::golem::apidefinition::CompiledHttpRoute> for
         let method = MethodPattern::try_from(value.method)?;
         let path = AllPathPatterns::parse(value.path.as_str()).map_err(|e| e.to_string())?;
         let binding = value.binding.ok_or("binding is missing")?.try_into()?;
+        let examples = value
+            .examples
+            .into_iter()
+            .map(crate::api_definition::http::RouteExample::try_from)
+            .collect::<Result<Vec<_>, String>>()?;
         Ok(CompiledRoute {
             method,
             path,
             binding,
+            examples,
         })
     }
 }
@@ -473,10 +662,17 @@ impl TryFrom<grpc_apidefinition::HttpRoute> for crate::api_definition::http::Rou
 
         let method: MethodPattern = value.method.try_into()?;
 
+        let examples = value
+            .examples
+            .into_iter()
+            .map(crate::api_definition::http::RouteExample::try_from)
+            .collect::<Result<Vec<_>, String>>()?;
+
         let result = crate::api_definition::http::Route {
             method,
             path,
             binding,
+            examples,
         };
 
         Ok(result)
@@ -539,8 +735,10 @@ impl TryFrom<grpc_apidefinition::WorkerBinding> for crate::worker_binding::Golem
 
 #[cfg(test)]
 mod tests {
+    use super::{get_rib_version, HttpApiDefinitionRequest};
     use crate::api_definition::http::MethodPattern;
     use golem_api_grpc::proto::golem::apidefinition as grpc_apidefinition;
+    use rib::RibVersion;
     use test_r::test;
 
     #[test]
@@ -551,4 +749,14 @@ mod tests {
             assert_eq!(method, method_grpc as i32);
         }
     }
+
+    #[test]
+    fn test_default_rib_version() {
+        let request: HttpApiDefinitionRequest =
+            serde_json::from_str(r#"{"id": "test", "version": "0.1.0", "routes": []}"#).unwrap();
+        assert_eq!(request.rib_version, RibVersion::LATEST);
+
+        assert_eq!(get_rib_version(None), Ok(RibVersion::LEGACY));
+        assert_eq!(get_rib_version(Some(2)), Ok(RibVersion::V2));
+    }
 }
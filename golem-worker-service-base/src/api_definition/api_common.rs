@@ -49,6 +49,8 @@ pub struct ApiDeploymentRequest<Namespace> {
     pub namespace: Namespace,
     pub api_definition_keys: Vec<ApiDefinitionIdWithVersion>,
     pub site: ApiSite,
+    // Enables the output of Rib `log` statements for the whole site, kept as it is when not set
+    pub rib_logging: Option<bool>,
 }
 
 #[derive(Eq, Hash, PartialEq, Clone, Debug, serde::Deserialize)]
@@ -56,6 +58,7 @@ pub struct ApiDeployment<Namespace> {
     pub namespace: Namespace,
     pub api_definition_keys: Vec<ApiDefinitionIdWithVersion>,
     pub site: ApiSite,
+    pub rib_logging: bool,
     pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
@@ -8,9 +8,11 @@ use derive_more::Display;
 use golem_service_base::model::{Component, VersionedComponentId};
 use golem_wasm_ast::analysis::AnalysedExport;
 use poem_openapi::Enum;
+use rib::RibVersion;
 use serde::{Deserialize, Serialize, Serializer};
 use serde_json::Value;
 
+use crate::api_definition::http::RouteExample;
 use crate::api_definition::{ApiDefinitionId, ApiVersion, HasGolemWorkerBindings};
 use crate::parser::path_pattern_parser::PathPatternParser;
 use crate::parser::{GolemParser, ParseError};
@@ -25,6 +27,9 @@ pub struct HttpApiDefinitionRequest {
     pub routes: Vec<Route>,
     #[serde(default)]
     pub draft: bool,
+    // Version of the Rib grammar the expressions in the routes were written against
+    #[serde(default)]
+    pub rib_version: RibVersion,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -35,6 +40,8 @@ pub struct HttpApiDefinition {
     pub routes: Vec<Route>,
     #[serde(default)]
     pub draft: bool,
+    #[serde(default)]
+    pub rib_version: RibVersion,
     pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
@@ -48,6 +55,7 @@ impl HttpApiDefinition {
             version: request.version,
             routes: request.routes,
             draft: request.draft,
+            rib_version: request.rib_version,
             created_at,
         }
     }
@@ -60,6 +68,7 @@ impl From<HttpApiDefinition> for HttpApiDefinitionRequest {
             version: value.version,
             routes: value.routes,
             draft: value.draft,
+            rib_version: value.rib_version,
         }
     }
 }
@@ -75,6 +84,7 @@ impl From<CompiledHttpApiDefinition> for HttpApiDefinition {
                 .map(Route::from)
                 .collect(),
             draft: compiled_http_api_definition.draft,
+            rib_version: compiled_http_api_definition.rib_version,
             created_at: compiled_http_api_definition.created_at,
         }
     }
@@ -91,6 +101,7 @@ pub struct CompiledHttpApiDefinition {
     pub version: ApiVersion,
     pub routes: Vec<CompiledRoute>,
     pub draft: bool,
+    pub rib_version: RibVersion,
     pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
@@ -111,6 +122,7 @@ impl CompiledHttpApiDefinition {
             version: http_api_definition.version.clone(),
             routes: compiled_routes,
             draft: http_api_definition.draft,
+            rib_version: http_api_definition.rib_version,
             created_at: http_api_definition.created_at,
         })
     }
@@ -351,6 +363,8 @@ pub struct Route {
     pub method: MethodPattern,
     pub path: AllPathPatterns,
     pub binding: GolemWorkerBinding,
+    #[serde(default)]
+    pub examples: Vec<RouteExample>,
 }
 
 #[derive(Debug, Clone, PartialEq, Encode, Decode)]
@@ -358,6 +372,7 @@ pub struct CompiledRoute {
     pub method: MethodPattern,
     pub path: AllPathPatterns,
     pub binding: CompiledGolemWorkerBinding,
+    pub examples: Vec<RouteExample>,
 }
 
 #[derive(Debug)]
@@ -405,6 +420,7 @@ impl CompiledRoute {
             method: route.method.clone(),
             path: route.path.clone(),
             binding,
+            examples: route.examples.clone(),
         })
     }
 }
@@ -415,6 +431,7 @@ impl From<CompiledRoute> for Route {
             method: compiled_route.method,
             path: compiled_route.path,
             binding: compiled_route.binding.into(),
+            examples: compiled_route.examples,
         }
     }
 }
@@ -16,13 +16,16 @@ pub fn get_api_definition(openapi: OpenAPI) -> Result<HttpApiDefinitionRequest,
     let api_definition_version =
         ApiVersion(get_root_extension(&openapi, GOLEM_API_DEFINITION_VERSION)?);
 
-    let routes = get_routes(openapi.paths)?;
+    let rib_version = get_rib_version(&openapi)?;
+
+    let routes = get_routes(openapi.paths, rib_version)?;
 
     Ok(HttpApiDefinitionRequest {
         id: api_definition_id,
         version: api_definition_version,
         routes,
         draft: true,
+        rib_version,
     })
 }
 
@@ -75,11 +78,11 @@ impl ParseFromJSON for JsonOpenApiDefinition {
 }
 
 mod internal {
-    use crate::api_definition::http::{AllPathPatterns, MethodPattern, Route};
+    use crate::api_definition::http::{AllPathPatterns, MethodPattern, Route, RouteExample};
     use crate::worker_binding::{GolemWorkerBinding, ResponseMapping};
     use golem_common::model::ComponentId;
     use openapiv3::{OpenAPI, PathItem, Paths, ReferenceOr};
-    use rib::Expr;
+    use rib::{Expr, RibVersion};
     use serde_json::Value;
 
     use golem_service_base::model::VersionedComponentId;
@@ -88,6 +91,7 @@ mod internal {
     pub(crate) const GOLEM_API_DEFINITION_ID_EXTENSION: &str = "x-golem-api-definition-id";
     pub(crate) const GOLEM_API_DEFINITION_VERSION: &str = "x-golem-api-definition-version";
     pub(crate) const GOLEM_WORKER_BRIDGE_EXTENSION: &str = "x-golem-worker-bridge";
+    pub(crate) const GOLEM_RIB_VERSION_EXTENSION: &str = "x-golem-rib-version";
 
     pub(crate) fn get_root_extension(open_api: &OpenAPI, key_name: &str) -> Result<String, String> {
         open_api
@@ -101,7 +105,28 @@ mod internal {
             .map(|x| x.to_string())
     }
 
-    pub(crate) fn get_routes(paths: Paths) -> Result<Vec<Route>, String> {
+    // The Rib version is optional, and specs that don't declare it are written against the latest one.
+    // Both `x-golem-rib-version: 2` and `x-golem-rib-version: "v2"` are accepted
+    pub(crate) fn get_rib_version(open_api: &OpenAPI) -> Result<RibVersion, String> {
+        let value = open_api
+            .extensions
+            .iter()
+            .find(|(key, _)| key.to_lowercase() == GOLEM_RIB_VERSION_EXTENSION)
+            .map(|(_, value)| value);
+
+        match value {
+            None => Ok(RibVersion::LATEST),
+            Some(Value::Number(number)) => number
+                .as_u64()
+                .and_then(|number| u32::try_from(number).ok())
+                .ok_or(format!("Invalid value for {}", GOLEM_RIB_VERSION_EXTENSION))
+                .and_then(RibVersion::try_from),
+            Some(Value::String(version)) => version.parse(),
+            Some(_) => Err(format!("Invalid value for {}", GOLEM_RIB_VERSION_EXTENSION)),
+        }
+    }
+
+    pub(crate) fn get_routes(paths: Paths, rib_version: RibVersion) -> Result<Vec<Route>, String> {
         let mut routes: Vec<Route> = vec![];
 
         for (path, path_item) in paths.iter() {
@@ -110,7 +135,8 @@ mod internal {
                     let path_pattern = get_path_pattern(path)?;
 
                     for (str, _) in item.iter() {
-                        let route = get_route_from_path_item(str, item, &path_pattern)?;
+                        let route =
+                            get_route_from_path_item(str, item, &path_pattern, rib_version)?;
                         routes.push(route);
                     }
                 }
@@ -130,6 +156,7 @@ mod internal {
         method: &str,
         path_item: &PathItem,
         path_pattern: &AllPathPatterns,
+        rib_version: RibVersion,
     ) -> Result<Route, String> {
         let method_res = match method {
             "get" => Ok(MethodPattern::Get),
@@ -154,19 +181,33 @@ mod internal {
             ))?;
 
         let binding = GolemWorkerBinding {
-            worker_name: get_worker_id_expr(worker_bridge_info)?,
+            worker_name: get_worker_id_expr(worker_bridge_info, rib_version)?,
             component_id: get_component_id(worker_bridge_info)?,
-            idempotency_key: get_idempotency_key(worker_bridge_info)?,
-            response: get_response_mapping(worker_bridge_info)?,
+            idempotency_key: get_idempotency_key(worker_bridge_info, rib_version)?,
+            response: get_response_mapping(worker_bridge_info, rib_version)?,
         };
 
         Ok(Route {
             path: path_pattern.clone(),
             method,
             binding,
+            examples: get_examples(worker_bridge_info)?,
         })
     }
 
+    pub(crate) fn get_examples(worker_bridge_info: &Value) -> Result<Vec<RouteExample>, String> {
+        match worker_bridge_info.get("examples") {
+            Some(examples) => {
+                let examples: Vec<crate::api::RouteExample> =
+                    serde_json::from_value(examples.clone())
+                        .map_err(|err| format!("Invalid examples: {}", err))?;
+
+                Ok(examples.into_iter().map(|example| example.into()).collect())
+            }
+            None => Ok(vec![]),
+        }
+    }
+
     pub(crate) fn get_component_id(
         worker_bridge_info: &Value,
     ) -> Result<VersionedComponentId, String> {
@@ -193,6 +234,7 @@ mod internal {
 
     pub(crate) fn get_response_mapping(
         worker_bridge_info: &Value,
+        rib_version: RibVersion,
     ) -> Result<ResponseMapping, String> {
         let response = {
             let response_mapping_optional = worker_bridge_info.get("response").ok_or(
@@ -201,7 +243,9 @@ mod internal {
             )?;
 
             match response_mapping_optional {
-                Value::String(expr) => rib::from_string(expr).map_err(|err| err.to_string()),
+                Value::String(expr) => {
+                    rib::from_string_with_version(expr, rib_version).map_err(|err| err.to_string())
+                }
                 _ => Err(
                     "Invalid response mapping type. It should be a string representing expression"
                         .to_string(),
@@ -212,21 +256,28 @@ mod internal {
         Ok(ResponseMapping(response.clone()))
     }
 
-    pub(crate) fn get_worker_id_expr(worker_bridge_info: &Value) -> Result<Expr, String> {
+    pub(crate) fn get_worker_id_expr(
+        worker_bridge_info: &Value,
+        rib_version: RibVersion,
+    ) -> Result<Expr, String> {
         let worker_id = worker_bridge_info
             .get("worker-name")
             .ok_or("No worker-name found")?
             .as_str()
             .ok_or("worker-name is not a string")?;
 
-        rib::from_string(worker_id).map_err(|err| err.to_string())
+        rib::from_string_with_version(worker_id, rib_version).map_err(|err| err.to_string())
     }
 
-    pub(crate) fn get_idempotency_key(worker_bridge_info: &Value) -> Result<Option<Expr>, String> {
+    pub(crate) fn get_idempotency_key(
+        worker_bridge_info: &Value,
+        rib_version: RibVersion,
+    ) -> Result<Option<Expr>, String> {
         if let Some(key) = worker_bridge_info.get("idempotency-key") {
             let key_expr = key.as_str().ok_or("idempotency-key is not a string")?;
             Ok(Some(
-                rib::from_string(key_expr).map_err(|err| err.to_string())?,
+                rib::from_string_with_version(key_expr, rib_version)
+                    .map_err(|err| err.to_string())?,
             ))
         } else {
             Ok(None)
@@ -247,7 +298,7 @@ mod tests {
     use crate::worker_binding::{GolemWorkerBinding, ResponseMapping};
     use golem_common::model::ComponentId;
     use openapiv3::PathItem;
-    use rib::Expr;
+    use rib::{Expr, LogLevel, RibVersion};
     use serde_json::json;
     use uuid::Uuid;
 
@@ -268,7 +319,7 @@ mod tests {
 
         let path_pattern = AllPathPatterns::parse("/test").unwrap();
 
-        let result = get_route_from_path_item("get", &path_item, &path_pattern);
+        let result = get_route_from_path_item("get", &path_item, &path_pattern, RibVersion::LATEST);
         assert_eq!(
             result,
             Ok(Route {
@@ -309,8 +360,49 @@ mod tests {
                         .into_iter()
                         .collect()
                     ))
-                }
+                },
+                examples: vec![],
             })
         );
     }
+
+    #[test]
+    fn test_get_rib_version() {
+        let mut open_api = OpenAPI::default();
+        assert_eq!(get_rib_version(&open_api), Ok(RibVersion::LATEST));
+
+        open_api
+            .extensions
+            .insert("x-golem-rib-version".to_string(), json!(2));
+        assert_eq!(get_rib_version(&open_api), Ok(RibVersion::V2));
+
+        open_api
+            .extensions
+            .insert("x-golem-rib-version".to_string(), json!("v1"));
+        assert_eq!(get_rib_version(&open_api), Ok(RibVersion::V1));
+
+        open_api
+            .extensions
+            .insert("x-golem-rib-version".to_string(), json!(99));
+        assert!(get_rib_version(&open_api).is_err());
+    }
+
+    #[test]
+    fn test_get_response_mapping_with_rib_version() {
+        let worker_bridge_info = json!({
+            "response": "log(info, worker.response)"
+        });
+
+        let v2 = get_response_mapping(&worker_bridge_info, RibVersion::V2);
+        assert_eq!(
+            v2,
+            Ok(ResponseMapping(Expr::log(
+                LogLevel::Info,
+                Expr::select_field(Expr::identifier("worker"), "response")
+            )))
+        );
+
+        let v1 = get_response_mapping(&worker_bridge_info, RibVersion::V1).unwrap();
+        assert!(matches!(v1.0, Expr::Call(_, _, _)));
+    }
 }
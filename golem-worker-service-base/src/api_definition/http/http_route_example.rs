@@ -0,0 +1,654 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bincode::{Decode, Encode};
+use golem_api_grpc::proto::golem::apidefinition as grpc_apidefinition;
+use golem_common::model::exports::function_by_name;
+use golem_wasm_ast::analysis::analysed_type::tuple;
+use golem_wasm_ast::analysis::AnalysedExport;
+use golem_wasm_rpc::json::TypeAnnotatedValueJsonExtensions;
+use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
+use hyper::http::{HeaderMap, HeaderName, HeaderValue, Method};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::api_definition::http::{
+    CompiledHttpApiDefinition, CompiledRoute, ComponentMetadataDictionary, MethodPattern,
+};
+use crate::http::router::{Router, RouterPattern};
+use crate::http::{ApiInputPath, InputHttpRequest};
+use crate::worker_binding::RequestToWorkerBindingResolver;
+use crate::worker_bridge_execution::{
+    WorkerRequest, WorkerRequestExecutor, WorkerRequestExecutorError, WorkerResponse,
+};
+use crate::worker_service_rib_interpreter::{DefaultRibInterpreter, WorkerServiceRibInterpreter};
+
+/// An example request/response pair of a route, evaluated against the compiled bindings
+/// of the route with the worker responses mocked. Bodies and mocked results are JSON strings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct RouteExample {
+    pub name: String,
+    pub request: ExampleRequest,
+    #[serde(default)]
+    pub worker_responses: Vec<MockedWorkerResponse>,
+    pub response: ExampleResponse,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct ExampleRequest {
+    /// Concrete path of the request, optionally including the query string
+    pub path: String,
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    pub body: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct MockedWorkerResponse {
+    pub function_name: String,
+    pub result: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct ExampleResponse {
+    pub status: u16,
+    /// Headers that are expected to be present in the response. Other headers are ignored.
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    pub body: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RouteExampleResult {
+    pub method: MethodPattern,
+    pub path: String,
+    pub example: String,
+    pub failure: Option<String>,
+}
+
+impl RouteExampleResult {
+    pub fn passed(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+impl Display for RouteExampleResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.failure {
+            None => write!(
+                f,
+                "{} {} ({}): passed",
+                self.method, self.path, self.example
+            ),
+            Some(failure) => write!(
+                f,
+                "{} {} ({}): {}",
+                self.method, self.path, self.example, failure
+            ),
+        }
+    }
+}
+
+/// Runs the examples of all routes of the definition
+pub async fn run_route_examples(
+    definition: &CompiledHttpApiDefinition,
+    metadata_dictionary: &ComponentMetadataDictionary,
+) -> Vec<RouteExampleResult> {
+    // Maps requests to the index of the route they are routed to
+    let mut router = Router::new();
+    for (index, route) in definition.routes.iter().enumerate() {
+        let path: Vec<RouterPattern> = route
+            .path
+            .path_patterns
+            .iter()
+            .map(|pattern| pattern.clone().into())
+            .collect();
+        router.add_route(route.method.clone().into(), path, index);
+    }
+
+    let mut results = vec![];
+
+    for (index, route) in definition.routes.iter().enumerate() {
+        for example in &route.examples {
+            let context = ExampleContext {
+                definition,
+                router: &router,
+                route_index: index,
+                route,
+            };
+            let failure = example.run(&context, metadata_dictionary).await.err();
+
+            results.push(RouteExampleResult {
+                method: route.method.clone(),
+                path: route.path.to_string(),
+                example: example.name.clone(),
+                failure,
+            });
+        }
+    }
+
+    results
+}
+
+struct ExampleContext<'a> {
+    definition: &'a CompiledHttpApiDefinition,
+    router: &'a Router<usize>,
+    route_index: usize,
+    route: &'a CompiledRoute,
+}
+
+impl RouteExample {
+    async fn run(
+        &self,
+        context: &ExampleContext<'_>,
+        metadata_dictionary: &ComponentMetadataDictionary,
+    ) -> Result<(), String> {
+        let route = context.route;
+        let exports = metadata_dictionary
+            .metadata
+            .get(&route.binding.component_id)
+            .ok_or(format!(
+                "Component metadata of {} not found",
+                route.binding.component_id
+            ))?;
+
+        let mut worker_responses = HashMap::new();
+        for worker_response in &self.worker_responses {
+            worker_responses.insert(
+                worker_response.function_name.clone(),
+                worker_response.to_type_annotated_value(exports)?,
+            );
+        }
+
+        let input_http_request = self
+            .request
+            .to_input_http_request(route.method.clone().into())?;
+
+        let path: Vec<&str> =
+            RouterPattern::split(&input_http_request.input_path.base_path).collect();
+        match context
+            .router
+            .check_path(&input_http_request.req_method, &path)
+        {
+            Some(index) if *index == context.route_index => {}
+            Some(index) => {
+                let other = &context.definition.routes[*index];
+                return Err(format!(
+                    "Request is routed to a different route: {} {}",
+                    other.method, other.path
+                ));
+            }
+            None => return Err("Request does not match any route".to_string()),
+        }
+
+        let resolved = input_http_request
+            .resolve_worker_binding(std::slice::from_ref(context.definition))
+            .await
+            .map_err(|err| err.to_string())?;
+
+        let evaluator: Arc<dyn WorkerServiceRibInterpreter + Sync + Send> =
+            Arc::new(DefaultRibInterpreter::from_worker_request_executor(
+                Arc::new(MockedWorkerRequestExecutor { worker_responses }),
+            ));
+
+        let response: poem::Response = resolved.interpret_response_mapping(&evaluator).await;
+
+        self.response.check(response).await
+    }
+}
+
+impl ExampleRequest {
+    fn to_input_http_request(&self, method: Method) -> Result<InputHttpRequest, String> {
+        let (base_path, query_path) = match self.path.split_once('?') {
+            Some((base_path, query_path)) => (base_path.to_string(), Some(query_path.to_string())),
+            None => (self.path.clone(), None),
+        };
+
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            let header_name = HeaderName::from_str(name)
+                .map_err(|err| format!("Invalid request header name {}: {}", name, err))?;
+            let header_value = HeaderValue::from_str(value)
+                .map_err(|err| format!("Invalid value of request header {}: {}", name, err))?;
+            headers.insert(header_name, header_value);
+        }
+
+        let req_body = match &self.body {
+            Some(body) => serde_json::from_str(body)
+                .map_err(|err| format!("Request body is not valid JSON: {}", err))?,
+            None => Value::Null,
+        };
+
+        Ok(InputHttpRequest {
+            input_path: ApiInputPath {
+                base_path,
+                query_path,
+            },
+            headers,
+            req_method: method,
+            req_body,
+        })
+    }
+}
+
+impl MockedWorkerResponse {
+    fn to_type_annotated_value(
+        &self,
+        exports: &Vec<AnalysedExport>,
+    ) -> Result<TypeAnnotatedValue, String> {
+        let function = function_by_name(exports, &self.function_name)?
+            .ok_or(format!("Function {} not found", self.function_name))?;
+
+        let result: Value = serde_json::from_str(&self.result).map_err(|err| {
+            format!(
+                "Mocked result of {} is not valid JSON: {}",
+                self.function_name, err
+            )
+        })?;
+
+        // Worker responses are tuples of the function results
+        let result = if function.results.len() == 1 {
+            Value::Array(vec![result])
+        } else {
+            result
+        };
+
+        let result_type = tuple(
+            function
+                .results
+                .iter()
+                .map(|result| result.typ.clone())
+                .collect(),
+        );
+
+        TypeAnnotatedValue::parse_with_type(&result, &result_type).map_err(|errors| {
+            format!(
+                "Mocked result of {} does not match its result type: {}",
+                self.function_name,
+                errors.join(", ")
+            )
+        })
+    }
+}
+
+impl ExampleResponse {
+    async fn check(&self, response: poem::Response) -> Result<(), String> {
+        let status = response.status().as_u16();
+        if status != self.status {
+            return Err(format!(
+                "Expected status {} but got {}",
+                self.status, status
+            ));
+        }
+
+        for (name, expected) in &self.headers {
+            let actual = response
+                .headers()
+                .get(name.as_str())
+                .and_then(|value| value.to_str().ok());
+
+            if actual != Some(expected.as_str()) {
+                return Err(format!(
+                    "Expected header {}: {} but got {}",
+                    name,
+                    expected,
+                    actual.unwrap_or("none")
+                ));
+            }
+        }
+
+        if let Some(expected_body) = &self.body {
+            let expected: Value = serde_json::from_str(expected_body)
+                .map_err(|err| format!("Expected body is not valid JSON: {}", err))?;
+
+            let bytes = response
+                .into_body()
+                .into_bytes()
+                .await
+                .map_err(|err| format!("Failed to read response body: {}", err))?;
+
+            let actual: Value = serde_json::from_slice(&bytes)
+                .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&bytes).to_string()));
+
+            if actual != expected {
+                return Err(format!("Expected body {} but got {}", expected, actual));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+struct MockedWorkerRequestExecutor {
+    worker_responses: HashMap<String, TypeAnnotatedValue>,
+}
+
+#[async_trait]
+impl WorkerRequestExecutor for MockedWorkerRequestExecutor {
+    async fn execute(
+        &self,
+        resolved_worker_request: WorkerRequest,
+    ) -> Result<WorkerResponse, WorkerRequestExecutorError> {
+        self.worker_responses
+            .get(&resolved_worker_request.function_name)
+            .cloned()
+            .map(WorkerResponse::new)
+            .ok_or_else(|| {
+                format!(
+                    "No mocked worker response for {}",
+                    resolved_worker_request.function_name
+                )
+                .into()
+            })
+    }
+}
+
+impl From<RouteExample> for grpc_apidefinition::RouteExample {
+    fn from(value: RouteExample) -> Self {
+        grpc_apidefinition::RouteExample {
+            name: value.name,
+            request: Some(grpc_apidefinition::ExampleRequest {
+                path: value.request.path,
+                headers: value.request.headers,
+                body: value.request.body,
+            }),
+            worker_responses: value
+                .worker_responses
+                .into_iter()
+                .map(|worker_response| grpc_apidefinition::MockedWorkerResponse {
+                    function_name: worker_response.function_name,
+                    result: worker_response.result,
+                })
+                .collect(),
+            response: Some(grpc_apidefinition::ExampleResponse {
+                status: value.response.status as u32,
+                headers: value.response.headers,
+                body: value.response.body,
+            }),
+        }
+    }
+}
+
+impl TryFrom<grpc_apidefinition::RouteExample> for RouteExample {
+    type Error = String;
+
+    fn try_from(value: grpc_apidefinition::RouteExample) -> Result<Self, Self::Error> {
+        let request = value.request.ok_or("example request is missing")?;
+        let response = value.response.ok_or("example response is missing")?;
+        let status = u16::try_from(response.status)
+            .map_err(|_| format!("Invalid example response status {}", response.status))?;
+
+        Ok(RouteExample {
+            name: value.name,
+            request: ExampleRequest {
+                path: request.path,
+                headers: request.headers,
+                body: request.body,
+            },
+            worker_responses: value
+                .worker_responses
+                .into_iter()
+                .map(|worker_response| MockedWorkerResponse {
+                    function_name: worker_response.function_name,
+                    result: worker_response.result,
+                })
+                .collect(),
+            response: ExampleResponse {
+                status,
+                headers: response.headers,
+                body: response.body,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use super::*;
+    use crate::api_definition::http::HttpApiDefinition;
+    use golem_common::model::ComponentId;
+    use golem_service_base::model::VersionedComponentId;
+    use golem_wasm_ast::analysis::analysed_type::str;
+    use golem_wasm_ast::analysis::{
+        AnalysedFunction, AnalysedFunctionParameter, AnalysedFunctionResult, AnalysedInstance,
+    };
+
+    fn get_metadata() -> ComponentMetadataDictionary {
+        let versioned_component_id = VersionedComponentId {
+            component_id: ComponentId::try_from("0b6d9cd8-f373-4e29-8a5a-548e61b868a5").unwrap(),
+            version: 0,
+        };
+
+        let analysed_export = AnalysedExport::Instance(AnalysedInstance {
+            name: "golem:it/api".to_string(),
+            functions: vec![AnalysedFunction {
+                name: "get-cart-contents".to_string(),
+                parameters: vec![AnalysedFunctionParameter {
+                    name: "a".to_string(),
+                    typ: str(),
+                }],
+                results: vec![AnalysedFunctionResult {
+                    name: None,
+                    typ: str(),
+                }],
+            }],
+        });
+
+        ComponentMetadataDictionary {
+            metadata: HashMap::from_iter(vec![(versioned_component_id, vec![analysed_export])]),
+        }
+    }
+
+    fn get_compiled_definition(examples: &str) -> CompiledHttpApiDefinition {
+        get_compiled_definition_with_routes(examples, "")
+    }
+
+    fn get_compiled_definition_with_routes(
+        examples: &str,
+        other_routes: &str,
+    ) -> CompiledHttpApiDefinition {
+        let yaml_string = format!(
+            r#"
+          id: users-api
+          version: 0.0.1
+          createdAt: 2024-08-21T07:42:15.696Z
+          routes:
+          - method: Get
+            path: foo/{{user-id}}
+            binding:
+              componentId:
+                componentId: 0b6d9cd8-f373-4e29-8a5a-548e61b868a5
+                version: 0
+              workerName: '${{let id: u64 = request.path.user-id; "shopping-cart-${{id}}"}}'
+              response: '${{let result = golem:it/api.{{get-cart-contents}}("a"); {{status: 200, body: result}}}}'
+            examples: {}
+          {}
+        "#,
+            examples, other_routes
+        );
+
+        let http_api_definition: HttpApiDefinition =
+            serde_yaml::from_str(yaml_string.as_str()).unwrap();
+
+        CompiledHttpApiDefinition::from_http_api_definition(&http_api_definition, &get_metadata())
+            .unwrap()
+    }
+
+    async fn run(examples: &str) -> Vec<Option<String>> {
+        let definition = get_compiled_definition(examples);
+
+        run_route_examples(&definition, &get_metadata())
+            .await
+            .into_iter()
+            .map(|result| result.failure)
+            .collect()
+    }
+
+    #[test]
+    async fn test_passing_example() {
+        let result = run(r#"
+            - name: cart
+              request:
+                path: foo/1
+              workerResponses:
+              - functionName: golem:it/api.{get-cart-contents}
+                result: '"apple"'
+              response:
+                status: 200
+                body: '"apple"'
+            "#)
+        .await;
+
+        assert_eq!(result, vec![None]);
+    }
+
+    #[test]
+    async fn test_example_with_unexpected_body() {
+        let result = run(r#"
+            - name: cart
+              request:
+                path: foo/1
+              workerResponses:
+              - functionName: golem:it/api.{get-cart-contents}
+                result: '"apple"'
+              response:
+                status: 200
+                body: '"pear"'
+            "#)
+        .await;
+
+        assert_eq!(
+            result,
+            vec![Some("Expected body \"pear\" but got \"apple\"".to_string())]
+        );
+    }
+
+    #[test]
+    async fn test_example_with_unexpected_status() {
+        let result = run(r#"
+            - name: cart
+              request:
+                path: foo/1
+              workerResponses:
+              - functionName: golem:it/api.{get-cart-contents}
+                result: '"apple"'
+              response:
+                status: 404
+            "#)
+        .await;
+
+        assert_eq!(
+            result,
+            vec![Some("Expected status 404 but got 200".to_string())]
+        );
+    }
+
+    #[test]
+    async fn test_example_with_invalid_mocked_result() {
+        let result = run(r#"
+            - name: cart
+              request:
+                path: foo/1
+              workerResponses:
+              - functionName: golem:it/api.{get-cart-contents}
+                result: '42'
+              response:
+                status: 200
+            "#)
+        .await;
+
+        assert!(result[0]
+            .as_ref()
+            .unwrap()
+            .starts_with("Mocked result of golem:it/api.{get-cart-contents} does not match"));
+    }
+
+    #[test]
+    async fn test_example_without_mocked_result() {
+        let result = run(r#"
+            - name: cart
+              request:
+                path: foo/1
+              response:
+                status: 200
+            "#)
+        .await;
+
+        assert_eq!(
+            result,
+            vec![Some("Expected status 200 but got 500".to_string())]
+        );
+    }
+
+    #[test]
+    async fn test_example_routed_to_a_different_route() {
+        // The other route has the same binding, so it can only be told apart by its path
+        let definition = get_compiled_definition_with_routes(
+            r#"
+            - name: cart
+              request:
+                path: bar/1
+              workerResponses:
+              - functionName: golem:it/api.{get-cart-contents}
+                result: '"apple"'
+              response:
+                status: 200
+                body: '"apple"'
+            "#,
+            r#"
+          - method: Get
+            path: bar/{user-id}
+            binding:
+              componentId:
+                componentId: 0b6d9cd8-f373-4e29-8a5a-548e61b868a5
+                version: 0
+              workerName: '${let id: u64 = request.path.user-id; "shopping-cart-${id}"}'
+              response: '${let result = golem:it/api.{get-cart-contents}("a"); {status: 200, body: result}}'
+            "#,
+        );
+
+        let result = run_route_examples(&definition, &get_metadata()).await;
+
+        assert_eq!(result.len(), 1);
+        assert!(result[0]
+            .failure
+            .as_ref()
+            .unwrap()
+            .starts_with("Request is routed to a different route"));
+    }
+
+    #[test]
+    fn test_route_example_proto_conversion() {
+        let definition = get_compiled_definition(
+            r#"
+            - name: cart
+              request:
+                path: foo/1?x=y
+                headers:
+                  user: jon
+                body: '{"a": 1}'
+              workerResponses:
+              - functionName: golem:it/api.{get-cart-contents}
+                result: '"apple"'
+              response:
+                status: 200
+                body: '"apple"'
+            "#,
+        );
+
+        let example = definition.routes[0].examples[0].clone();
+        let proto: grpc_apidefinition::RouteExample = example.clone().into();
+        let decoded: RouteExample = proto.try_into().unwrap();
+
+        assert_eq!(example, decoded);
+    }
+}
@@ -119,7 +119,7 @@ mod tests {
         WorkerRequest, WorkerRequestExecutor, WorkerRequestExecutorError, WorkerResponse,
     };
     use crate::worker_service_rib_interpreter::{
-        DefaultRibInterpreter, EvaluationError, WorkerServiceRibInterpreter,
+        DefaultRibInterpreter, EvaluationError, RibLogContext, WorkerServiceRibInterpreter,
     };
     use async_trait::async_trait;
     use golem_common::model::{ComponentId, IdempotencyKey};
@@ -136,7 +136,41 @@ mod tests {
     use rib::{GetLiteralValue, RibInterpreterResult};
     use serde_json::Value;
     use std::collections::HashMap;
-    use std::sync::Arc;
+    use std::sync::{Arc, Mutex};
+    use tracing::instrument::WithSubscriber;
+    use tracing_subscriber::fmt::MakeWriter;
+
+    // Collects the formatted output of a tracing subscriber
+    #[derive(Clone, Default)]
+    struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+    impl CapturedLogs {
+        fn lines(&self) -> Vec<String> {
+            String::from_utf8_lossy(&self.0.lock().unwrap())
+                .lines()
+                .map(|line| line.to_string())
+                .collect()
+        }
+    }
+
+    impl std::io::Write for CapturedLogs {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for CapturedLogs {
+        type Writer = CapturedLogs;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
 
     struct TestWorkerRequestExecutor {}
 
@@ -362,7 +396,7 @@ mod tests {
                 .unwrap();
 
         let resolved_route = api_request
-            .resolve_worker_binding(vec![compiled])
+            .resolve_worker_binding(&[compiled])
             .await
             .unwrap();
 
@@ -396,6 +430,121 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    async fn test_end_to_end_evaluation_with_log() {
+        let empty_headers = HeaderMap::new();
+        let api_request = get_api_request("foo/1", None, &empty_headers, serde_json::Value::Null);
+        let expression =
+            r#"let response = golem:it/api.{get-cart-contents}("a", "b"); log(info, response)"#;
+
+        let api_specification: HttpApiDefinition = get_api_spec(
+            "foo/{user-id}",
+            "${let id: u64 = request.path.user-id; \"shopping-cart-${id}\"}",
+            expression,
+        );
+
+        let evaluator = get_test_evaluator();
+        let compiled = CompiledHttpApiDefinition::from_http_api_definition(
+            &api_specification,
+            &get_metadata(),
+        )
+        .unwrap();
+
+        let mut resolved_route = api_request
+            .resolve_worker_binding(&[compiled])
+            .await
+            .unwrap();
+
+        resolved_route.rib_log_context = Some(RibLogContext {
+            deployment: "localhost".to_string(),
+            route: "GET /foo/1".to_string(),
+        });
+
+        let logs = CapturedLogs::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_ansi(false)
+            .with_max_level(tracing::Level::INFO)
+            .with_writer(logs.clone())
+            .finish();
+
+        let test_response: TestResponse = resolved_route
+            .interpret_response_mapping(&evaluator)
+            .with_subscriber(subscriber)
+            .await;
+
+        let result = (test_response.function_name, test_response.function_params);
+
+        let expected = (
+            "golem:it/api.{get-cart-contents}".to_string(),
+            Value::Array(vec![
+                Value::String("a".to_string()),
+                Value::String("b".to_string()),
+            ]),
+        );
+
+        assert_eq!(result, expected);
+
+        let logs = logs.lines();
+        let rib_logs = logs
+            .iter()
+            .filter(|line| line.contains("Rib log"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(rib_logs.len(), 1, "{:?}", logs);
+        let rib_log = rib_logs[0];
+        assert!(rib_log.contains("INFO"), "{}", rib_log);
+        assert!(rib_log.contains(r#"deployment="localhost""#), "{}", rib_log);
+        assert!(rib_log.contains(r#"route="GET /foo/1""#), "{}", rib_log);
+        assert!(rib_log.contains("value="), "{}", rib_log);
+        assert!(rib_log.contains("get-cart-contents"), "{}", rib_log);
+        assert!(rib_log.contains("typ=record"), "{}", rib_log);
+    }
+
+    #[test]
+    async fn test_end_to_end_evaluation_without_log_context() {
+        let empty_headers = HeaderMap::new();
+        let api_request = get_api_request("foo/1", None, &empty_headers, serde_json::Value::Null);
+        let expression =
+            r#"let response = golem:it/api.{get-cart-contents}("a", "b"); log(info, response)"#;
+
+        let api_specification: HttpApiDefinition = get_api_spec(
+            "foo/{user-id}",
+            "${let id: u64 = request.path.user-id; \"shopping-cart-${id}\"}",
+            expression,
+        );
+
+        let evaluator = get_test_evaluator();
+        let compiled = CompiledHttpApiDefinition::from_http_api_definition(
+            &api_specification,
+            &get_metadata(),
+        )
+        .unwrap();
+
+        let resolved_route = api_request
+            .resolve_worker_binding(&[compiled])
+            .await
+            .unwrap();
+
+        let logs = CapturedLogs::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_ansi(false)
+            .with_max_level(tracing::Level::INFO)
+            .with_writer(logs.clone())
+            .finish();
+
+        let _: TestResponse = resolved_route
+            .interpret_response_mapping(&evaluator)
+            .with_subscriber(subscriber)
+            .await;
+
+        let logs = logs.lines();
+        assert!(
+            logs.iter().all(|line| !line.contains("Rib log")),
+            "{:?}",
+            logs
+        );
+    }
+
     #[test]
     async fn test_worker_request_resolution_with_concrete_params() {
         let empty_headers = HeaderMap::new();
@@ -866,7 +1015,7 @@ mod tests {
             .unwrap();
 
             let resolved_route = api_request
-                .resolve_worker_binding(vec![compiled_api_spec])
+                .resolve_worker_binding(&[compiled_api_spec])
                 .await;
 
             let result = resolved_route.map(|x| x.worker_detail);
@@ -907,7 +1056,7 @@ mod tests {
             .unwrap();
 
             let resolved_route = api_request
-                .resolve_worker_binding(vec![compiled_api_spec])
+                .resolve_worker_binding(&[compiled_api_spec])
                 .await
                 .unwrap();
 
@@ -37,7 +37,7 @@ impl ApiDefinitionRecord {
         definition: CompiledHttpApiDefinition,
         created_at: chrono::DateTime<chrono::Utc>,
     ) -> Result<Self, String> {
-        let data = record_data_serde::serialize(&definition.routes)?;
+        let data = record_data_serde::serialize(&definition.routes, definition.rib_version)?;
         Ok(Self {
             namespace: namespace.to_string(),
             id: definition.id.0,
@@ -52,13 +52,14 @@ impl ApiDefinitionRecord {
 impl TryFrom<ApiDefinitionRecord> for CompiledHttpApiDefinition {
     type Error = String;
     fn try_from(value: ApiDefinitionRecord) -> Result<Self, Self::Error> {
-        let routes = record_data_serde::deserialize(&value.data)?;
+        let (routes, rib_version) = record_data_serde::deserialize(&value.data)?;
 
         Ok(Self {
             id: value.id.into(),
             version: value.version.into(),
             routes,
             draft: value.draft,
+            rib_version,
             created_at: value.created_at,
         })
     }
@@ -315,17 +316,21 @@ pub mod record_data_serde {
         CompiledHttpApiDefinition, CompiledHttpRoute,
     };
     use prost::Message;
+    use rib::RibVersion;
 
     pub const SERIALIZATION_VERSION_V1: u8 = 1u8;
 
-    pub fn serialize(value: &[CompiledRoute]) -> Result<Bytes, String> {
+    pub fn serialize(value: &[CompiledRoute], rib_version: RibVersion) -> Result<Bytes, String> {
         let routes: Vec<CompiledHttpRoute> = value
             .iter()
             .cloned()
             .map(CompiledHttpRoute::try_from)
             .collect::<Result<Vec<CompiledHttpRoute>, String>>()?;
 
-        let proto_value: CompiledHttpApiDefinition = CompiledHttpApiDefinition { routes };
+        let proto_value: CompiledHttpApiDefinition = CompiledHttpApiDefinition {
+            routes,
+            rib_version: Some(rib_version.as_u32()),
+        };
 
         let mut bytes = BytesMut::new();
         bytes.put_u8(SERIALIZATION_VERSION_V1);
@@ -333,7 +338,7 @@ pub mod record_data_serde {
         Ok(bytes.freeze())
     }
 
-    pub fn deserialize(bytes: &[u8]) -> Result<Vec<CompiledRoute>, String> {
+    pub fn deserialize(bytes: &[u8]) -> Result<(Vec<CompiledRoute>, RibVersion), String> {
         let (version, data) = bytes.split_at(1);
 
         match version[0] {
@@ -347,7 +352,14 @@ pub mod record_data_serde {
                     .map(CompiledRoute::try_from)
                     .collect::<Result<Vec<CompiledRoute>, String>>()?;
 
-                Ok(value)
+                // Definitions stored before the Rib version was recorded were written against V1
+                let rib_version = proto_value
+                    .rib_version
+                    .map(RibVersion::try_from)
+                    .transpose()?
+                    .unwrap_or(RibVersion::LEGACY);
+
+                Ok((value, rib_version))
             }
             _ => Err("Unsupported serialization version".to_string()),
         }
@@ -23,6 +23,14 @@ use std::fmt::Display;
 use std::ops::Deref;
 use std::sync::Arc;
 
+/// An API definition deployed to a site, together with the settings of the deployment
+#[derive(sqlx::FromRow, Debug, Clone)]
+pub struct DeployedApiDefinitionRecord {
+    #[sqlx(flatten)]
+    pub definition: ApiDefinitionRecord,
+    pub rib_logging: bool,
+}
+
 #[derive(sqlx::FromRow, Debug, Clone)]
 pub struct ApiDeploymentRecord {
     pub namespace: String,
@@ -31,6 +39,7 @@ pub struct ApiDeploymentRecord {
     pub subdomain: Option<String>,
     pub definition_id: String,
     pub definition_version: String,
+    pub rib_logging: bool,
     pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
@@ -39,6 +48,7 @@ impl ApiDeploymentRecord {
         namespace: Namespace,
         site: ApiSite,
         definition_id: ApiDefinitionIdWithVersion,
+        rib_logging: bool,
         created_at: chrono::DateTime<chrono::Utc>,
     ) -> Self {
         Self {
@@ -48,6 +58,7 @@ impl ApiDeploymentRecord {
             subdomain: site.subdomain.clone(),
             definition_id: definition_id.id.0,
             definition_version: definition_id.version.0,
+            rib_logging,
             created_at,
         }
     }
@@ -77,7 +88,9 @@ pub trait ApiDeploymentRepo {
     async fn get_definitions_by_site(
         &self,
         site: &str,
-    ) -> Result<Vec<ApiDefinitionRecord>, RepoError>;
+    ) -> Result<Vec<DeployedApiDefinitionRecord>, RepoError>;
+
+    async fn set_rib_logging(&self, site: &str, rib_logging: bool) -> Result<(), RepoError>;
 }
 
 pub struct DbApiDeploymentRepo<DB: Database> {
@@ -100,9 +113,9 @@ impl ApiDeploymentRepo for DbApiDeploymentRepo<sqlx::Postgres> {
                 sqlx::query(
                     r#"
                       INSERT INTO api_deployments
-                        (namespace, site, host, subdomain, definition_id, definition_version, created_at)
+                        (namespace, site, host, subdomain, definition_id, definition_version, rib_logging, created_at)
                       VALUES
-                        ($1, $2, $3, $4, $5, $6, $7)
+                        ($1, $2, $3, $4, $5, $6, $7, $8)
                        "#,
                 )
                 .bind(deployment.namespace.clone())
@@ -111,6 +124,7 @@ impl ApiDeploymentRepo for DbApiDeploymentRepo<sqlx::Postgres> {
                 .bind(deployment.subdomain.clone())
                 .bind(deployment.definition_id.clone())
                 .bind(deployment.definition_version.clone())
+                .bind(deployment.rib_logging)
                 .bind(deployment.created_at)
                 .execute(&mut *transaction)
                 .await?;
@@ -120,6 +134,15 @@ impl ApiDeploymentRepo for DbApiDeploymentRepo<sqlx::Postgres> {
         Ok(())
     }
 
+    async fn set_rib_logging(&self, site: &str, rib_logging: bool) -> Result<(), RepoError> {
+        sqlx::query("UPDATE api_deployments SET rib_logging = $1 WHERE site = $2")
+            .bind(rib_logging)
+            .bind(site)
+            .execute(self.db_pool.deref())
+            .await?;
+        Ok(())
+    }
+
     async fn delete(&self, deployments: Vec<ApiDeploymentRecord>) -> Result<bool, RepoError> {
         if !deployments.is_empty() {
             let mut transaction = self.db_pool.begin().await?;
@@ -149,7 +172,7 @@ impl ApiDeploymentRepo for DbApiDeploymentRepo<sqlx::Postgres> {
     ) -> Result<Vec<ApiDeploymentRecord>, RepoError> {
         sqlx::query_as::<_, ApiDeploymentRecord>(
             r#"
-                SELECT namespace, site, host, subdomain, definition_id, definition_version, created_at::timestamptz
+                SELECT namespace, site, host, subdomain, definition_id, definition_version, rib_logging, created_at::timestamptz
                 FROM api_deployments
                 WHERE namespace = $1 AND definition_id = $2
                 "#,
@@ -169,7 +192,7 @@ impl ApiDeploymentRepo for DbApiDeploymentRepo<sqlx::Postgres> {
     ) -> Result<Vec<ApiDeploymentRecord>, RepoError> {
         sqlx::query_as::<_, ApiDeploymentRecord>(
             r#"
-                SELECT namespace, site, host, subdomain, definition_id, definition_version, created_at
+                SELECT namespace, site, host, subdomain, definition_id, definition_version, rib_logging, created_at
                 FROM api_deployments
                 WHERE namespace = $1 AND definition_id = $2
                 "#,
@@ -190,7 +213,7 @@ impl ApiDeploymentRepo for DbApiDeploymentRepo<sqlx::Postgres> {
     ) -> Result<Vec<ApiDeploymentRecord>, RepoError> {
         sqlx::query_as::<_, ApiDeploymentRecord>(
             r#"
-                SELECT namespace, site, host, subdomain, definition_id, definition_version, created_at::timestamptz
+                SELECT namespace, site, host, subdomain, definition_id, definition_version, rib_logging, created_at::timestamptz
                 FROM api_deployments
                 WHERE namespace = $1 AND definition_id = $2 AND definition_version = $3
                 "#,
@@ -212,7 +235,7 @@ impl ApiDeploymentRepo for DbApiDeploymentRepo<sqlx::Postgres> {
     ) -> Result<Vec<ApiDeploymentRecord>, RepoError> {
         sqlx::query_as::<_, ApiDeploymentRecord>(
             r#"
-                SELECT namespace, site, host, subdomain, definition_id, definition_version, created_at
+                SELECT namespace, site, host, subdomain, definition_id, definition_version, rib_logging, created_at
                 FROM api_deployments
                 WHERE namespace = $1 AND definition_id = $2 AND definition_version = $3
                 "#,
@@ -232,7 +255,7 @@ impl ApiDeploymentRepo for DbApiDeploymentRepo<sqlx::Postgres> {
     ) -> Result<Vec<ApiDeploymentRecord>, RepoError> {
         sqlx::query_as::<_, ApiDeploymentRecord>(
             r#"
-                SELECT namespace, site, host, subdomain, definition_id, definition_version, created_at::timestamptz
+                SELECT namespace, site, host, subdomain, definition_id, definition_version, rib_logging, created_at::timestamptz
                 FROM api_deployments
                 WHERE
                  site = $1
@@ -248,7 +271,7 @@ impl ApiDeploymentRepo for DbApiDeploymentRepo<sqlx::Postgres> {
     async fn get_by_site_sqlite(&self, site: &str) -> Result<Vec<ApiDeploymentRecord>, RepoError> {
         sqlx::query_as::<_, ApiDeploymentRecord>(
             r#"
-                SELECT namespace, site, host, subdomain, definition_id, definition_version, created_at
+                SELECT namespace, site, host, subdomain, definition_id, definition_version, rib_logging, created_at
                 FROM api_deployments
                 WHERE site = $1
                 "#,
@@ -263,10 +286,10 @@ impl ApiDeploymentRepo for DbApiDeploymentRepo<sqlx::Postgres> {
     async fn get_definitions_by_site_postgres(
         &self,
         site: &str,
-    ) -> Result<Vec<ApiDefinitionRecord>, RepoError> {
-        sqlx::query_as::<_, ApiDefinitionRecord>(
+    ) -> Result<Vec<DeployedApiDefinitionRecord>, RepoError> {
+        sqlx::query_as::<_, DeployedApiDefinitionRecord>(
             r#"
-                SELECT api_definitions.namespace, api_definitions.id, api_definitions.version, api_definitions.draft, api_definitions.data AS data, api_definitions.created_at::timestamptz
+                SELECT api_definitions.namespace, api_definitions.id, api_definitions.version, api_definitions.draft, api_definitions.data AS data, api_definitions.created_at::timestamptz, api_deployments.rib_logging
                 FROM api_deployments
                   JOIN api_definitions ON api_deployments.namespace = api_definitions.namespace AND api_deployments.definition_id = api_definitions.id AND api_deployments.definition_version = api_definitions.version
                 WHERE
@@ -283,10 +306,10 @@ impl ApiDeploymentRepo for DbApiDeploymentRepo<sqlx::Postgres> {
     async fn get_definitions_by_site_sqlite(
         &self,
         site: &str,
-    ) -> Result<Vec<ApiDefinitionRecord>, RepoError> {
-        sqlx::query_as::<_, ApiDefinitionRecord>(
+    ) -> Result<Vec<DeployedApiDefinitionRecord>, RepoError> {
+        sqlx::query_as::<_, DeployedApiDefinitionRecord>(
             r#"
-                SELECT api_definitions.namespace, api_definitions.id, api_definitions.version, api_definitions.draft, api_definitions.data, api_definitions.created_at
+                SELECT api_definitions.namespace, api_definitions.id, api_definitions.version, api_definitions.draft, api_definitions.data, api_definitions.created_at, api_deployments.rib_logging
                 FROM api_deployments
                   JOIN api_definitions ON api_deployments.namespace = api_definitions.namespace AND api_deployments.definition_id = api_definitions.id AND api_deployments.definition_version = api_definitions.version
                 WHERE
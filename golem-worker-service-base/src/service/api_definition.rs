@@ -17,8 +17,8 @@ use std::hash::Hash;
 use std::sync::Arc;
 
 use crate::api_definition::http::{
-    CompiledHttpApiDefinition, ComponentMetadataDictionary, HttpApiDefinition,
-    HttpApiDefinitionRequest, RouteCompilationErrors,
+    run_route_examples, CompiledHttpApiDefinition, ComponentMetadataDictionary, HttpApiDefinition,
+    HttpApiDefinitionRequest, RouteCompilationErrors, RouteExampleResult,
 };
 use crate::api_definition::{ApiDefinitionId, ApiVersion, HasGolemWorkerBindings};
 use crate::repo::api_definition::ApiDefinitionRecord;
@@ -52,6 +52,8 @@ pub enum ApiDefinitionError<E> {
     ComponentNotFoundError(Vec<VersionedComponentId>),
     #[error("Rib compilation error: {0}")]
     RibCompilationErrors(String),
+    #[error("Route examples failed: {}", .0.join(", "))]
+    RouteExamplesFailed(Vec<String>),
     #[error("API definition not found: {0}")]
     ApiDefinitionNotFound(ApiDefinitionId),
     #[error("API definition is not draft: {0}")]
@@ -80,6 +82,7 @@ impl<E: SafeDisplay + Display> SafeDisplay for ApiDefinitionError<E> {
             ApiDefinitionError::ValidationError(inner) => inner.to_safe_string(),
             ApiDefinitionError::ComponentNotFoundError(_) => self.to_string(),
             ApiDefinitionError::RibCompilationErrors(_) => self.to_string(),
+            ApiDefinitionError::RouteExamplesFailed(_) => self.to_string(),
             ApiDefinitionError::ApiDefinitionNotFound(_) => self.to_string(),
             ApiDefinitionError::ApiDefinitionNotDraft(_) => self.to_string(),
             ApiDefinitionError::ApiDefinitionAlreadyExists(_) => self.to_string(),
@@ -153,6 +156,14 @@ pub trait ApiDefinitionService<AuthCtx, Namespace, ValidationError> {
         namespace: &Namespace,
         auth_ctx: &AuthCtx,
     ) -> ApiResult<Vec<CompiledHttpApiDefinition>, ValidationError>;
+
+    // Compiles the definition without storing it and evaluates the examples of its routes
+    async fn test(
+        &self,
+        definition: &HttpApiDefinitionRequest,
+        namespace: &Namespace,
+        auth_ctx: &AuthCtx,
+    ) -> ApiResult<Vec<RouteExampleResult>, ValidationError>;
 }
 
 pub struct ApiDefinitionServiceDefault<AuthCtx, ValidationError> {
@@ -223,6 +234,26 @@ impl<AuthCtx, ValidationError> ApiDefinitionServiceDefault<AuthCtx, ValidationEr
 
         Ok(components)
     }
+
+    // Registration fails if any of the route examples does not hold
+    async fn check_route_examples(
+        &self,
+        definition: &CompiledHttpApiDefinition,
+        component_metadata_dictionary: &ComponentMetadataDictionary,
+    ) -> Result<(), ApiDefinitionError<ValidationError>> {
+        let failures = run_route_examples(definition, component_metadata_dictionary)
+            .await
+            .into_iter()
+            .filter(|result| !result.passed())
+            .map(|result| result.to_string())
+            .collect::<Vec<_>>();
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(ApiDefinitionError::RouteExamplesFailed(failures))
+        }
+    }
 }
 
 #[async_trait]
@@ -271,6 +302,12 @@ where
             &component_metadata_dictionary,
         )?;
 
+        self.check_route_examples(
+            &compiled_http_api_definition,
+            &component_metadata_dictionary,
+        )
+        .await?;
+
         let record = ApiDefinitionRecord::new(
             namespace.clone(),
             compiled_http_api_definition.clone(),
@@ -326,6 +363,12 @@ where
             &component_metadata_dictionary,
         )?;
 
+        self.check_route_examples(
+            &compiled_http_api_definition,
+            &component_metadata_dictionary,
+        )
+        .await?;
+
         let record = ApiDefinitionRecord::new(
             namespace.clone(),
             compiled_http_api_definition.clone(),
@@ -448,6 +491,36 @@ where
 
         Ok(values)
     }
+
+    async fn test(
+        &self,
+        definition: &HttpApiDefinitionRequest,
+        namespace: &Namespace,
+        auth_ctx: &AuthCtx,
+    ) -> ApiResult<Vec<RouteExampleResult>, ValidationError> {
+        info!(namespace = %namespace, "Test API definition");
+
+        let definition = HttpApiDefinition::new(definition.clone(), Utc::now());
+
+        let components = self.get_all_components(&definition, auth_ctx).await?;
+
+        self.api_definition_validator
+            .validate(&definition, components.as_slice())?;
+
+        let component_metadata_dictionary =
+            ComponentMetadataDictionary::from_components(&components);
+
+        let compiled_http_api_definition = CompiledHttpApiDefinition::from_http_api_definition(
+            &definition,
+            &component_metadata_dictionary,
+        )?;
+
+        Ok(run_route_examples(
+            &compiled_http_api_definition,
+            &component_metadata_dictionary,
+        )
+        .await)
+    }
 }
 
 #[cfg(test)]
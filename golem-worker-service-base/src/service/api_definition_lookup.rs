@@ -17,7 +17,7 @@ use std::sync::Arc;
 
 use crate::api_definition::http::CompiledHttpApiDefinition;
 use crate::http::InputHttpRequest;
-use crate::service::api_deployment::ApiDeploymentService;
+use crate::service::api_deployment::{ApiDeploymentService, DeployedApiDefinitions};
 use async_trait::async_trait;
 use tracing::error;
 
@@ -28,7 +28,10 @@ use tracing::error;
 // However, requires lot more work.
 #[async_trait]
 pub trait ApiDefinitionsLookup<Input, ApiDefinition> {
-    async fn get(&self, input: Input) -> Result<Vec<ApiDefinition>, ApiDefinitionLookupError>;
+    async fn get(
+        &self,
+        input: Input,
+    ) -> Result<DeployedApiDefinitions<ApiDefinition>, ApiDefinitionLookupError>;
 }
 
 pub struct ApiDefinitionLookupError(pub String);
@@ -56,7 +59,7 @@ impl<Namespace> ApiDefinitionsLookup<InputHttpRequest, CompiledHttpApiDefinition
     async fn get(
         &self,
         input_http_request: InputHttpRequest,
-    ) -> Result<Vec<CompiledHttpApiDefinition>, ApiDefinitionLookupError> {
+    ) -> Result<DeployedApiDefinitions<CompiledHttpApiDefinition>, ApiDefinitionLookupError> {
         // HOST should exist in Http Request
         let host = input_http_request
             .get_host()
@@ -66,7 +69,7 @@ impl<Namespace> ApiDefinitionsLookup<InputHttpRequest, CompiledHttpApiDefinition
 
         let http_api_defs = self
             .deployment_service
-            .get_definitions_by_site(&host)
+            .get_deployed_definitions_by_site(&host)
             .await
             .map_err(|err| {
                 error!("Error getting API definitions from the repo: {}", err);
@@ -76,7 +79,7 @@ impl<Namespace> ApiDefinitionsLookup<InputHttpRequest, CompiledHttpApiDefinition
                 ))
             })?;
 
-        if http_api_defs.is_empty() {
+        if http_api_defs.definitions.is_empty() {
             return Err(ApiDefinitionLookupError(format!(
                 "API deployment with site: {} not found",
                 &host
@@ -67,6 +67,11 @@ pub trait ApiDeploymentService<Namespace> {
         site: &ApiSiteString,
     ) -> Result<Vec<CompiledHttpApiDefinition>, ApiDeploymentError<Namespace>>;
 
+    async fn get_deployed_definitions_by_site(
+        &self,
+        site: &ApiSiteString,
+    ) -> Result<DeployedApiDefinitions<CompiledHttpApiDefinition>, ApiDeploymentError<Namespace>>;
+
     async fn delete(
         &self,
         namespace: &Namespace,
@@ -74,6 +79,13 @@ pub trait ApiDeploymentService<Namespace> {
     ) -> Result<(), ApiDeploymentError<Namespace>>;
 }
 
+/// The API definitions deployed to a site, together with the settings of the deployment
+#[derive(Debug, Clone)]
+pub struct DeployedApiDefinitions<ApiDefinition> {
+    pub definitions: Vec<ApiDefinition>,
+    pub rib_logging: bool,
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum ApiDeploymentError<Namespace> {
     #[error("API definition not found: {1}")]
@@ -226,6 +238,16 @@ where
 
         let mut existing_api_definition_keys: HashSet<ApiDefinitionIdWithVersion> = HashSet::new();
 
+        let rib_logging = deployment.rib_logging.unwrap_or_else(|| {
+            existing_deployment_records
+                .iter()
+                .any(|record| record.rib_logging)
+        });
+
+        let rib_logging_changed = existing_deployment_records
+            .iter()
+            .any(|record| record.rib_logging != rib_logging);
+
         for deployment_record in existing_deployment_records {
             if deployment_record.namespace != deployment.namespace.to_string()
                 || deployment_record.subdomain != deployment.site.subdomain
@@ -287,6 +309,7 @@ where
                     deployment.namespace.clone(),
                     deployment.site.clone(),
                     api_definition_key.clone(),
+                    rib_logging,
                     created_at,
                 ));
             }
@@ -320,7 +343,18 @@ where
             Err(ApiDeploymentError::ApiDefinitionsConflict(
                 conflicting_definitions,
             ))
-        } else if !new_deployment_records.is_empty() {
+        } else {
+            if rib_logging_changed {
+                info!(namespace = %deployment.namespace,
+                    "Set Rib logging of API deployment - site: {}, enabled: {}",
+                    deployment.site, rib_logging
+                );
+
+                self.deployment_repo
+                    .set_rib_logging(deployment.site.to_string().as_str(), rib_logging)
+                    .await?;
+            }
+
             for api_definition_key in set_not_draft {
                 info!(namespace = %deployment.namespace,
                     "Set API definition as not draft - definition id: {}, definition version: {}",
@@ -337,9 +371,10 @@ where
                     .await?;
             }
 
-            self.deployment_repo.create(new_deployment_records).await?;
-            Ok(())
-        } else {
+            if !new_deployment_records.is_empty() {
+                self.deployment_repo.create(new_deployment_records).await?;
+            }
+
             Ok(())
         }
     }
@@ -434,12 +469,14 @@ where
             {
                 Some(val) => {
                     val.api_definition_keys.push(api_definition_key);
+                    val.rib_logging |= deployment_record.rib_logging;
                 }
                 None => {
                     values.push(ApiDeployment {
                         site,
                         namespace,
                         api_definition_keys: vec![api_definition_key],
+                        rib_logging: deployment_record.rib_logging,
                         created_at: deployment_record.created_at,
                     });
                 }
@@ -467,6 +504,8 @@ where
 
         let mut created_at: Option<chrono::DateTime<Utc>> = None;
 
+        let mut rib_logging = false;
+
         for deployment_record in existing_deployment_records {
             if site.is_none() {
                 site = Some(ApiSite {
@@ -491,6 +530,8 @@ where
                 created_at = Some(deployment_record.created_at);
             }
 
+            rib_logging |= deployment_record.rib_logging;
+
             api_definition_keys.push(ApiDefinitionIdWithVersion {
                 id: deployment_record.definition_id.into(),
                 version: deployment_record.definition_version.into(),
@@ -502,6 +543,7 @@ where
                 namespace,
                 site,
                 api_definition_keys,
+                rib_logging,
                 created_at,
             })),
             _ => Ok(None),
@@ -512,6 +554,17 @@ where
         &self,
         site: &ApiSiteString,
     ) -> Result<Vec<CompiledHttpApiDefinition>, ApiDeploymentError<Namespace>> {
+        Ok(self
+            .get_deployed_definitions_by_site(site)
+            .await?
+            .definitions)
+    }
+
+    async fn get_deployed_definitions_by_site(
+        &self,
+        site: &ApiSiteString,
+    ) -> Result<DeployedApiDefinitions<CompiledHttpApiDefinition>, ApiDeploymentError<Namespace>>
+    {
         info!("Get API definitions");
         let records = self
             .deployment_repo
@@ -519,16 +572,21 @@ where
             .await?;
 
         let mut values: Vec<CompiledHttpApiDefinition> = vec![];
+        let mut rib_logging = false;
 
         for record in records {
+            rib_logging |= record.rib_logging;
             values.push(
-                record.try_into().map_err(|e| {
+                record.definition.try_into().map_err(|e| {
                     ApiDeploymentError::conversion_error("API definition record", e)
                 })?,
             );
         }
 
-        Ok(values)
+        Ok(DeployedApiDefinitions {
+            definitions: values,
+            rib_logging,
+        })
     }
 
     async fn delete(
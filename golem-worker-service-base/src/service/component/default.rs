@@ -1,3 +1,7 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
 use async_trait::async_trait;
 use http::Uri;
 use tonic::codec::CompressionEncoding;
@@ -5,14 +9,14 @@ use tonic::transport::Channel;
 
 use golem_api_grpc::proto::golem::component::v1::component_service_client::ComponentServiceClient;
 use golem_api_grpc::proto::golem::component::v1::{
-    get_component_metadata_response, GetComponentMetadataResponse, GetLatestComponentRequest,
-    GetVersionedComponentRequest,
+    get_component_metadata_response, get_worker_defaults_response, GetComponentMetadataResponse,
+    GetComponentRequest, GetLatestComponentRequest, GetVersionedComponentRequest,
 };
 use golem_common::client::{GrpcClient, GrpcClientConfig};
 use golem_common::config::RetryConfig;
 use golem_common::model::ComponentId;
 use golem_common::retries::with_retries;
-use golem_service_base::model::Component;
+use golem_service_base::model::{Component, WorkerDefaults};
 
 use crate::service::component::ComponentServiceError;
 use crate::service::with_metadata;
@@ -34,12 +38,27 @@ pub trait ComponentService<AuthCtx> {
         component_id: &ComponentId,
         auth_ctx: &AuthCtx,
     ) -> ComponentResult<Component>;
+
+    async fn get_worker_defaults(
+        &self,
+        component_id: &ComponentId,
+        auth_ctx: &AuthCtx,
+    ) -> ComponentResult<WorkerDefaults>;
 }
 
+/// How long the worker defaults of a component are reused before they are fetched again.
+/// Changes to the defaults take up to this long to apply to new workers.
+const WORKER_DEFAULTS_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// Worker defaults are cached per component and caller, so they are only returned to callers
+/// that passed the component service's authorization
+type WorkerDefaultsCacheKey = (ComponentId, Vec<(String, String)>);
+
 #[derive(Clone)]
 pub struct RemoteComponentService {
     client: GrpcClient<ComponentServiceClient<Channel>>,
     retry_config: RetryConfig,
+    worker_defaults_cache: Arc<RwLock<HashMap<WorkerDefaultsCacheKey, (Instant, WorkerDefaults)>>>,
 }
 
 impl RemoteComponentService {
@@ -58,9 +77,37 @@ impl RemoteComponentService {
                 },
             ),
             retry_config,
+            worker_defaults_cache: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
+    fn worker_defaults_cache_key<AuthCtx>(
+        component_id: &ComponentId,
+        metadata: &AuthCtx,
+    ) -> WorkerDefaultsCacheKey
+    where
+        AuthCtx: IntoIterator<Item = (String, String)> + Clone,
+    {
+        let mut metadata: Vec<(String, String)> = metadata.clone().into_iter().collect();
+        metadata.sort();
+        (component_id.clone(), metadata)
+    }
+
+    fn cached_worker_defaults(&self, key: &WorkerDefaultsCacheKey) -> Option<WorkerDefaults> {
+        self.worker_defaults_cache
+            .read()
+            .unwrap()
+            .get(key)
+            .filter(|(fetched_at, _)| fetched_at.elapsed() < WORKER_DEFAULTS_CACHE_TTL)
+            .map(|(_, worker_defaults)| worker_defaults.clone())
+    }
+
+    fn cache_worker_defaults(&self, key: WorkerDefaultsCacheKey, worker_defaults: &WorkerDefaults) {
+        let mut cache = self.worker_defaults_cache.write().unwrap();
+        cache.retain(|_, (fetched_at, _)| fetched_at.elapsed() < WORKER_DEFAULTS_CACHE_TTL);
+        cache.insert(key, (Instant::now(), worker_defaults.clone()));
+    }
+
     fn process_metadata_response(
         response: GetComponentMetadataResponse,
     ) -> Result<Component, ComponentServiceError> {
@@ -176,4 +223,55 @@ where
 
         Ok(value)
     }
+
+    async fn get_worker_defaults(
+        &self,
+        component_id: &ComponentId,
+        metadata: &AuthCtx,
+    ) -> ComponentResult<WorkerDefaults> {
+        let cache_key = Self::worker_defaults_cache_key(component_id, metadata);
+        if let Some(worker_defaults) = self.cached_worker_defaults(&cache_key) {
+            return Ok(worker_defaults);
+        }
+
+        let value = with_retries(
+            "component",
+            "get_worker_defaults",
+            Some(component_id.to_string()),
+            &self.retry_config,
+            &(self.client.clone(), component_id.clone(), metadata.clone()),
+            |(client, id, metadata)| {
+                Box::pin(async move {
+                    let response = client
+                        .call(move |client| {
+                            let request = GetComponentRequest {
+                                component_id: Some(id.clone().into()),
+                            };
+                            let request = with_metadata(request, metadata.clone());
+
+                            Box::pin(client.get_worker_defaults(request))
+                        })
+                        .await?
+                        .into_inner();
+
+                    match response.result {
+                        None => Err(ComponentServiceError::Internal(
+                            "Empty response".to_string(),
+                        )),
+                        Some(get_worker_defaults_response::Result::Success(worker_defaults)) => {
+                            Ok(worker_defaults.into())
+                        }
+                        Some(get_worker_defaults_response::Result::Error(error)) => {
+                            Err(error.into())
+                        }
+                    }
+                })
+            },
+            Self::is_retriable,
+        )
+        .await?;
+
+        self.cache_worker_defaults(cache_key, &value);
+        Ok(value)
+    }
 }
@@ -125,6 +125,7 @@ mod tests {
                     idempotency_key: None,
                     response: ResponseMapping(Expr::literal("sample")),
                 },
+                examples: vec![],
             }
         }
 
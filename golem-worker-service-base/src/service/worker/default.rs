@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use async_trait::async_trait;
 use golem_wasm_ast::analysis::AnalysedFunctionResult;
@@ -97,6 +100,7 @@ pub trait WorkerService<AuthCtx> {
         params: Vec<TypeAnnotatedValue>,
         invocation_context: Option<InvocationContext>,
         metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
     ) -> WorkerResult<TypeAnnotatedValue> {
         let params = self.validate_typed_parameters(params)?;
         self.invoke_and_await_typed(
@@ -106,6 +110,7 @@ pub trait WorkerService<AuthCtx> {
             params,
             invocation_context,
             metadata,
+            auth_ctx,
         )
         .await
     }
@@ -120,6 +125,7 @@ pub trait WorkerService<AuthCtx> {
         params: Vec<ProtoVal>,
         invocation_context: Option<InvocationContext>,
         metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
     ) -> WorkerResult<TypeAnnotatedValue>;
 
     /// Invokes a worker using raw `Val` parameter values and awaits its results returning
@@ -132,6 +138,7 @@ pub trait WorkerService<AuthCtx> {
         params: Vec<ProtoVal>,
         invocation_context: Option<InvocationContext>,
         metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
     ) -> WorkerResult<InvokeResult>;
 
     /// Validates the provided list of `TypeAnnotatedValue` parameters, and then enqueues
@@ -144,6 +151,7 @@ pub trait WorkerService<AuthCtx> {
         params: Vec<TypeAnnotatedValue>,
         invocation_context: Option<InvocationContext>,
         metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
     ) -> WorkerResult<()> {
         let params = self.validate_typed_parameters(params)?;
         self.invoke(
@@ -153,6 +161,7 @@ pub trait WorkerService<AuthCtx> {
             params,
             invocation_context,
             metadata,
+            auth_ctx,
         )
         .await
     }
@@ -167,6 +176,7 @@ pub trait WorkerService<AuthCtx> {
         params: Vec<ProtoVal>,
         invocation_context: Option<InvocationContext>,
         metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
     ) -> WorkerResult<()>;
 
     async fn complete_promise(
@@ -304,8 +314,18 @@ where
         arguments: Vec<String>,
         environment_variables: HashMap<String, String>,
         metadata: WorkerRequestMetadata,
-        _auth_ctx: &AuthCtx,
+        auth_ctx: &AuthCtx,
     ) -> WorkerResult<WorkerId> {
+        let (arguments, environment_variables) =
+            if arguments.is_empty() || environment_variables.is_empty() {
+                self.component_service
+                    .get_worker_defaults(&worker_id.component_id, auth_ctx)
+                    .await?
+                    .apply(worker_id, arguments, environment_variables)
+            } else {
+                (arguments, environment_variables)
+            };
+
         let worker_id_clone = worker_id.clone();
         self.call_worker_executor(
             worker_id.clone(),
@@ -430,8 +450,11 @@ where
         params: Vec<ProtoVal>,
         invocation_context: Option<InvocationContext>,
         metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
     ) -> WorkerResult<TypeAnnotatedValue> {
-        let worker_id = worker_id.clone();
+        let (worker_id, invocation_context) = self
+            .with_worker_defaults(worker_id, invocation_context, auth_ctx)
+            .await?;
         let worker_id_clone = worker_id.clone();
         let function_name_clone = function_name.clone();
 
@@ -492,8 +515,11 @@ where
         params: Vec<ProtoVal>,
         invocation_context: Option<InvocationContext>,
         metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
     ) -> WorkerResult<InvokeResult> {
-        let worker_id = worker_id.clone();
+        let (worker_id, invocation_context) = self
+            .with_worker_defaults(worker_id, invocation_context, auth_ctx)
+            .await?;
         let worker_id_clone = worker_id.clone();
 
         let invoke_response = self.call_worker_executor(
@@ -552,8 +578,11 @@ where
         params: Vec<ProtoVal>,
         invocation_context: Option<InvocationContext>,
         metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
     ) -> WorkerResult<()> {
-        let worker_id = worker_id.clone();
+        let (worker_id, invocation_context) = self
+            .with_worker_defaults(worker_id, invocation_context, auth_ctx)
+            .await?;
         self.call_worker_executor(
             worker_id.clone(),
             move |worker_executor_client| {
@@ -899,6 +928,33 @@ impl<AuthCtx> WorkerServiceDefault<AuthCtx>
 where
     AuthCtx: Send + Sync,
 {
+    /// Resolves the component's worker defaults into the invocation context, so they are used
+    /// if the invocation creates the worker
+    async fn with_worker_defaults(
+        &self,
+        worker_id: &TargetWorkerId,
+        invocation_context: Option<InvocationContext>,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<(TargetWorkerId, Option<InvocationContext>)> {
+        let worker_defaults = self
+            .component_service
+            .get_worker_defaults(&worker_id.component_id, auth_ctx)
+            .await?;
+        if worker_defaults.args.is_empty() && worker_defaults.env.is_empty() {
+            return Ok((worker_id.clone(), invocation_context));
+        }
+
+        // The name of a new worker is chosen here so it can be used in the defaults
+        let worker_id = worker_id.clone().into_worker_id(&HashSet::new(), 0);
+        let mut invocation_context = invocation_context.unwrap_or_default();
+        let (args, env) =
+            worker_defaults.apply(&worker_id, invocation_context.args, invocation_context.env);
+        invocation_context.args = args;
+        invocation_context.env = env;
+
+        Ok((worker_id.into_target_worker_id(), Some(invocation_context)))
+    }
+
     async fn try_get_component_for_worker(
         &self,
         worker_id: &WorkerId,
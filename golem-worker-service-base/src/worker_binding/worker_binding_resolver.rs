@@ -2,8 +2,8 @@ use crate::api_definition::http::{CompiledHttpApiDefinition, VarInfo};
 use crate::http::http_request::router;
 use crate::http::router::RouterPattern;
 use crate::http::InputHttpRequest;
-use crate::worker_service_rib_interpreter::EvaluationError;
 use crate::worker_service_rib_interpreter::WorkerServiceRibInterpreter;
+use crate::worker_service_rib_interpreter::{EvaluationError, RibLogContext};
 use async_trait::async_trait;
 use golem_common::model::IdempotencyKey;
 use golem_service_base::model::VersionedComponentId;
@@ -24,7 +24,7 @@ use crate::worker_bridge_execution::to_response::ToResponse;
 pub trait RequestToWorkerBindingResolver<ApiDefinition> {
     async fn resolve_worker_binding(
         &self,
-        api_definitions: Vec<ApiDefinition>,
+        api_definitions: &[ApiDefinition],
     ) -> Result<ResolvedWorkerBindingFromRequest, WorkerBindingResolutionError>;
 }
 
@@ -48,6 +48,8 @@ pub struct ResolvedWorkerBindingFromRequest {
     pub worker_detail: WorkerDetail,
     pub request_details: RequestDetails,
     pub compiled_response_mapping: ResponseMappingCompiled,
+    // Set only if the deployment has Rib logging enabled
+    pub rib_log_context: Option<RibLogContext>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -106,6 +108,7 @@ impl ResolvedWorkerBindingFromRequest {
                         &self.worker_detail.idempotency_key,
                         &self.compiled_response_mapping.compiled_response.clone(),
                         &rib_input,
+                        &self.rib_log_context,
                     )
                     .await;
 
@@ -124,7 +127,7 @@ impl ResolvedWorkerBindingFromRequest {
 impl RequestToWorkerBindingResolver<CompiledHttpApiDefinition> for InputHttpRequest {
     async fn resolve_worker_binding(
         &self,
-        compiled_api_definitions: Vec<CompiledHttpApiDefinition>,
+        compiled_api_definitions: &[CompiledHttpApiDefinition],
     ) -> Result<ResolvedWorkerBindingFromRequest, WorkerBindingResolutionError> {
         let compiled_routes = compiled_api_definitions
             .iter()
@@ -216,6 +219,7 @@ impl RequestToWorkerBindingResolver<CompiledHttpApiDefinition> for InputHttpRequ
             worker_detail,
             request_details: http_request_details,
             compiled_response_mapping: binding.response_compiled.clone(),
+            rib_log_context: None,
         };
 
         Ok(resolved_binding)
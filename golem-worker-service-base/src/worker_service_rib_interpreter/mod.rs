@@ -8,7 +8,7 @@ use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 use golem_common::model::{ComponentId, IdempotencyKey};
 
 use crate::worker_binding::RibInputValue;
-use rib::{RibByteCode, RibFunctionInvoke, RibInterpreterResult};
+use rib::{RibByteCode, RibFunctionInvoke, RibInterpreterResult, RibLogger};
 
 use crate::worker_bridge_execution::{WorkerRequest, WorkerRequestExecutor};
 
@@ -25,9 +25,25 @@ pub trait WorkerServiceRibInterpreter {
         idempotency_key: &Option<IdempotencyKey>,
         rib_byte_code: &RibByteCode,
         rib_input: &RibInputValue,
+        rib_log_context: &Option<RibLogContext>,
     ) -> Result<RibInterpreterResult, EvaluationError>;
 }
 
+// Identifies where the values of Rib `log` statements come from.
+// Without a context, `log` statements are evaluated but nothing is logged.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RibLogContext {
+    pub deployment: String,
+    pub route: String,
+}
+
+impl RibLogContext {
+    pub fn logger(&self) -> RibLogger {
+        let context = self.clone();
+        Arc::new(move |level, value| internal::log_rib_value(&context, level, value))
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct EvaluationError(pub String);
 
@@ -66,6 +82,7 @@ impl WorkerServiceRibInterpreter for DefaultRibInterpreter {
         idempotency_key: &Option<IdempotencyKey>,
         expr: &RibByteCode,
         rib_input: &RibInputValue,
+        rib_log_context: &Option<RibLogContext>,
     ) -> Result<RibInterpreterResult, EvaluationError> {
         let executor = self.worker_request_executor.clone();
 
@@ -99,8 +116,54 @@ impl WorkerServiceRibInterpreter for DefaultRibInterpreter {
                 .boxed() // This ensures the future is boxed with the correct type
             },
         );
-        rib::interpret(expr, rib_input.value.clone(), worker_invoke_function)
+        match rib_log_context {
+            Some(rib_log_context) => rib::interpret_with_logger(
+                expr,
+                rib_input.value.clone(),
+                worker_invoke_function,
+                rib_log_context.logger(),
+            )
             .await
-            .map_err(EvaluationError)
+            .map_err(EvaluationError),
+            None => rib::interpret(expr, rib_input.value.clone(), worker_invoke_function)
+                .await
+                .map_err(EvaluationError),
+        }
+    }
+}
+
+mod internal {
+    use crate::worker_service_rib_interpreter::RibLogContext;
+    use golem_wasm_ast::analysis::AnalysedType;
+    use rib::{LogLevel, RibInterpreterResult, TypeName};
+    use tracing::{debug, error, info, trace, warn};
+
+    pub(crate) fn log_rib_value(
+        context: &RibLogContext,
+        level: LogLevel,
+        value: &RibInterpreterResult,
+    ) {
+        let (value, typ) = match value {
+            RibInterpreterResult::Val(value) => (
+                golem_wasm_rpc::type_annotated_value_to_string(value)
+                    .unwrap_or_else(|err| format!("<{}>", err)),
+                AnalysedType::try_from(value)
+                    .ok()
+                    .and_then(|typ| TypeName::try_from(typ).ok())
+                    .map_or("unknown".to_string(), |typ| typ.to_string()),
+            ),
+            RibInterpreterResult::Unit => ("()".to_string(), "unit".to_string()),
+        };
+
+        let deployment = context.deployment.as_str();
+        let route = context.route.as_str();
+
+        match level {
+            LogLevel::Trace => trace!(deployment, route, %value, %typ, "Rib log"),
+            LogLevel::Debug => debug!(deployment, route, %value, %typ, "Rib log"),
+            LogLevel::Info => info!(deployment, route, %value, %typ, "Rib log"),
+            LogLevel::Warn => warn!(deployment, route, %value, %typ, "Rib log"),
+            LogLevel::Error => error!(deployment, route, %value, %typ, "Rib log"),
+        }
     }
 }
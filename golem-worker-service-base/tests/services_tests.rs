@@ -5,7 +5,7 @@ use golem_common::config::{DbPostgresConfig, DbSqliteConfig};
 use golem_common::model::ComponentId;
 use golem_service_base::auth::{DefaultNamespace, EmptyAuthCtx};
 use golem_service_base::db;
-use golem_service_base::model::Component;
+use golem_service_base::model::{Component, WorkerDefaults};
 use golem_wasm_ast::analysis::{
     AnalysedExport, AnalysedFunction, AnalysedFunctionParameter, AnalysedFunctionResult,
     AnalysedInstance,
@@ -187,6 +187,14 @@ impl<AuthCtx> ComponentService<AuthCtx> for TestComponentService {
     ) -> ComponentResult<Component> {
         Ok(Self::test_component())
     }
+
+    async fn get_worker_defaults(
+        &self,
+        _component_id: &ComponentId,
+        _auth_ctx: &AuthCtx,
+    ) -> ComponentResult<WorkerDefaults> {
+        Ok(WorkerDefaults::default())
+    }
 }
 
 async fn test_services(
@@ -363,7 +371,28 @@ async fn test_deployment(
         .get_by_site(&ApiSiteString("test.com".to_string()))
         .await
         .unwrap();
-    assert!(deployment.is_some());
+    assert!(deployment.is_some_and(|deployment| !deployment.rib_logging));
+
+    let deployment = ApiDeploymentRequest {
+        rib_logging: Some(true),
+        ..get_api_deployment("test.com", None, vec![&def3.id.0])
+    };
+    deployment_service.deploy(&deployment).await.unwrap();
+
+    let deployment = deployment_service
+        .get_by_site(&ApiSiteString("test.com".to_string()))
+        .await
+        .unwrap();
+    assert!(deployment.is_some_and(|deployment| deployment.rib_logging));
+
+    let deployment = get_api_deployment("test.com", None, vec![&def3.id.0]);
+    deployment_service.deploy(&deployment).await.unwrap();
+
+    let deployment = deployment_service
+        .get_by_site(&ApiSiteString("test.com".to_string()))
+        .await
+        .unwrap();
+    assert!(deployment.is_some_and(|deployment| deployment.rib_logging));
 
     let deployments = deployment_service
         .get_by_id(&DefaultNamespace::default(), &def3.id)
@@ -728,6 +757,7 @@ fn get_api_deployment(
             host: host.to_string(),
             subdomain: subdomain.map(|s| s.to_string()),
         },
+        rib_logging: None,
     }
 }
 
@@ -0,0 +1,2 @@
+ALTER TABLE api_deployments
+    ADD COLUMN IF NOT EXISTS rib_logging boolean NOT NULL DEFAULT false;
\ No newline at end of file
@@ -7,6 +7,7 @@ use golem_service_base::auth::{DefaultNamespace, EmptyAuthCtx};
 use golem_worker_service_base::api::ApiEndpointError;
 use golem_worker_service_base::api::HttpApiDefinitionRequest;
 use golem_worker_service_base::api::HttpApiDefinitionWithTypeInfo;
+use golem_worker_service_base::api::RouteExampleResult;
 use golem_worker_service_base::api_definition::http::get_api_definition;
 use golem_worker_service_base::api_definition::http::CompiledHttpApiDefinition;
 use golem_worker_service_base::api_definition::http::HttpApiDefinitionRequest as CoreHttpApiDefinitionRequest;
@@ -100,6 +101,45 @@ impl RegisterApiDefinitionApi {
         record.result(response)
     }
 
+    /// Test the route examples of an API definition
+    ///
+    /// Compiles the API definition without storing it, and evaluates the examples of its routes
+    /// with the worker responses mocked.
+    #[oai(path = "/test", method = "post", operation_id = "test_definition")]
+    async fn test(
+        &self,
+        payload: Json<HttpApiDefinitionRequest>,
+    ) -> Result<Json<Vec<RouteExampleResult>>, ApiEndpointError> {
+        let record = recorded_http_api_request!(
+            "test_definition",
+            api_definition_id = payload.0.id.to_string(),
+            version = payload.0.version.to_string()
+        );
+
+        let response = {
+            let definition: CoreHttpApiDefinitionRequest = payload
+                .0
+                .try_into()
+                .map_err(|err| ApiEndpointError::bad_request(safe(err)))?;
+
+            let results = self
+                .definition_service
+                .test(
+                    &definition,
+                    &DefaultNamespace::default(),
+                    &EmptyAuthCtx::default(),
+                )
+                .instrument(record.span.clone())
+                .await?;
+
+            Ok(Json(
+                results.into_iter().map(RouteExampleResult::from).collect(),
+            ))
+        };
+
+        record.result(response)
+    }
+
     /// Update an existing API definition.
     ///
     /// Only draft API definitions can be updated.
@@ -310,7 +350,7 @@ mod test {
     use golem_common::config::DbSqliteConfig;
     use golem_common::model::ComponentId;
     use golem_service_base::db;
-    use golem_service_base::model::Component;
+    use golem_service_base::model::{Component, WorkerDefaults};
     use golem_worker_service_base::repo::api_definition::{ApiDefinitionRepo, DbApiDefinitionRepo};
     use golem_worker_service_base::repo::api_deployment;
     use golem_worker_service_base::service::api_definition::ApiDefinitionServiceDefault;
@@ -318,6 +358,7 @@ mod test {
     use golem_worker_service_base::service::http::http_api_definition_validator::HttpApiDefinitionValidator;
     use http::StatusCode;
     use poem::test::TestClient;
+    use rib::RibVersion;
     use std::marker::PhantomData;
 
     struct SqliteDb<'c> {
@@ -362,6 +403,14 @@ mod test {
         ) -> ComponentResult<Component> {
             unimplemented!()
         }
+
+        async fn get_worker_defaults(
+            &self,
+            _component_id: &ComponentId,
+            _auth_ctx: &EmptyAuthCtx,
+        ) -> ComponentResult<WorkerDefaults> {
+            unimplemented!()
+        }
     }
 
     async fn make_route<'c>() -> (poem::Route, SqliteDb<'c>) {
@@ -411,6 +460,7 @@ mod test {
                 version: ApiVersion("1.0".to_string()),
                 routes: vec![],
                 draft: false,
+                rib_version: RibVersion::LATEST,
             };
 
         let response = client
@@ -441,6 +491,7 @@ mod test {
                 version: ApiVersion("42.0".to_string()),
                 routes: vec![],
                 draft: false,
+                rib_version: RibVersion::LATEST,
             };
 
         let response = client
@@ -466,6 +517,7 @@ mod test {
                 version: ApiVersion("1.0".to_string()),
                 routes: vec![],
                 draft: false,
+                rib_version: RibVersion::LATEST,
             };
         let response = client
             .post("/v1/api/definitions")
@@ -480,6 +532,7 @@ mod test {
                 version: ApiVersion("2.0".to_string()),
                 routes: vec![],
                 draft: false,
+                rib_version: RibVersion::LATEST,
             };
         let response = client
             .post("/v1/api/definitions")
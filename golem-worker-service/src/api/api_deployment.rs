@@ -49,6 +49,7 @@ impl ApiDeploymentApi {
                 namespace: DefaultNamespace::default(),
                 api_definition_keys: api_definition_infos,
                 site: payload.site.clone(),
+                rib_logging: payload.rib_logging,
             };
 
             self.deployment_service
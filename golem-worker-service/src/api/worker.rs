@@ -157,6 +157,7 @@ impl WorkerApi {
                 params.0.params,
                 None,
                 empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
             )
             .instrument(record.span.clone())
             .await
@@ -200,6 +201,7 @@ impl WorkerApi {
                 params.0.params,
                 None,
                 empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
             )
             .instrument(record.span.clone())
             .await
@@ -242,6 +244,7 @@ impl WorkerApi {
                 params.0.params,
                 None,
                 empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
             )
             .instrument(record.span.clone())
             .await
@@ -285,6 +288,7 @@ impl WorkerApi {
                 params.0.params,
                 None,
                 empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
             )
             .instrument(record.span.clone())
             .await
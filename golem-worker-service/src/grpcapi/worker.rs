@@ -663,6 +663,7 @@ impl WorkerGrpcApi {
                 params.params,
                 request.context,
                 empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
             )
             .await?;
 
@@ -687,6 +688,7 @@ impl WorkerGrpcApi {
                 params,
                 request.context,
                 empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
             )
             .await?;
 
@@ -712,6 +714,7 @@ impl WorkerGrpcApi {
                 params.params,
                 request.context,
                 empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
             )
             .await?;
 
@@ -739,6 +742,7 @@ impl WorkerGrpcApi {
                 params,
                 request.context,
                 empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
             )
             .await?;
 
@@ -776,6 +780,7 @@ impl WorkerGrpcApi {
                 params.params,
                 request.context,
                 empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
             )
             .await?;
 
@@ -96,6 +96,7 @@ mod internal {
                 invoke_parameters,
                 None,
                 empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
             )
             .await
             .map_err(|e| e.to_string())?;
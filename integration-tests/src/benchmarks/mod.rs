@@ -24,9 +24,11 @@ use crate::benchmarks::data::Data;
 use golem_common::model::{ComponentId, IdempotencyKey, WorkerId};
 use golem_test_framework::config::{CliParams, CliTestDependencies};
 use golem_test_framework::dsl::benchmark::{
-    BenchmarkApi, BenchmarkRecorder, BenchmarkResult, ResultKey, RunConfig,
+    Benchmark, BenchmarkApi, BenchmarkRecorder, BenchmarkResult, ResultKey, RunConfig,
 };
 use golem_test_framework::dsl::TestDsl;
+use golem_test_framework::reporter::s3::S3ArtifactReporter;
+use golem_test_framework::reporter::{ArtifactReporter, RunMetadata};
 
 pub mod data;
 
@@ -207,8 +209,13 @@ pub async fn get_benchmark_results<A: BenchmarkApi>(params: CliParams) -> Benchm
     }
 }
 
-pub async fn run_benchmark<A: BenchmarkApi>() {
+pub async fn run_benchmark<A: Benchmark>() {
     let params = CliParams::parse();
+    let reporter_config = params.artifact_reporter.config();
+    let metadata = reporter_config
+        .as_ref()
+        .map(|config| RunMetadata::new(&config.run_id, params.artifact_run_config()));
+
     let result = get_benchmark_results::<A>(params.clone()).await;
     if params.json {
         let str = serde_json::to_string(&result).expect("Failed to serialize BenchmarkResult");
@@ -216,6 +223,22 @@ pub async fn run_benchmark<A: BenchmarkApi>() {
     } else {
         println!("{}", result.view());
     }
+
+    if let (Some(config), Some(metadata)) = (reporter_config, metadata) {
+        let reporter = S3ArtifactReporter::new(config, metadata).await;
+        if let Err(err) = publish_benchmark_result(&reporter, A::name(), &result).await {
+            warn!("Failed to publish the benchmark result: {err}");
+        }
+    }
+}
+
+async fn publish_benchmark_result(
+    reporter: &impl ArtifactReporter,
+    name: &str,
+    result: &BenchmarkResult,
+) -> anyhow::Result<()> {
+    reporter.publish_metadata().await?;
+    reporter.publish_benchmark_result(name, result).await
 }
 
 pub struct InvokeResult {
@@ -17,8 +17,6 @@ use async_trait::async_trait;
 use golem_wasm_rpc::Value;
 use tokio::task::JoinSet;
 
-use golem_api_grpc::proto::golem::shardmanager;
-use golem_api_grpc::proto::golem::shardmanager::v1::GetRoutingTableRequest;
 use golem_common::model::{RoutingTable, WorkerId};
 use golem_test_framework::config::{CliParams, TestDependencies};
 use golem_test_framework::dsl::benchmark::{Benchmark, BenchmarkRecorder, RunConfig};
@@ -167,23 +165,12 @@ impl Benchmark for RpcCpuIntensive {
     ) {
         let calculate_iter: u64 = 200000;
 
-        let shard_manager = benchmark_context.deps.shard_manager();
-
-        let mut shard_manager_client = shard_manager.client().await;
-
-        let routing_table = shard_manager_client
-            .get_routing_table(GetRoutingTableRequest {})
+        let shard_manager_routing_table = benchmark_context
+            .deps
+            .get_routing_table()
             .await
             .expect("Unable to fetch the routing table from shard-manager-service");
 
-        let shard_manager_routing_table: RoutingTable = match routing_table.into_inner() {
-            shardmanager::v1::GetRoutingTableResponse {
-                result:
-                    Some(shardmanager::v1::get_routing_table_response::Result::Success(routing_table)),
-            } => routing_table.into(),
-            _ => panic!("Unable to fetch the routing table from shard-manager-service"),
-        };
-
         self.benchmark_rpc_invocation(
             benchmark_context,
             context,
@@ -31,6 +31,7 @@ use golem_common::model::{
     WorkerStatus,
 };
 use golem_test_framework::config::EnvBasedTestDependencies;
+use golem_test_framework::reporter::failed_test::TestArtifactCollector;
 use rand::seq::IteratorRandom;
 use serde_json::json;
 use std::time::{Duration, SystemTime};
@@ -791,56 +792,63 @@ async fn shopping_cart_example(deps: &EnvBasedTestDependencies, _tracing: &Traci
 #[test]
 #[tracing::instrument]
 async fn auction_example_1(deps: &EnvBasedTestDependencies, _tracing: &Tracing) {
-    let registry_component_id = deps.store_component("auction_registry_composed").await;
-    let auction_component_id = deps.store_component("auction").await;
-
-    let mut env = HashMap::new();
-    env.insert(
-        "AUCTION_COMPONENT_ID".to_string(),
-        auction_component_id.to_string(),
-    );
-    let registry_worker_id = deps
-        .start_worker_with(&registry_component_id, "auction-registry-1", vec![], env)
-        .await;
+    let artifacts = TestArtifactCollector::new("worker::auction_example_1");
+    artifacts
+        .publish_on_failure(deps, async {
+            let registry_component_id = deps.store_component("auction_registry_composed").await;
+            let auction_component_id = deps.store_component("auction").await;
+
+            let mut env = HashMap::new();
+            env.insert(
+                "AUCTION_COMPONENT_ID".to_string(),
+                auction_component_id.to_string(),
+            );
+            let registry_worker_id = deps
+                .start_worker_with(&registry_component_id, "auction-registry-1", vec![], env)
+                .await;
+
+            let _ = deps.log_output(&registry_worker_id).await;
+
+            artifacts.track_worker(deps, &registry_worker_id).await;
+
+            let expiration = SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap()
+                .as_secs();
+
+            let mut create_results = vec![];
+
+            for _ in 1..100 {
+                let create_auction_result = deps
+                    .invoke_and_await(
+                        &registry_worker_id,
+                        "auction:registry/api.{create-auction}",
+                        vec![
+                            Value::String("test-auction".to_string()),
+                            Value::String("this is a test".to_string()),
+                            Value::F32(100.0),
+                            Value::U64(expiration + 600),
+                        ],
+                    )
+                    .await;
 
-    let _ = deps.log_output(&registry_worker_id).await;
+                create_results.push(create_auction_result);
+            }
 
-    let expiration = SystemTime::now()
-        .duration_since(SystemTime::UNIX_EPOCH)
-        .unwrap()
-        .as_secs();
+            let get_auctions_result = deps
+                .invoke_and_await(
+                    &registry_worker_id,
+                    "auction:registry/api.{get-auctions}",
+                    vec![],
+                )
+                .await;
 
-    let mut create_results = vec![];
+            println!("result: {:?}", create_results);
+            println!("result: {:?}", get_auctions_result);
 
-    for _ in 1..100 {
-        let create_auction_result = deps
-            .invoke_and_await(
-                &registry_worker_id,
-                "auction:registry/api.{create-auction}",
-                vec![
-                    Value::String("test-auction".to_string()),
-                    Value::String("this is a test".to_string()),
-                    Value::F32(100.0),
-                    Value::U64(expiration + 600),
-                ],
-            )
-            .await;
-
-        create_results.push(create_auction_result);
-    }
-
-    let get_auctions_result = deps
-        .invoke_and_await(
-            &registry_worker_id,
-            "auction:registry/api.{get-auctions}",
-            vec![],
-        )
+            check!(create_results.iter().all(|r| r.is_ok()));
+        })
         .await;
-
-    println!("result: {:?}", create_results);
-    println!("result: {:?}", get_auctions_result);
-
-    check!(create_results.iter().all(|r| r.is_ok()));
 }
 
 fn get_worker_ids(workers: Vec<(WorkerMetadata, Option<String>)>) -> HashSet<WorkerId> {
@@ -1080,32 +1088,38 @@ async fn get_running_workers(deps: &EnvBasedTestDependencies, _tracing: &Tracing
 #[test]
 #[tracing::instrument]
 async fn auto_update_on_idle(deps: &EnvBasedTestDependencies, _tracing: &Tracing) {
-    let component_id = deps.store_unique_component("update-test-v1").await;
-    let worker_id = deps
-        .start_worker(&component_id, "auto_update_on_idle")
+    let artifacts = TestArtifactCollector::new("worker::auto_update_on_idle");
+    artifacts
+        .publish_on_failure(deps, async {
+            let component_id = deps.store_unique_component("update-test-v1").await;
+            let worker_id = deps
+                .start_worker(&component_id, "auto_update_on_idle")
+                .await;
+            let _ = deps.log_output(&worker_id).await;
+            artifacts.track_worker(deps, &worker_id).await;
+
+            let target_version = deps.update_component(&component_id, "update-test-v2").await;
+            info!("Updated component to version {target_version}");
+
+            deps.auto_update_worker(&worker_id, target_version).await;
+
+            let result = deps
+                .invoke_and_await(&worker_id, "golem:component/api.{f2}", vec![])
+                .await
+                .unwrap();
+
+            info!("result: {:?}", result);
+            let (metadata, _) = deps.get_worker_metadata(&worker_id).await.unwrap();
+
+            // Expectation: the worker has no history so the update succeeds and then calling f2 returns
+            // the current state which is 0
+            check!(result[0] == Value::U64(0));
+            check!(metadata.last_known_status.component_version == target_version);
+            check!(metadata.last_known_status.pending_updates.is_empty());
+            check!(metadata.last_known_status.failed_updates.is_empty());
+            check!(metadata.last_known_status.successful_updates.len() == 1);
+        })
         .await;
-    let _ = deps.log_output(&worker_id).await;
-
-    let target_version = deps.update_component(&component_id, "update-test-v2").await;
-    info!("Updated component to version {target_version}");
-
-    deps.auto_update_worker(&worker_id, target_version).await;
-
-    let result = deps
-        .invoke_and_await(&worker_id, "golem:component/api.{f2}", vec![])
-        .await
-        .unwrap();
-
-    info!("result: {:?}", result);
-    let (metadata, _) = deps.get_worker_metadata(&worker_id).await.unwrap();
-
-    // Expectation: the worker has no history so the update succeeds and then calling f2 returns
-    // the current state which is 0
-    check!(result[0] == Value::U64(0));
-    check!(metadata.last_known_status.component_version == target_version);
-    check!(metadata.last_known_status.pending_updates.is_empty());
-    check!(metadata.last_known_status.failed_updates.is_empty());
-    check!(metadata.last_known_status.successful_updates.len() == 1);
 }
 
 #[test]
@@ -1114,53 +1128,59 @@ async fn auto_update_on_idle_via_host_function(
     deps: &EnvBasedTestDependencies,
     _tracing: &Tracing,
 ) {
-    let component_id = deps.store_unique_component("update-test-v1").await;
-    let worker_id = deps
-        .start_worker(&component_id, "auto_update_on_idle_via_host_function")
+    let artifacts = TestArtifactCollector::new("worker::auto_update_on_idle_via_host_function");
+    artifacts
+        .publish_on_failure(deps, async {
+            let component_id = deps.store_unique_component("update-test-v1").await;
+            let worker_id = deps
+                .start_worker(&component_id, "auto_update_on_idle_via_host_function")
+                .await;
+            let _ = deps.log_output(&worker_id).await;
+            artifacts.track_worker(deps, &worker_id).await;
+
+            let target_version = deps.update_component(&component_id, "update-test-v2").await;
+            info!("Updated component to version {target_version}");
+
+            let runtime_svc = deps.store_component("runtime-service").await;
+            let runtime_svc_worker = WorkerId {
+                component_id: runtime_svc,
+                worker_name: "runtime-service".to_string(),
+            };
+            deps.invoke_and_await(
+                &runtime_svc_worker,
+                "golem:it/api.{update-worker}",
+                vec![
+                    Value::Record(vec![
+                        Value::Record(vec![Value::Record(vec![
+                            Value::U64(worker_id.component_id.0.as_u64_pair().0),
+                            Value::U64(worker_id.component_id.0.as_u64_pair().1),
+                        ])]),
+                        Value::String(worker_id.worker_name.clone()),
+                    ]),
+                    Value::U64(target_version),
+                    Value::Enum(0),
+                ],
+            )
+            .await
+            .unwrap();
+
+            let result = deps
+                .invoke_and_await(&worker_id, "golem:component/api.{f2}", vec![])
+                .await
+                .unwrap();
+
+            info!("result: {:?}", result);
+            let (metadata, _) = deps.get_worker_metadata(&worker_id).await.unwrap();
+
+            // Expectation: the worker has no history so the update succeeds and then calling f2 returns
+            // the current state which is 0
+            check!(result[0] == Value::U64(0));
+            check!(metadata.last_known_status.component_version == target_version);
+            check!(metadata.last_known_status.pending_updates.is_empty());
+            check!(metadata.last_known_status.failed_updates.is_empty());
+            check!(metadata.last_known_status.successful_updates.len() == 1);
+        })
         .await;
-    let _ = deps.log_output(&worker_id).await;
-
-    let target_version = deps.update_component(&component_id, "update-test-v2").await;
-    info!("Updated component to version {target_version}");
-
-    let runtime_svc = deps.store_component("runtime-service").await;
-    let runtime_svc_worker = WorkerId {
-        component_id: runtime_svc,
-        worker_name: "runtime-service".to_string(),
-    };
-    deps.invoke_and_await(
-        &runtime_svc_worker,
-        "golem:it/api.{update-worker}",
-        vec![
-            Value::Record(vec![
-                Value::Record(vec![Value::Record(vec![
-                    Value::U64(worker_id.component_id.0.as_u64_pair().0),
-                    Value::U64(worker_id.component_id.0.as_u64_pair().1),
-                ])]),
-                Value::String(worker_id.worker_name.clone()),
-            ]),
-            Value::U64(target_version),
-            Value::Enum(0),
-        ],
-    )
-    .await
-    .unwrap();
-
-    let result = deps
-        .invoke_and_await(&worker_id, "golem:component/api.{f2}", vec![])
-        .await
-        .unwrap();
-
-    info!("result: {:?}", result);
-    let (metadata, _) = deps.get_worker_metadata(&worker_id).await.unwrap();
-
-    // Expectation: the worker has no history so the update succeeds and then calling f2 returns
-    // the current state which is 0
-    check!(result[0] == Value::U64(0));
-    check!(metadata.last_known_status.component_version == target_version);
-    check!(metadata.last_known_status.pending_updates.is_empty());
-    check!(metadata.last_known_status.failed_updates.is_empty());
-    check!(metadata.last_known_status.successful_updates.len() == 1);
 }
 
 #[test]
@@ -0,0 +1,205 @@
+// Generated by `wit-bindgen` 0.25.0. DO NOT EDIT!
+// Options used:
+#[allow(dead_code)]
+pub mod golem {
+    #[allow(dead_code)]
+    pub mod api1_1_0_rc2 {
+        #[allow(dead_code, clippy::all)]
+        pub mod cancellation {
+            #[used]
+            #[doc(hidden)]
+            #[cfg(target_arch = "wasm32")]
+            static __FORCE_SECTION_REF: fn() =
+                super::super::super::__link_custom_section_describing_imports;
+            use super::super::super::_rt;
+            #[allow(unused_unsafe, clippy::all)]
+            /// Returns true if the invocation the worker is currently processing was asked to stop.
+            ///
+            /// Cancellation is requested when the worker gets interrupted while a cancellation grace period
+            /// is configured, or when the invocation exceeds the configured invocation timeout. The result
+            /// is recorded in the oplog, so a replayed invocation observes the cancellation at the same point.
+            pub fn is_cancelled() -> bool {
+                unsafe {
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/cancellation@1.1.0-rc2")]
+                    extern "C" {
+                        #[link_name = "is-cancelled"]
+                        fn wit_import() -> i32;
+                    }
+
+                    #[cfg(not(target_arch = "wasm32"))]
+                    fn wit_import() -> i32 {
+                        unreachable!()
+                    }
+                    let ret = wit_import();
+                    _rt::bool_lift(ret as u8)
+                }
+            }
+        }
+    }
+}
+#[allow(dead_code)]
+pub mod exports {
+    #[allow(dead_code)]
+    pub mod golem {
+        #[allow(dead_code)]
+        pub mod it {
+            #[allow(dead_code, clippy::all)]
+            pub mod api {
+                #[used]
+                #[doc(hidden)]
+                #[cfg(target_arch = "wasm32")]
+                static __FORCE_SECTION_REF: fn() =
+                    super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_poll_until_cancelled_cabi<T: Guest>(arg0: i64) -> i64 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::poll_until_cancelled(arg0 as u64);
+                    _rt::as_i64(result0)
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_ignore_cancellation_cabi<T: Guest>(arg0: i64) -> i64 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::ignore_cancellation(arg0 as u64);
+                    _rt::as_i64(result0)
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_get_last_result_cabi<T: Guest>() -> i64 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::get_last_result();
+                    _rt::as_i64(result0)
+                }
+                pub trait Guest {
+                    /// Runs until the invocation gets cancelled or max-iterations is reached, returning the number of iterations
+                    fn poll_until_cancelled(max_iterations: u64) -> u64;
+                    /// Runs for the given number of iterations without checking for cancellation
+                    fn ignore_cancellation(iterations: u64) -> u64;
+                    /// Returns the result of the last finished invocation
+                    fn get_last_result() -> u64;
+                }
+                #[doc(hidden)]
+
+                macro_rules! __export_golem_it_api_cabi{
+    ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+
+      #[export_name = "golem:it/api#poll-until-cancelled"]
+      unsafe extern "C" fn export_poll_until_cancelled(arg0: i64,) -> i64 {
+        $($path_to_types)*::_export_poll_until_cancelled_cabi::<$ty>(arg0)
+      }
+      #[export_name = "golem:it/api#ignore-cancellation"]
+      unsafe extern "C" fn export_ignore_cancellation(arg0: i64,) -> i64 {
+        $($path_to_types)*::_export_ignore_cancellation_cabi::<$ty>(arg0)
+      }
+      #[export_name = "golem:it/api#get-last-result"]
+      unsafe extern "C" fn export_get_last_result() -> i64 {
+        $($path_to_types)*::_export_get_last_result_cabi::<$ty>()
+      }
+    };);
+  }
+                #[doc(hidden)]
+                pub(crate) use __export_golem_it_api_cabi;
+            }
+        }
+    }
+}
+mod _rt {
+    pub unsafe fn bool_lift(val: u8) -> bool {
+        if cfg!(debug_assertions) {
+            match val {
+                0 => false,
+                1 => true,
+                _ => panic!("invalid bool discriminant"),
+            }
+        } else {
+            val != 0
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn run_ctors_once() {
+        wit_bindgen_rt::run_ctors_once();
+    }
+
+    pub fn as_i64<T: AsI64>(t: T) -> i64 {
+        t.as_i64()
+    }
+
+    pub trait AsI64 {
+        fn as_i64(self) -> i64;
+    }
+
+    impl<'a, T: Copy + AsI64> AsI64 for &'a T {
+        fn as_i64(self) -> i64 {
+            (*self).as_i64()
+        }
+    }
+
+    impl AsI64 for i64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+    }
+
+    impl AsI64 for u64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+    }
+}
+
+/// Generates `#[no_mangle]` functions to export the specified type as the
+/// root implementation of all generated traits.
+///
+/// For more information see the documentation of `wit_bindgen::generate!`.
+///
+/// ```rust
+/// # macro_rules! export{ ($($t:tt)*) => (); }
+/// # trait Guest {}
+/// struct MyType;
+///
+/// impl Guest for MyType {
+///     // ...
+/// }
+///
+/// export!(MyType);
+/// ```
+#[allow(unused_macros)]
+#[doc(hidden)]
+
+macro_rules! __export_cancellation_impl {
+  ($ty:ident) => (self::export!($ty with_types_in self););
+  ($ty:ident with_types_in $($path_to_types_root:tt)*) => (
+  $($path_to_types_root)*::exports::golem::it::api::__export_golem_it_api_cabi!($ty with_types_in $($path_to_types_root)*::exports::golem::it::api);
+  )
+}
+#[doc(inline)]
+pub(crate) use __export_cancellation_impl as export;
+
+#[cfg(target_arch = "wasm32")]
+#[link_section = "component-type:wit-bindgen:0.25.0:cancellation:encoded world"]
+#[doc(hidden)]
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 357] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xe2\x01\x01A\x02\x01\
+A\x04\x01B\x02\x01@\0\0\x7f\x04\0\x0cis-cancelled\x01\0\x03\x01\x20golem:api/can\
+cellation@1.1.0-rc2\x05\0\x01B\x06\x01@\x01\x0emax-iterationsw\0w\x04\0\x14poll-\
+until-cancelled\x01\0\x01@\x01\x0aiterationsw\0w\x04\0\x13ignore-cancellation\x01\
+\x01\x01@\0\0w\x04\0\x0fget-last-result\x01\x02\x04\x01\x0cgolem:it/api\x05\x01\x04\
+\x01\x15golem:it/cancellation\x04\0\x0b\x12\x01\0\x0ccancellation\x03\0\0\0G\x09\
+producers\x01\x0cprocessed-by\x02\x0dwit-component\x070.208.1\x10wit-bindgen-rus\
+t\x060.25.0";
+
+#[inline(never)]
+#[doc(hidden)]
+#[cfg(target_arch = "wasm32")]
+pub fn __link_custom_section_describing_imports() {
+    wit_bindgen_rt::maybe_link_cabi_realloc();
+}
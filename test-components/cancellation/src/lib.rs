@@ -0,0 +1,44 @@
+mod bindings;
+
+use crate::bindings::exports::golem::it::api::Guest;
+use crate::bindings::golem::api1_1_0_rc2::cancellation::is_cancelled;
+
+use std::thread::sleep;
+use std::time::Duration;
+
+struct Component;
+
+static mut LAST_RESULT: u64 = 0;
+
+fn set_last_result(result: u64) -> u64 {
+    unsafe { LAST_RESULT = result };
+    result
+}
+
+impl Guest for Component {
+    fn poll_until_cancelled(max_iterations: u64) -> u64 {
+        println!("Polling for cancellation");
+        for iteration in 0..max_iterations {
+            if is_cancelled() {
+                println!("Cancelled after {iteration} iterations");
+                return set_last_result(iteration);
+            }
+            sleep(Duration::from_millis(100));
+        }
+        set_last_result(max_iterations)
+    }
+
+    fn ignore_cancellation(iterations: u64) -> u64 {
+        println!("Ignoring cancellation");
+        for _ in 0..iterations {
+            sleep(Duration::from_millis(100));
+        }
+        set_last_result(iterations)
+    }
+
+    fn get_last_result() -> u64 {
+        unsafe { LAST_RESULT }
+    }
+}
+
+bindings::export!(Component with_types_in bindings);
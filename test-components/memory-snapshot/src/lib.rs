@@ -0,0 +1,65 @@
+#![no_std]
+
+mod bindings;
+
+use core::alloc::{GlobalAlloc, Layout};
+use core::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+use crate::bindings::exports::golem::it::api::*;
+
+static TOTAL: AtomicU64 = AtomicU64::new(0);
+
+struct Component;
+
+impl Guest for Component {
+    fn add(value: u64) {
+        TOTAL.fetch_add(value, Ordering::Relaxed);
+    }
+
+    fn get() -> u64 {
+        TOTAL.load(Ordering::Relaxed)
+    }
+}
+
+/// Never frees anything, the exported functions do not allocate
+struct BumpAllocator;
+
+const HEAP_SIZE: usize = 64 * 1024;
+
+static mut HEAP: [u8; HEAP_SIZE] = [0; HEAP_SIZE];
+static HEAP_USED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for BumpAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let base = core::ptr::addr_of_mut!(HEAP) as usize;
+        let start = (base + HEAP_USED.load(Ordering::Relaxed)).next_multiple_of(layout.align());
+        let end = start + layout.size();
+        if end > base + HEAP_SIZE {
+            core::ptr::null_mut()
+        } else {
+            HEAP_USED.store(end - base, Ordering::Relaxed);
+            start as *mut u8
+        }
+    }
+
+    unsafe fn dealloc(&self, _ptr: *mut u8, _layout: Layout) {}
+}
+
+#[global_allocator]
+static ALLOCATOR: BumpAllocator = BumpAllocator;
+
+/// No libc is linked, so reallocations need their own `memcpy`
+#[no_mangle]
+unsafe extern "C" fn memcpy(dest: *mut u8, src: *const u8, n: usize) -> *mut u8 {
+    for i in 0..n {
+        core::ptr::write_volatile(dest.add(i), core::ptr::read_volatile(src.add(i)));
+    }
+    dest
+}
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    core::arch::wasm32::unreachable()
+}
+
+bindings::export!(Component with_types_in bindings);
@@ -4091,6 +4091,141 @@ pub mod wasi {
         }
     }
 }
+#[allow(dead_code)]
+pub mod exports {
+    #[allow(dead_code)]
+    pub mod golem {
+        #[allow(dead_code)]
+        pub mod it {
+            #[allow(dead_code, clippy::all)]
+            pub mod api {
+                #[used]
+                #[doc(hidden)]
+                #[cfg(target_arch = "wasm32")]
+                static __FORCE_SECTION_REF: fn() =
+                    super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                pub type PromiseId = super::super::super::super::golem::api::host::PromiseId;
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_create_promise_cabi<T: Guest>() -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::create_promise();
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let super::super::super::super::golem::api::host::PromiseId {
+                        worker_id: worker_id2,
+                        oplog_idx: oplog_idx2,
+                    } = result0;
+                    let super::super::super::super::golem::api::host::WorkerId {
+                        component_id: component_id3,
+                        worker_name: worker_name3,
+                    } = worker_id2;
+                    let super::super::super::super::golem::api::host::ComponentId { uuid: uuid4 } =
+                        component_id3;
+                    let super::super::super::super::golem::api::host::Uuid {
+                        high_bits: high_bits5,
+                        low_bits: low_bits5,
+                    } = uuid4;
+                    *ptr1.add(0).cast::<i64>() = _rt::as_i64(high_bits5);
+                    *ptr1.add(8).cast::<i64>() = _rt::as_i64(low_bits5);
+                    let vec6 = (worker_name3.into_bytes()).into_boxed_slice();
+                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                    let len6 = vec6.len();
+                    ::core::mem::forget(vec6);
+                    *ptr1.add(20).cast::<usize>() = len6;
+                    *ptr1.add(16).cast::<*mut u8>() = ptr6.cast_mut();
+                    *ptr1.add(24).cast::<i64>() = _rt::as_i64(oplog_idx2);
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_create_promise<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(16).cast::<*mut u8>();
+                    let l1 = *arg0.add(20).cast::<usize>();
+                    _rt::cabi_dealloc(l0, l1, 1);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_await_promise_cabi<T: Guest>(
+                    arg0: i64,
+                    arg1: i64,
+                    arg2: *mut u8,
+                    arg3: usize,
+                    arg4: i64,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg3;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg2.cast(), len0, len0);
+                    let result1 =
+                        T::await_promise(super::super::super::super::golem::api::host::PromiseId {
+                            worker_id: super::super::super::super::golem::api::host::WorkerId {
+                                component_id:
+                                    super::super::super::super::golem::api::host::ComponentId {
+                                        uuid: super::super::super::super::golem::api::host::Uuid {
+                                            high_bits: arg0 as u64,
+                                            low_bits: arg1 as u64,
+                                        },
+                                    },
+                                worker_name: _rt::string_lift(bytes0),
+                            },
+                            oplog_idx: arg4 as u64,
+                        });
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let vec3 = (result1).into_boxed_slice();
+                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                    let len3 = vec3.len();
+                    ::core::mem::forget(vec3);
+                    *ptr2.add(4).cast::<usize>() = len3;
+                    *ptr2.add(0).cast::<*mut u8>() = ptr3.cast_mut();
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_await_promise<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(0).cast::<*mut u8>();
+                    let l1 = *arg0.add(4).cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 1, 1);
+                }
+                pub trait Guest {
+                    fn create_promise() -> PromiseId;
+                    fn await_promise(promise_id: PromiseId) -> _rt::Vec<u8>;
+                }
+                #[doc(hidden)]
+
+                macro_rules! __export_golem_it_api_cabi{
+                                    ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+
+                                      #[export_name = "golem:it/api#create-promise"]
+                                      unsafe extern "C" fn export_create_promise() -> *mut u8 {
+                                        $($path_to_types)*::_export_create_promise_cabi::<$ty>()
+                                      }
+                                      #[export_name = "cabi_post_golem:it/api#create-promise"]
+                                      unsafe extern "C" fn _post_return_create_promise(arg0: *mut u8,) {
+                                        $($path_to_types)*::__post_return_create_promise::<$ty>(arg0)
+                                      }
+                                      #[export_name = "golem:it/api#await-promise"]
+                                      unsafe extern "C" fn export_await_promise(arg0: i64,arg1: i64,arg2: *mut u8,arg3: usize,arg4: i64,) -> *mut u8 {
+                                        $($path_to_types)*::_export_await_promise_cabi::<$ty>(arg0, arg1, arg2, arg3, arg4)
+                                      }
+                                      #[export_name = "cabi_post_golem:it/api#await-promise"]
+                                      unsafe extern "C" fn _post_return_await_promise(arg0: *mut u8,) {
+                                        $($path_to_types)*::__post_return_await_promise::<$ty>(arg0)
+                                      }
+                                    };);
+                                  }
+                #[doc(hidden)]
+                pub(crate) use __export_golem_it_api_cabi;
+                #[repr(align(8))]
+                struct _RetArea([::core::mem::MaybeUninit<u8>; 32]);
+                static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 32]);
+            }
+        }
+    }
+}
 mod _rt {
 
     use core::fmt;
@@ -4397,20 +4532,21 @@ mod _rt {
 #[doc(hidden)]
 
 macro_rules! __export_promise_impl {
-                                ($ty:ident) => (self::export!($ty with_types_in self););
-                                ($ty:ident with_types_in $($path_to_types_root:tt)*) => (
-                                $($path_to_types_root)*::__export_world_promise_cabi!($ty with_types_in $($path_to_types_root)*);
-                                )
-                              }
+                            ($ty:ident) => (self::export!($ty with_types_in self););
+                            ($ty:ident with_types_in $($path_to_types_root:tt)*) => (
+                            $($path_to_types_root)*::__export_world_promise_cabi!($ty with_types_in $($path_to_types_root)*);
+                            $($path_to_types_root)*::exports::golem::it::api::__export_golem_it_api_cabi!($ty with_types_in $($path_to_types_root)*::exports::golem::it::api);
+                            )
+                          }
 #[doc(inline)]
 pub(crate) use __export_promise_impl as export;
 
 #[cfg(target_arch = "wasm32")]
 #[link_section = "component-type:wit-bindgen:0.25.0:promise:encoded world"]
 #[doc(hidden)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 3573] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xf7\x1a\x01A\x02\x01\
-A\x0e\x01B\x0a\x04\0\x08pollable\x03\x01\x01h\0\x01@\x01\x04self\x01\0\x7f\x04\0\
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 3691] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xed\x1b\x01A\x02\x01\
+A\x11\x01B\x0a\x04\0\x08pollable\x03\x01\x01h\0\x01@\x01\x04self\x01\0\x7f\x04\0\
 \x16[method]pollable.ready\x01\x02\x01@\x01\x04self\x01\x01\0\x04\0\x16[method]p\
 ollable.block\x01\x03\x01p\x01\x01py\x01@\x01\x02in\x04\0\x05\x04\0\x04poll\x01\x06\
 \x03\x01\x12wasi:io/poll@0.2.0\x05\0\x02\x03\0\0\x08pollable\x01B*\x02\x03\x02\x01\
@@ -4481,9 +4617,12 @@ new-persistence-level\x13\x01\0\x04\0\x1bset-oplog-persistence-level\x01H\x01@\0
 K\x01@\x03\x09worker-id\x0d\x0etarget-version\x07\x04mode\x15\x01\0\x04\0\x0dupd\
 ate-worker\x01L\x01@\0\02\x04\0\x11get-self-metadata\x01M\x01k2\x01@\x01\x09work\
 er-id\x0d\0\xce\0\x04\0\x13get-worker-metadata\x01O\x03\x01\x14golem:api/host@0.\
-2.0\x05\x06\x01p}\x01@\0\0\x07\x04\0\x03run\x01\x08\x04\x01\x10golem:it/promise\x04\
-\0\x0b\x0d\x01\0\x07promise\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dw\
-it-component\x070.208.1\x10wit-bindgen-rust\x060.25.0";
+2.0\x05\x06\x01p}\x01@\0\0\x07\x04\0\x03run\x01\x08\x02\x03\0\x03\x0apromise-id\x01\
+B\x07\x02\x03\x02\x01\x09\x04\0\x0apromise-id\x03\0\0\x01@\0\0\x01\x04\0\x0ecrea\
+te-promise\x01\x02\x01p}\x01@\x01\x0apromise-id\x01\0\x03\x04\0\x0dawait-promise\
+\x01\x04\x04\x01\x0cgolem:it/api\x05\x0a\x04\x01\x10golem:it/promise\x04\0\x0b\x0d\
+\x01\0\x07promise\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-compone\
+nt\x070.208.1\x10wit-bindgen-rust\x060.25.0";
 
 #[inline(never)]
 #[doc(hidden)]
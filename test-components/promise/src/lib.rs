@@ -1,5 +1,6 @@
 mod bindings;
 
+use crate::bindings::exports::golem::it::api::Guest as ApiGuest;
 use crate::bindings::golem::api::host::*;
 use crate::bindings::Guest;
 
@@ -12,4 +13,14 @@ impl Guest for Component {
     }
 }
 
+impl ApiGuest for Component {
+    fn create_promise() -> PromiseId {
+        create_promise()
+    }
+
+    fn await_promise(promise_id: PromiseId) -> Vec<u8> {
+        await_promise(&promise_id)
+    }
+}
+
 bindings::export!(Component with_types_in bindings);